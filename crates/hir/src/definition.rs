@@ -1,12 +1,18 @@
 use base_db::EditionedFileId;
 use hir_def::{
-    expression_store::path::Path, item_tree::Name, mod_path::ModPath, resolver::ResolveKind,
+    db::{DefinitionWithBodyId, FunctionId},
+    expression_store::path::Path,
+    item_scope::ItemScope,
+    item_tree::Name,
+    mod_path::ModPath,
+    resolver::ResolveKind,
+    signature::{FieldId, StructSignature},
 };
 use syntax::{AstNode as _, SyntaxNode, SyntaxToken, ast, match_ast};
 
 use crate::{
-    Field, Function, GlobalConstant, GlobalVariable, Local, ModuleDef, Override, Semantics, Struct,
-    TypeAlias,
+    ChildContainer, Field, Function, GlobalConstant, GlobalVariable, HirDatabase, Local, ModuleDef,
+    Override, Semantics, Struct, TypeAlias,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,6 +52,9 @@ impl Definition {
                 ast::FieldExpression(field_expression) => {
                     resolve_field(semantics, file_id, field_expression)
                 },
+                ast::Name(name) => {
+                    Self::from_name(semantics, file_id, &name)
+                },
                 _ => {
                     tracing::warn!("attempted to go to definition {:?}", node);
                     None
@@ -55,6 +64,96 @@ impl Definition {
     }
 }
 
+impl Definition {
+    /// Classifies the name of a declaration, such as the `foo` in `fn foo()` or `let foo = 1;`.
+    #[must_use]
+    pub fn from_name(
+        semantics: &Semantics<'_>,
+        file_id: EditionedFileId,
+        name: &ast::Name,
+    ) -> Option<Self> {
+        let parent = name.syntax().parent()?;
+        let container = semantics.find_container(file_id, name.syntax())?;
+        let definition = match container {
+            ChildContainer::DefinitionWithBodyId(DefinitionWithBodyId::Function(function)) => {
+                resolve_local(semantics, function, name)?
+            },
+            ChildContainer::FunctionId(function) => {
+                if ast::FunctionDeclaration::can_cast(parent.kind()) {
+                    Self::ModuleDef(ModuleDef::Function(Function { id: function }))
+                } else {
+                    resolve_local(semantics, function, name)?
+                }
+            },
+            ChildContainer::GlobalVariableId(id) => {
+                Self::ModuleDef(ModuleDef::GlobalVariable(GlobalVariable { id }))
+            },
+            ChildContainer::GlobalConstantId(id) => {
+                Self::ModuleDef(ModuleDef::GlobalConstant(GlobalConstant { id }))
+            },
+            ChildContainer::OverrideId(id) => Self::ModuleDef(ModuleDef::Override(Override { id })),
+            ChildContainer::TypeAliasId(id) => {
+                Self::ModuleDef(ModuleDef::TypeAlias(TypeAlias { id }))
+            },
+            ChildContainer::StructId(id) => {
+                if ast::StructMember::can_cast(parent.kind()) {
+                    let field =
+                        StructSignature::of(semantics.db, id).field(&Name::from(name.clone()))?;
+                    Self::Field(Field {
+                        id: FieldId {
+                            r#struct: id,
+                            field,
+                        },
+                    })
+                } else {
+                    Self::ModuleDef(ModuleDef::Struct(Struct { id }))
+                }
+            },
+            ChildContainer::ImportId(_) => {
+                let item = ast::ImportItem::cast(parent)?;
+                resolve_import_item(semantics, file_id, &item)?
+            },
+            ChildContainer::DefinitionWithBodyId(
+                DefinitionWithBodyId::GlobalVariable(_)
+                | DefinitionWithBodyId::GlobalConstant(_)
+                | DefinitionWithBodyId::Override(_)
+                | DefinitionWithBodyId::GlobalAssertStatement(_),
+            )
+            | ChildContainer::GlobalAssertStatementId(_) => return None,
+        };
+        Some(definition)
+    }
+
+    /// The name the definition was declared with.
+    ///
+    /// Returns `None` for definitions which cannot be named, such as `const_assert`s.
+    #[must_use]
+    pub fn name(
+        &self,
+        db: &dyn HirDatabase,
+    ) -> Option<Name> {
+        let name = match self {
+            Self::Local(local) => local.name(db),
+            Self::Field(field) => field.name(db),
+            Self::ModuleDef(definition) => match definition {
+                ModuleDef::Function(function) => function.name(db),
+                ModuleDef::GlobalVariable(variable) => variable.name(db),
+                ModuleDef::GlobalConstant(constant) => constant.name(db),
+                ModuleDef::Override(r#override) => r#override.name(db),
+                ModuleDef::Struct(r#struct) => r#struct.name(db),
+                ModuleDef::TypeAlias(type_alias) => type_alias.name(db),
+                ModuleDef::GlobalAssertStatement(_) => return None,
+            },
+            Self::BuiltinFunction(name)
+            | Self::BuiltinType(name)
+            | Self::BuiltinTypeGenerator(name)
+            | Self::BuiltinEnumerant(name)
+            | Self::BuiltinDeclaration(name) => name.clone(),
+        };
+        Some(name)
+    }
+}
+
 impl From<ResolveKind> for Definition {
     fn from(value: ResolveKind) -> Self {
         match value {
@@ -100,6 +199,34 @@ fn resolve_path(
     }
 }
 
+fn resolve_local(
+    semantics: &Semantics<'_>,
+    function: FunctionId,
+    name: &ast::Name,
+) -> Option<Definition> {
+    let binding = semantics
+        .analyze(DefinitionWithBodyId::Function(function))
+        .binding_id(name)?;
+    Some(Definition::Local(Local {
+        parent: function,
+        binding,
+    }))
+}
+
+/// Resolves the item brought into scope by an import, such as the `bar` in `import foo::bar;`.
+fn resolve_import_item(
+    semantics: &Semantics<'_>,
+    file_id: EditionedFileId,
+    item: &ast::ImportItem,
+) -> Option<Definition> {
+    let name = item.alias().or_else(|| item.name())?;
+    let module_item = ItemScope::of(semantics.db, file_id).get(&Name::from(name))?;
+    module_item.import?;
+    ResolveKind::try_from(module_item.definition)
+        .ok()
+        .map(Definition::from)
+}
+
 fn resolve_field(
     semantics: &Semantics<'_>,
     file_id: EditionedFileId,
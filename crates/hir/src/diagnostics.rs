@@ -38,6 +38,8 @@ pub enum AnyDiagnostic {
     },
     UnresolvedImport {
         id: InFile<AstPointer<ast::ImportStatement>>,
+        name: Name,
+        alternatives: Vec<Name>,
     },
     TooManySupers {
         id: InFile<AstPointer<ast::ImportStatement>>,
@@ -385,8 +387,14 @@ pub(crate) fn any_diag_from_def_diagnostic(
             id: id.ast_ptr(db),
             name: name.clone(),
         },
-        DefDiagnosticKind::UnresolvedImport { id } => {
-            AnyDiagnostic::UnresolvedImport { id: id.ast_ptr(db) }
+        DefDiagnosticKind::UnresolvedImport {
+            id,
+            name,
+            alternatives,
+        } => AnyDiagnostic::UnresolvedImport {
+            id: id.ast_ptr(db),
+            name: name.clone(),
+            alternatives: alternatives.clone(),
         },
         DefDiagnosticKind::TooManySupers { id } => {
             AnyDiagnostic::TooManySupers { id: id.ast_ptr(db) }
@@ -5,29 +5,38 @@ pub mod db;
 pub mod definition;
 pub mod diagnostics;
 
-use base_db::{EditionedFileId, Intern as _, Lookup as _, SourceDatabase};
+use base_db::{EditionedFileId, Intern as _, Lookup as _, SourceDatabase, TextRange};
 use diagnostics::AnyDiagnostic;
 use either::Either;
 use hir_def::{
     AstIdMap, HasSource as _, InFile,
+    attributes::{AttributeDefId, AttributesWithOwner},
     body::{BindingId, Body, BodySourceMap, scope::ExprScopes},
     db::{
         DefinitionWithBodyId, FunctionId, GlobalAssertStatementId, GlobalConstantId,
         GlobalVariableId, ImportId, Location, OverrideId, StructId, TypeAliasId,
     },
-    expression::{ExpressionId, StatementId},
+    expression::{Expression, ExpressionId, Literal, StatementId},
     expression_store::{ExpressionStore, ExpressionStoreOwnerId, ExpressionStoreSource},
     item_scope::ItemScope,
-    item_tree::{ItemTree, ModuleItemId},
+    item_tree::{ItemTree, ModuleItemId, Name},
     resolver::Resolver,
-    signature::{FieldId, FunctionSignature, ParameterId, StructSignature, TypeAliasSignature},
+    signature::{
+        ConstantSignature, FieldId, FunctionSignature, OverrideSignature, ParameterId,
+        StructSignature, TypeAliasSignature, VariableSignature,
+    },
+};
+use hir_ty::{
+    function::FunctionDetails,
+    infer::InferenceResult,
+    layout::{FieldLayout, struct_member_layout},
+    ty::{ArrayType, Pointer, Reference, Type, TypeKind},
 };
-use hir_ty::{infer::InferenceResult, ty::Type};
 use smallvec::SmallVec;
 use stdx::impl_from;
 use syntax::{AstNode as _, HasName as _, SyntaxNode, ast, pointer::AstPointer};
 
-pub use hir_ty::{AddressSpace, db::HirDatabase};
+pub use hir_ty::{AccessMode, AddressSpace, call_graph::ShaderStage, db::HirDatabase};
 
 pub trait HasSource {
     type Ast;
@@ -201,6 +210,34 @@ impl<'db> Semantics<'db> {
         Module { file_id }
     }
 
+    /// Finds the item of a WESL `import` statement which brought `name` into the scope of the module.
+    ///
+    /// Returns `None` if `name` is declared in the module itself.
+    #[must_use]
+    pub fn find_import_item(
+        &self,
+        file_id: EditionedFileId,
+        name: &Name,
+    ) -> Option<InFile<ast::ImportItem>> {
+        let item_scope = ItemScope::of(self.db, file_id);
+        let import = match item_scope.get(name) {
+            Some(item) => item.import?,
+            None => item_scope.import_paths.get(name)?.import,
+        };
+        let statement = import.lookup(self.db).source(self.db);
+        let item = statement
+            .value
+            .syntax()
+            .descendants()
+            .filter_map(ast::ImportItem::cast)
+            .find(|item| {
+                item.alias()
+                    .or_else(|| item.name())
+                    .is_some_and(|local_name| Name::from(local_name) == *name)
+            })?;
+        Some(statement.with_value(item))
+    }
+
     fn import_to_def(
         &self,
         source: &InFile<ast::ImportStatement>,
@@ -556,6 +593,25 @@ impl HasSource for Local {
     }
 }
 
+impl Local {
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        let body = Body::of(db, DefinitionWithBodyId::Function(self.parent));
+        body.bindings[self.binding].name.clone()
+    }
+
+    #[must_use]
+    pub fn ty(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Type {
+        InferenceResult::of(db, DefinitionWithBodyId::Function(self.parent))[self.binding]
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct Parameter {
     pub id: ParameterId,
@@ -603,6 +659,104 @@ impl HasSource for Function {
     }
 }
 
+impl Function {
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        FunctionSignature::of(db, self.id).name.clone()
+    }
+
+    /// The lowered signature of the function, with parameter and return types resolved.
+    #[must_use]
+    pub fn details(
+        self,
+        db: &dyn HirDatabase,
+    ) -> FunctionDetails {
+        db.function_type(self.id).lookup(db).clone()
+    }
+
+    /// The stage of the function, if it is an entry point.
+    #[must_use]
+    pub fn shader_stage(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Option<ShaderStage> {
+        ShaderStage::of(db, self.id)
+    }
+
+    /// The calls of user-declared functions in the body of the function.
+    ///
+    /// Each call comes with the range of the callee path in the file of the function.
+    #[must_use]
+    pub fn calls(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Vec<(Self, TextRange)> {
+        let (_, source_map) = Body::with_source_map(db, DefinitionWithBodyId::Function(self.id));
+        let root = self.id.lookup(db).file_id.parse(db).syntax();
+        db.function_calls(self.id)
+            .iter()
+            .filter_map(|call| {
+                let expression = source_map
+                    .expression_to_source(call.expression)
+                    .ok()?
+                    .to_node(&root);
+                let range = if let ast::Expression::FunctionCall(function_call) = &expression
+                    && let Some(path) = function_call
+                        .ident_expression()
+                        .and_then(|ident_expression| ident_expression.path())
+                {
+                    path.syntax().text_range()
+                } else {
+                    expression.syntax().text_range()
+                };
+                Some((Self { id: call.callee }, range))
+            })
+            .collect()
+    }
+
+    /// Whether the function calls `other`, directly or transitively.
+    #[must_use]
+    pub fn reaches(
+        self,
+        db: &dyn HirDatabase,
+        other: Self,
+    ) -> bool {
+        db.reachable_functions(self.id).contains(&other.id)
+    }
+
+    /// The resource variables which the function uses, directly or through the functions it
+    /// calls, ordered by group and binding.
+    #[must_use]
+    pub fn resources(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Vec<(GlobalVariable, ResourceBinding)> {
+        let mut resources: Vec<(GlobalVariable, ResourceBinding)> = Vec::new();
+        for function in &*db.reachable_functions(self.id) {
+            for id in &*db.used_global_variables(*function) {
+                let variable = GlobalVariable { id: *id };
+                if resources.iter().all(|(used, _)| *used != variable)
+                    && let Some(binding) = variable.binding(db)
+                {
+                    resources.push((variable, binding));
+                }
+            }
+        }
+        resources.sort_by_key(|(_, binding)| *binding);
+        resources
+    }
+}
+
+/// The `@group` and `@binding` attributes of a resource variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ResourceBinding {
+    pub group: u32,
+    pub binding: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct GlobalVariable {
     id: GlobalVariableId,
@@ -619,6 +773,52 @@ impl HasSource for GlobalVariable {
     }
 }
 
+impl GlobalVariable {
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        VariableSignature::of(db, self.id).name.clone()
+    }
+
+    /// The inferred type of the declaration.
+    #[must_use]
+    pub fn ty(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Type {
+        InferenceResult::of(db, DefinitionWithBodyId::GlobalVariable(self.id)).return_type()
+    }
+
+    /// The `@group` and `@binding` of the variable, if both are integer literals.
+    #[must_use]
+    pub fn binding(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Option<ResourceBinding> {
+        let attributes =
+            &AttributesWithOwner::of(db, AttributeDefId::GlobalVariable(self.id)).attribute_list;
+        let value = |name: &str| {
+            let attribute = attributes
+                .attributes
+                .iter()
+                .find(|attribute| attribute.name.as_str() == name)?;
+            if let Expression::Literal(Literal::Int(value, _)) =
+                attributes.store[*attribute.parameters.first()?]
+            {
+                u32::try_from(value).ok()
+            } else {
+                None
+            }
+        };
+        Some(ResourceBinding {
+            group: value("group")?,
+            binding: value("binding")?,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct GlobalConstant {
     id: GlobalConstantId,
@@ -635,6 +835,25 @@ impl HasSource for GlobalConstant {
     }
 }
 
+impl GlobalConstant {
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        ConstantSignature::of(db, self.id).name.clone()
+    }
+
+    /// The inferred type of the declaration.
+    #[must_use]
+    pub fn ty(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Type {
+        InferenceResult::of(db, DefinitionWithBodyId::GlobalConstant(self.id)).return_type()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct Override {
     id: OverrideId,
@@ -651,6 +870,25 @@ impl HasSource for Override {
     }
 }
 
+impl Override {
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        OverrideSignature::of(db, self.id).name.clone()
+    }
+
+    /// The inferred type of the declaration.
+    #[must_use]
+    pub fn ty(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Type {
+        InferenceResult::of(db, DefinitionWithBodyId::Override(self.id)).return_type()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct Struct {
     id: StructId,
@@ -667,6 +905,80 @@ impl HasSource for Struct {
     }
 }
 
+impl Struct {
+    /// The struct which defines `r#type`, looking through references, pointers and arrays.
+    #[must_use]
+    pub fn from_type(
+        db: &dyn HirDatabase,
+        r#type: Type,
+    ) -> Option<Self> {
+        match r#type.kind(db) {
+            TypeKind::Struct(id) => Some(Self { id }),
+            TypeKind::Reference(Reference { inner, .. })
+            | TypeKind::Pointer(Pointer { inner, .. })
+            | TypeKind::Array(ArrayType { inner, .. }) => Self::from_type(db, inner),
+            TypeKind::Error
+            | TypeKind::Scalar(_)
+            | TypeKind::Atomic(_)
+            | TypeKind::Vector(_)
+            | TypeKind::Matrix(_)
+            | TypeKind::BuiltinStruct(_)
+            | TypeKind::Texture(_)
+            | TypeKind::Sampler(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        StructSignature::of(db, self.id).name.clone()
+    }
+
+    #[must_use]
+    pub fn ty(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Type {
+        TypeKind::Struct(self.id).intern(db)
+    }
+
+    #[must_use]
+    pub fn fields(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Vec<Field> {
+        StructSignature::of(db, self.id)
+            .fields()
+            .iter()
+            .map(|(field, _)| Field {
+                id: FieldId {
+                    r#struct: self.id,
+                    field,
+                },
+            })
+            .collect()
+    }
+
+    /// The address space whose layout rules apply to the struct.
+    ///
+    /// Structs which are (transitively) used in a `var<uniform>` in their own module
+    /// use the stricter uniform layout, everything else uses the storage layout.
+    #[must_use]
+    pub fn layout_address_space(
+        self,
+        db: &dyn HirDatabase,
+    ) -> AddressSpace {
+        let file_id = self.id.lookup(db).file_id;
+        if db.struct_is_used_in_uniform(self.id, file_id) {
+            AddressSpace::Uniform
+        } else {
+            AddressSpace::Storage
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct TypeAlias {
     id: TypeAliasId,
@@ -683,6 +995,25 @@ impl HasSource for TypeAlias {
     }
 }
 
+impl TypeAlias {
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        TypeAliasSignature::of(db, self.id).name.clone()
+    }
+
+    /// The type the alias resolves to.
+    #[must_use]
+    pub fn ty(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Type {
+        db.type_alias_type(self.id).0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct GlobalAssertStatement {
     id: GlobalAssertStatementId,
@@ -726,6 +1057,54 @@ impl HasSource for Field {
     }
 }
 
+impl Field {
+    #[must_use]
+    pub fn name(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Name {
+        StructSignature::of(db, self.id.r#struct).fields()[self.id.field]
+            .name
+            .clone()
+    }
+
+    #[must_use]
+    pub const fn parent_struct(self) -> Struct {
+        Struct {
+            id: self.id.r#struct,
+        }
+    }
+
+    #[must_use]
+    pub fn ty(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Type {
+        db.field_types(self.id.r#struct).0[self.id.field]
+    }
+
+    /// Computes the offset, size and alignment of the field, according to the layout rules of
+    /// `address_space`.
+    ///
+    /// Returns `None` if the layout of the struct cannot be computed, for example because one of
+    /// its fields is not host-shareable.
+    #[must_use]
+    pub fn layout(
+        self,
+        db: &dyn HirDatabase,
+        address_space: AddressSpace,
+    ) -> Option<FieldLayout> {
+        let fields = &db.field_types(self.id.r#struct).0;
+        let mut result = None;
+        struct_member_layout(fields, db, address_space, |field, _, layout| {
+            if field == self.id.field {
+                result = Some(layout);
+            }
+        })?;
+        result
+    }
+}
+
 /// The defs which can be visible in the module.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ModuleDef {
@@ -758,6 +1137,23 @@ impl ModuleDef {
             Self::Struct(_) | Self::TypeAlias(_) => None,
         }
     }
+
+    /// The file the definition is declared in.
+    #[must_use]
+    pub fn file_id(
+        &self,
+        db: &dyn SourceDatabase,
+    ) -> EditionedFileId {
+        match *self {
+            Self::Function(function) => function.id.lookup(db).file_id,
+            Self::GlobalVariable(variable) => variable.id.lookup(db).file_id,
+            Self::GlobalConstant(constant) => constant.id.lookup(db).file_id,
+            Self::Override(r#override) => r#override.id.lookup(db).file_id,
+            Self::Struct(r#struct) => r#struct.id.lookup(db).file_id,
+            Self::TypeAlias(type_alias) => type_alias.id.lookup(db).file_id,
+            Self::GlobalAssertStatement(assert_statement) => assert_statement.id.lookup(db).file_id,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
@@ -863,6 +1259,14 @@ impl Module {
     }
 }
 
+/// Returns `true` if `name` starts with `__`, which the WGSL specification disallows.
+///
+/// See: <https://www.w3.org/TR/WGSL/#identifiers>
+#[must_use]
+pub fn is_invalid_identifier(name: &str) -> bool {
+    name.starts_with("__")
+}
+
 #[expect(clippy::doc_paragraphs_missing_punctuation, reason = "clippy bug")]
 /// Check for identifiers starting with "__". These are invalid according the WGSL specification.
 ///
@@ -886,7 +1290,7 @@ fn validate_identifiers(
             $file_id:expr
         ) => {{
             let data = &$item_tree[*$id];
-            if data.name.as_str().starts_with("__") {
+            if is_invalid_identifier(data.name.as_str()) {
                 let ast_ptr = $ast_id_map.get(*$id);
                 let node = ast_ptr.to_node(&$root);
                 if let Some(name_node) = node.name() {
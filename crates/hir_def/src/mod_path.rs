@@ -2,7 +2,7 @@
 
 use std::{fmt, iter};
 
-use base_db::{EditionedFileId, Package, SourceDatabase};
+use base_db::{EditionedFileId, Package, SourceDatabase, VfsPath};
 use camino::Utf8Component;
 use smallvec::SmallVec;
 use syntax::ast::{self, ImportRelative};
@@ -36,7 +36,19 @@ impl AbsoluteModPath {
     ) -> Option<Self> {
         let source_root = package.data(db).source_root(db);
         let path = source_root.path_for_file(file_id.file_id(db))?;
-        let relative_path = path.strip_prefix(&package.data(db).root)?;
+        Self::for_path(&package.data(db).root, path)
+    }
+
+    /// Returns the absolute `package::` path of the module at `path`, in a package whose root is `root`.
+    ///
+    /// The file does not have to exist, which allows computing where a file will be after a move.
+    /// Returns none if `path` is outside of `root`.
+    #[must_use]
+    pub fn for_path(
+        root: &VfsPath,
+        path: &VfsPath,
+    ) -> Option<Self> {
+        let relative_path = path.strip_prefix(root)?;
         let segments: SmallVec<[Name; 1]> = relative_path
             .as_utf8_path()
             .with_extension("")
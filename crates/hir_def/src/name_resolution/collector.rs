@@ -263,11 +263,39 @@ impl ModCollector<'_> {
             if modules_map.modules.contains_key(path) {
                 Ok(None)
             } else {
-                Err(DefDiagnostic::unresolved_import(self.file_id, location))
+                let alternatives = self.import_alternatives(modules_map, module, head_segments);
+                Err(DefDiagnostic::unresolved_import(
+                    self.file_id,
+                    location,
+                    name.clone(),
+                    alternatives,
+                ))
             }
         }
     }
 
+    /// The names of the modules and items inside of the module at `head_segments`.
+    fn import_alternatives(
+        &self,
+        modules_map: &ModulesMap,
+        module: Option<EditionedFileId>,
+        head_segments: &[Name],
+    ) -> Vec<Name> {
+        let mut alternatives: Vec<Name> = modules_map
+            .modules
+            .keys()
+            .filter_map(|path| match path.segments() {
+                [parent @ .., child] if parent == head_segments => Some(child.clone()),
+                _ => None,
+            })
+            .collect();
+        if let Some(module) = module {
+            let item_tree = ItemTree::of(self.db, module);
+            alternatives.extend(item_names(item_tree).cloned());
+        }
+        alternatives
+    }
+
     fn resolve_item(
         &self,
         file_id: EditionedFileId,
@@ -323,3 +351,18 @@ impl ModCollector<'_> {
             })
     }
 }
+
+fn item_names(item_tree: &ItemTree) -> impl Iterator<Item = &Name> {
+    item_tree
+        .top_level_items()
+        .iter()
+        .filter_map(|item| match *item {
+            ModuleItemId::Struct(id) => Some(&item_tree[id].name),
+            ModuleItemId::TypeAlias(id) => Some(&item_tree[id].name),
+            ModuleItemId::GlobalVariable(id) => Some(&item_tree[id].name),
+            ModuleItemId::GlobalConstant(id) => Some(&item_tree[id].name),
+            ModuleItemId::Override(id) => Some(&item_tree[id].name),
+            ModuleItemId::Function(id) => Some(&item_tree[id].name),
+            ModuleItemId::GlobalAssertStatement(_) | ModuleItemId::ImportStatement(_) => None,
+        })
+}
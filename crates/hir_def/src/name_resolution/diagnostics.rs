@@ -22,6 +22,10 @@ pub enum DefDiagnosticKind {
     },
     UnresolvedImport {
         id: Location<ast::ImportStatement>,
+        /// The last segment of the import path, which did not resolve.
+        name: Name,
+        /// The modules and items which could have been imported instead of `name`.
+        alternatives: Vec<Name>,
     },
     TooManySupers {
         id: Location<ast::ImportStatement>,
@@ -61,10 +65,16 @@ impl DefDiagnostic {
     pub(crate) const fn unresolved_import(
         container: EditionedFileId,
         id: Location<ast::ImportStatement>,
+        name: Name,
+        alternatives: Vec<Name>,
     ) -> Self {
         Self {
             in_module: container,
-            kind: DefDiagnosticKind::UnresolvedImport { id },
+            kind: DefDiagnosticKind::UnresolvedImport {
+                id,
+                name,
+                alternatives,
+            },
         }
     }
 
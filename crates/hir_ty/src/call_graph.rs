@@ -0,0 +1,150 @@
+//! The static call graph between user-declared functions.
+//!
+//! Calls are resolved with the same scopes as type inference, so a call goes to
+//! the function the callee path resolves to, even if it was brought into scope by
+//! a WESL `import` from another module or package.
+
+use hir_def::{
+    attributes::{AttributeDefId, AttributesWithOwner},
+    body::{Body, scope::ExprScopes},
+    db::{DefinitionWithBodyId, FunctionId, GlobalVariableId},
+    expression::{Expression, ExpressionId},
+    resolver::{ResolveKind, Resolver},
+};
+use rustc_hash::FxHashSet;
+use triomphe::Arc;
+
+use crate::db::HirDatabase;
+
+/// The shader stage of an entry point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShaderStage {
+    Vertex,
+    Fragment,
+    Compute,
+}
+
+impl ShaderStage {
+    /// The stage attribute of the function, if it is an entry point.
+    #[must_use]
+    pub fn of(
+        db: &dyn HirDatabase,
+        function: FunctionId,
+    ) -> Option<Self> {
+        let attributes =
+            &AttributesWithOwner::of(db, AttributeDefId::Function(function)).attribute_list;
+        [Self::Vertex, Self::Fragment, Self::Compute]
+            .into_iter()
+            .find(|stage| attributes.has(stage.as_str()))
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Vertex => "vertex",
+            Self::Fragment => "fragment",
+            Self::Compute => "compute",
+        }
+    }
+}
+
+/// A call of a user-declared function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionCall {
+    pub callee: FunctionId,
+    /// The call expression in the body of the caller.
+    pub expression: ExpressionId,
+}
+
+#[salsa::tracked(returns(clone))]
+pub(crate) fn function_calls(
+    db: &dyn HirDatabase,
+    function: FunctionId,
+) -> Arc<[FunctionCall]> {
+    let body = Body::of(db, DefinitionWithBodyId::Function(function));
+    let resolver_for = expression_resolver(db, function);
+
+    body.exprs
+        .iter()
+        .filter_map(|(expression, data)| {
+            let Expression::Call {
+                ident_expression, ..
+            } = data
+            else {
+                return None;
+            };
+            if let Ok(ResolveKind::Function(callee)) =
+                resolver_for(expression).resolve(db, &ident_expression.path)
+            {
+                Some(FunctionCall { callee, expression })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// The module-scope variables which are named in the body of `function`, in order of first use.
+#[salsa::tracked(returns(clone))]
+pub(crate) fn used_global_variables(
+    db: &dyn HirDatabase,
+    function: FunctionId,
+) -> Arc<[GlobalVariableId]> {
+    let body = Body::of(db, DefinitionWithBodyId::Function(function));
+    let resolver_for = expression_resolver(db, function);
+
+    let mut seen = FxHashSet::default();
+    body.exprs
+        .iter()
+        .filter_map(|(expression, data)| {
+            let Expression::IdentExpression(ident_expression) = data else {
+                return None;
+            };
+            if let Ok(ResolveKind::GlobalVariable(variable)) =
+                resolver_for(expression).resolve(db, &ident_expression.path)
+            {
+                Some(variable)
+            } else {
+                None
+            }
+        })
+        .filter(|variable| seen.insert(*variable))
+        .collect()
+}
+
+/// Builds the resolver which sees the scope of an expression in the body of `function`.
+fn expression_resolver<'db>(
+    db: &'db dyn HirDatabase,
+    function: FunctionId,
+) -> impl Fn(ExpressionId) -> Resolver<'db> + 'db {
+    let owner = DefinitionWithBodyId::Function(function);
+    let expression_scopes = ExprScopes::of(db, owner);
+    let module_resolver = owner.resolver(db);
+    move |expression| match expression_scopes.scope_for_expression(expression) {
+        Some(scope_id) => {
+            module_resolver
+                .clone()
+                .push_expression_scope(function, expression_scopes, scope_id)
+        },
+        None => module_resolver.clone(),
+    }
+}
+
+#[salsa::tracked(returns(clone))]
+pub(crate) fn reachable_functions(
+    db: &dyn HirDatabase,
+    function: FunctionId,
+) -> Arc<[FunctionId]> {
+    // WGSL forbids recursion, but the graph of code being edited can still have cycles.
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![function];
+    let mut reachable = Vec::new();
+    while let Some(current) = stack.pop() {
+        if !visited.insert(current) {
+            continue;
+        }
+        reachable.push(current);
+        stack.extend(db.function_calls(current).iter().map(|call| call.callee));
+    }
+    reachable.into()
+}
@@ -5,7 +5,7 @@ use base_db::{EditionedFileId, Intern as _, Lookup as _, SourceDatabase};
 use hir_def::db::{Location, ModuleDefinitionId};
 use hir_def::signature::{StructSignature, TypeAliasSignature};
 use hir_def::{
-    db::{DefinitionWithBodyId, FunctionId, StructId, TypeAliasId},
+    db::{DefinitionWithBodyId, FunctionId, GlobalVariableId, StructId, TypeAliasId},
     item_scope::ItemScope,
     item_tree::ItemTree,
     resolver::Resolver,
@@ -17,6 +17,7 @@ use wgsl_types::syntax::AddressSpace;
 
 use crate::infer::get_name_and_range;
 use crate::{
+    call_graph::{self, FunctionCall},
     diagnostics::{InferenceDiagnostic, InferenceDiagnosticKind},
     function::{FunctionDetails, ResolvedFunctionId},
     infer::InferenceResult,
@@ -53,6 +54,30 @@ pub trait HirDatabase: SourceDatabase + 'static {
         type_alias_type(self.as_dyn(), key)
     }
 
+    /// The calls of user-declared functions in the body of `key`.
+    fn function_calls(
+        &self,
+        key: FunctionId,
+    ) -> Arc<[FunctionCall]> {
+        call_graph::function_calls(self.as_dyn(), key)
+    }
+
+    /// The functions which are transitively called by `key`, including `key` itself.
+    fn reachable_functions(
+        &self,
+        key: FunctionId,
+    ) -> Arc<[FunctionId]> {
+        call_graph::reachable_functions(self.as_dyn(), key)
+    }
+
+    /// The module-scope variables which are named in the body of `key`.
+    fn used_global_variables(
+        &self,
+        key: FunctionId,
+    ) -> Arc<[GlobalVariableId]> {
+        call_graph::used_global_variables(self.as_dyn(), key)
+    }
+
     fn struct_is_used_in_uniform(
         &self,
         key: StructId,
@@ -60,6 +85,14 @@ pub trait HirDatabase: SourceDatabase + 'static {
     ) -> bool {
         struct_is_used_in_uniform(self.as_dyn(), key, file_id)
     }
+
+    fn struct_is_used_in_storage(
+        &self,
+        key: StructId,
+        file_id: EditionedFileId,
+    ) -> bool {
+        struct_is_used_in_storage(self.as_dyn(), key, file_id)
+    }
 }
 
 #[salsa::db]
@@ -172,6 +205,25 @@ fn struct_is_used_in_uniform(
     db: &dyn HirDatabase,
     r#struct: StructId,
     file_id: EditionedFileId,
+) -> bool {
+    struct_is_used_in(db, r#struct, file_id, AddressSpace::Uniform)
+}
+
+#[salsa::tracked(returns(clone))]
+fn struct_is_used_in_storage(
+    db: &dyn HirDatabase,
+    r#struct: StructId,
+    file_id: EditionedFileId,
+) -> bool {
+    struct_is_used_in(db, r#struct, file_id, AddressSpace::Storage)
+}
+
+/// Whether a module-scope variable of the file has a type containing `r#struct` in `address_space`.
+fn struct_is_used_in(
+    db: &dyn HirDatabase,
+    r#struct: StructId,
+    file_id: EditionedFileId,
+    address_space: AddressSpace,
 ) -> bool {
     let module_info = ItemTree::of(db, file_id);
     module_info
@@ -184,8 +236,11 @@ fn struct_is_used_in_uniform(
                     InferenceResult::of(db, DefinitionWithBodyId::GlobalVariable(declaration));
                 let type_kind = inference.return_type().kind(db);
 
-                if let TypeKind::Reference(crate::ty::Reference { address_space, .. }) = type_kind
-                    && !matches!(address_space, AddressSpace::Uniform)
+                if let TypeKind::Reference(crate::ty::Reference {
+                    address_space: variable_address_space,
+                    ..
+                }) = type_kind
+                    && variable_address_space != address_space
                 {
                     return false;
                 }
@@ -153,6 +153,113 @@ pub fn get_name_and_range(
     }
 }
 
+/// Whether the builtin function `name` has an overload returning `expected`.
+///
+/// This asks the same overload resolution as the type inference of builtin calls.
+/// The overloads of builtin functions are computed from their arguments rather than listed,
+/// so this only tries the most common argument shapes: one to three arguments of the expected
+/// type, like `max(f32, f32) -> f32`, or of a vector of an expected scalar,
+/// like `length(vec3<f32>) -> f32`.
+///
+/// Builtins whose arguments have other shapes are never reported,
+/// for example `select`, which takes a `bool`, or the texture functions.
+#[must_use]
+pub fn builtin_function_returns(
+    db: &dyn HirDatabase,
+    name: &str,
+    expected: Type,
+) -> bool {
+    let kind = expected.kind(db);
+    if !matches!(
+        kind,
+        TypeKind::Scalar(_) | TypeKind::Vector(_) | TypeKind::Matrix(_)
+    ) {
+        return false;
+    }
+    let mut converter = WgslTypeConverter::new(db);
+    let expected_wgsl = converter.to_wgsl_types(expected);
+    let mut arguments = vec![expected_wgsl.clone()];
+    if matches!(kind, TypeKind::Scalar(_)) {
+        arguments.extend(
+            (2..=4).map(|size| wgsl_types::Type::Vec(size, Box::new(expected_wgsl.clone()))),
+        );
+    }
+    arguments.into_iter().any(|argument| {
+        (1..=3).any(|count| {
+            let arguments = vec![argument.clone(); count];
+            matches!(
+                wgsl_types::builtin::type_builtin_fn(name, None, &arguments),
+                Ok(Some(r#type)) if converter.from_wgsl_types(r#type) == expected
+            )
+        })
+    })
+}
+
+/// The scalar type of a concrete numeric scalar, vector or matrix.
+fn concrete_numeric_scalar(
+    r#type: Type,
+    db: &dyn HirDatabase,
+) -> Option<ScalarType> {
+    let scalar = match r#type.kind(db) {
+        TypeKind::Scalar(scalar) => scalar,
+        TypeKind::Vector(VectorType { component_type, .. }) => {
+            return concrete_numeric_scalar(component_type, db);
+        },
+        TypeKind::Matrix(MatrixType { inner, .. }) => return concrete_numeric_scalar(inner, db),
+        TypeKind::Error
+        | TypeKind::Atomic(_)
+        | TypeKind::Struct(_)
+        | TypeKind::BuiltinStruct(_)
+        | TypeKind::Array(_)
+        | TypeKind::Texture(_)
+        | TypeKind::Sampler(_)
+        | TypeKind::Reference(_)
+        | TypeKind::Pointer(_) => return None,
+    };
+    matches!(
+        scalar,
+        ScalarType::I32 | ScalarType::U32 | ScalarType::F32 | ScalarType::F16
+    )
+    .then_some(scalar)
+}
+
+/// A scalar, vector or matrix like `r#type`, but of the scalar type `scalar`.
+fn with_scalar(
+    r#type: Type,
+    scalar: ScalarType,
+    db: &dyn HirDatabase,
+) -> Option<Type> {
+    let kind = match r#type.kind(db) {
+        TypeKind::Scalar(_) => TypeKind::Scalar(scalar),
+        TypeKind::Vector(VectorType {
+            size,
+            component_type,
+        }) => TypeKind::Vector(VectorType {
+            size,
+            component_type: with_scalar(component_type, scalar, db)?,
+        }),
+        TypeKind::Matrix(MatrixType {
+            columns,
+            rows,
+            inner,
+        }) => TypeKind::Matrix(MatrixType {
+            columns,
+            rows,
+            inner: with_scalar(inner, scalar, db)?,
+        }),
+        TypeKind::Error
+        | TypeKind::Atomic(_)
+        | TypeKind::Struct(_)
+        | TypeKind::BuiltinStruct(_)
+        | TypeKind::Array(_)
+        | TypeKind::Texture(_)
+        | TypeKind::Sampler(_)
+        | TypeKind::Reference(_)
+        | TypeKind::Pointer(_) => return None,
+    };
+    Some(kind.intern(db))
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 struct InternedStandardTypes {
     unknown: Type,
@@ -174,6 +281,7 @@ pub struct InferenceResult {
     return_type: Type,
     call_resolutions: FxHashMap<ExpressionId, ResolvedCall>,
     field_resolutions: FxHashMap<ExpressionId, FieldId>,
+    conversions: FxHashMap<ExpressionId, Type>,
     standard_types: InternedStandardTypes,
 }
 
@@ -186,6 +294,7 @@ impl InferenceResult {
             return_type: TypeKind::Error.intern(db),
             call_resolutions: FxHashMap::default(),
             field_resolutions: FxHashMap::default(),
+            conversions: FxHashMap::default(),
             standard_types: InternedStandardTypes::new(db),
         }
     }
@@ -206,6 +315,15 @@ impl InferenceResult {
         self.call_resolutions.get(&expression).copied()
     }
 
+    /// Returns the concrete type which the abstract value of `expression` is converted to, if any.
+    #[must_use]
+    pub fn conversion(
+        &self,
+        expression: ExpressionId,
+    ) -> Option<Type> {
+        self.conversions.get(&expression).copied()
+    }
+
     #[must_use]
     pub fn diagnostics(&self) -> &[InferenceDiagnostic] {
         &self.diagnostics
@@ -223,6 +341,7 @@ impl InferenceResult {
             && self.diagnostics.is_empty()
             && self.call_resolutions.is_empty()
             && self.field_resolutions.is_empty()
+            && self.conversions.is_empty()
     }
 }
 
@@ -320,6 +439,82 @@ impl<'db> InferenceContext<'db> {
         self.return_type = r#type.unwrap_or_else(|| self.error_type());
     }
 
+    /// Records that the value of `expression`, which has the type `actual`, is automatically converted to `expected`.
+    ///
+    /// Only conversions of abstract values to concrete types are recorded.
+    fn record_conversion(
+        &mut self,
+        expression: ExpressionId,
+        actual: Type,
+        expected: Type,
+    ) {
+        if actual.kind(self.db).is_abstract(self.db)
+            && !expected.kind(self.db).is_abstract(self.db)
+            && actual
+                .conversion_rank(expected, self.db)
+                .is_some_and(|rank| rank > 0)
+        {
+            self.result.conversions.insert(expression, expected);
+        }
+    }
+
+    /// Records the conversions of the abstract operands of an operator or builtin function call.
+    ///
+    /// The overloads of operators and builtins are not known by parameter, so the abstract operands
+    /// are first converted to the numeric scalar type of the concrete operands, and otherwise
+    /// concretized. A conversion is only recorded if `result_of` still gives `result` for it.
+    fn record_operand_conversions(
+        &mut self,
+        operands: &[(ExpressionId, Type)],
+        result: Type,
+        mut result_of: impl FnMut(&mut WgslTypeConverter<'db>, &[Type]) -> Option<Type>,
+    ) {
+        let db = self.db;
+        let operands = operands
+            .iter()
+            .map(|&(expression, r#type)| (expression, r#type.loaded(db)))
+            .collect_vec();
+        let abstract_operands = operands
+            .iter()
+            .filter(|(_, r#type)| r#type.kind(db).is_abstract(db))
+            .count();
+        // Only a mix of abstract and concrete operands converts the abstract ones.
+        if abstract_operands == 0 || abstract_operands == operands.len() {
+            return;
+        }
+        let concrete_scalar = operands
+            .iter()
+            .find_map(|(_, r#type)| concrete_numeric_scalar(*r#type, db));
+        let candidates = [
+            concrete_scalar.map(|scalar| {
+                operands
+                    .iter()
+                    .map(|(_, r#type)| {
+                        if r#type.kind(db).is_abstract(db) {
+                            with_scalar(*r#type, scalar, db).unwrap_or(*r#type)
+                        } else {
+                            *r#type
+                        }
+                    })
+                    .collect_vec()
+            }),
+            Some(
+                operands
+                    .iter()
+                    .map(|(_, r#type)| r#type.concretize(db))
+                    .collect_vec(),
+            ),
+        ];
+        let Some(converted) = candidates.into_iter().flatten().find(|candidate| {
+            result_of(&mut self.converter, candidate).is_some_and(|r#type| r#type == result)
+        }) else {
+            return;
+        };
+        for ((expression, actual), expected) in operands.into_iter().zip(converted) {
+            self.record_conversion(expression, actual, expected);
+        }
+    }
+
     fn set_field_resolution(
         &mut self,
         expression: ExpressionId,
@@ -924,7 +1119,9 @@ impl<'db> InferenceContext<'db> {
             (None, Some(initializer)) => {
                 let r#type = self.infer_expression(initializer, store).loaded(self.db);
                 if abstract_handling == AbstractHandling::Concretize {
-                    r#type.concretize(self.db)
+                    let concrete_type = r#type.concretize(self.db);
+                    self.record_conversion(initializer, r#type, concrete_type);
+                    concrete_type
                 } else {
                     r#type
                 }
@@ -986,6 +1183,9 @@ impl<'db> InferenceContext<'db> {
 
         match expected {
             TypeExpectation::Type(expected_type) => {
+                if let TypeExpectationInner::Exact(expected_type) = expected_type {
+                    self.record_conversion(expression, r#type, expected_type);
+                }
                 if !r#type.is_err(self.db)
                     && self.expect_type_inner(r#type, expected_type) != Ok(())
                 {
@@ -1266,6 +1466,7 @@ impl<'db> InferenceContext<'db> {
             for (expected, (actual_expression, actual_type)) in
                 function.parameters().zip(arguments.iter().copied())
             {
+                self.record_conversion(actual_expression, actual_type, expected);
                 if !actual_type.is_convertible_to(expected, self.db) {
                     self.push_diagnostic(
                         store.store_source,
@@ -1351,7 +1552,28 @@ impl<'db> InferenceContext<'db> {
             &self.converter.to_wgsl_types(left_type.loaded(self.db)),
             &self.converter.to_wgsl_types(right_type.loaded(self.db)),
         ) {
-            Ok(r#type) => self.converter.from_wgsl_types(r#type),
+            Ok(r#type) => {
+                let r#type = self.converter.from_wgsl_types(r#type);
+                self.record_operand_conversions(
+                    &[(left_side, left_type), (right_side, right_type)],
+                    r#type,
+                    |converter, operands| {
+                        let [left, right] = operands else {
+                            return None;
+                        };
+                        let left = converter.to_wgsl_types(*left);
+                        let right = converter.to_wgsl_types(*right);
+                        wgsl_types::builtin::type_binary_op(
+                            to_wgsl_binary_operator(operation),
+                            &left,
+                            &right,
+                        )
+                        .ok()
+                        .map(|r#type| converter.from_wgsl_types(r#type))
+                    },
+                );
+                r#type
+            },
             Err(error) => {
                 self.push_diagnostic(
                     store.store_source,
@@ -1592,7 +1814,7 @@ impl<'db> InferenceContext<'db> {
                     // );
                     return self.error_type();
                 }
-                self.infer_builtin_function(expression, &argument_types, store, template, &name)
+                self.infer_builtin_function(expression, arguments, store, template, &name)
             },
             Lowered::Enumerant(_)
             | Lowered::GlobalConstant(_)
@@ -1650,12 +1872,13 @@ impl<'db> InferenceContext<'db> {
     fn infer_builtin_function(
         &mut self,
         expression: ExpressionId,
-        argument_types: &[Type],
+        arguments: &[(ExpressionId, Type)],
         store: &ExpressionStore,
         template_parameters: Option<TemplateParameters>,
         name: &Name,
     ) -> Type {
-        let wgsl_arguments = self.converter.to_wt_vec(argument_types);
+        let argument_types = arguments.iter().map(|(_, r#type)| *r#type).collect_vec();
+        let wgsl_arguments = self.converter.to_wt_vec(&argument_types);
         let Ok(template) = self.converter.to_maybe_vec_template(template_parameters) else {
             // assert fails with something like `sqrt<&y>(1)`
             // debug_assert!(
@@ -1670,7 +1893,21 @@ impl<'db> InferenceContext<'db> {
             &wgsl_arguments,
         );
         match return_type {
-            Ok(Some(r#type)) => self.converter.from_wgsl_types(r#type),
+            Ok(Some(r#type)) => {
+                let r#type = self.converter.from_wgsl_types(r#type);
+                self.record_operand_conversions(arguments, r#type, |converter, arguments| {
+                    let arguments = converter.to_wt_vec(arguments);
+                    wgsl_types::builtin::type_builtin_fn(
+                        name.as_str(),
+                        template.as_deref(),
+                        &arguments,
+                    )
+                    .ok()
+                    .flatten()
+                    .map(|r#type| converter.from_wgsl_types(r#type))
+                });
+                r#type
+            },
             Ok(None) => self.error_type(), // just a function that doesn't have a return value
             Err(error) => {
                 self.push_diagnostic(
@@ -1753,6 +1990,7 @@ impl<'db> InferenceContext<'db> {
         }
         // validate types
         for (argument_expression, argument_type) in arguments {
+            self.record_conversion(*argument_expression, *argument_type, array_type.inner);
             if !argument_type.is_convertible_to(array_type.inner, self.db) {
                 self.push_diagnostic(
                     store.store_source,
@@ -2113,6 +2351,7 @@ impl<'db> InferenceContext<'db> {
         for ((_, field_type), (argument_expression, argument_type)) in
             field_types.iter().zip(arguments.iter())
         {
+            self.record_conversion(*argument_expression, *argument_type, *field_type);
             if !argument_type.is_convertible_to(*field_type, self.db) {
                 self.push_diagnostic(
                     store.store_source,
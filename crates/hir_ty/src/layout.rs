@@ -257,6 +257,7 @@ impl TypeKind {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FieldLayout {
     pub offset: Bytes,
     pub align: Bytes,
@@ -275,8 +276,7 @@ where
 {
     let mut struct_align = Bytes::MIN;
 
-    let mut offset = 0;
-    let mut last_member_size = None;
+    let mut just_past_last_member = None;
 
     for (field_id, &field) in fields.iter() {
         // TODO: handle @align and @size
@@ -289,6 +289,10 @@ where
 
         struct_align = struct_align.max(align);
 
+        // The offset of a member is the end of the previous member, rounded up to the alignment
+        // of this member.
+        let offset = round_up(align, just_past_last_member.unwrap_or(0));
+
         on_field(
             field_id,
             field,
@@ -299,12 +303,10 @@ where
             },
         );
 
-        let new_offset = round_up(align, offset + size);
-        last_member_size = Some(size);
-        offset = new_offset;
+        just_past_last_member = Some(offset + size);
     }
 
-    let just_past_last_member = offset + last_member_size?;
+    let just_past_last_member = just_past_last_member?;
     let struct_size = round_up(struct_align, just_past_last_member);
     let struct_align = if address_space == AddressSpace::Uniform {
         round_up(16, struct_align)
@@ -2,6 +2,7 @@
 //! The type system. We currently use this to infer types for completion, hover
 //! information and various assists.
 
+pub mod call_graph;
 pub mod db;
 pub mod function;
 pub mod infer;
@@ -12,7 +13,7 @@ pub mod validate;
 pub mod diagnostics;
 pub mod lower;
 
-pub use wgsl_types::syntax::AddressSpace;
+pub use wgsl_types::syntax::{AccessMode, AddressSpace};
 
 #[cfg(test)]
 mod test_db;
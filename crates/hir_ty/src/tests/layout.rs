@@ -153,9 +153,9 @@ fn example_layout_of_structures_using_implicit_member_sizes_and_alignments() {
             }
             struct B {                                     //             align(16)  size(160)
                 a: vec2<f32>,                              // offset(0)  align(8)  size(8)
-                b: vec3<f32>,                              // offset(8)  align(16)  size(12)
-                c: f32,                                    // offset(32)  align(4)  size(4)
-                d: f32,                                    // offset(36)  align(4)  size(4)
+                b: vec3<f32>,                              // offset(16)  align(16)  size(12)
+                c: f32,                                    // offset(28)  align(4)  size(4)
+                d: f32,                                    // offset(32)  align(4)  size(4)
                 e: A,                                      // offset(40)  align(8)  size(24)
                 f: vec3<f32>,                              // offset(64)  align(16)  size(12)
                 g: array<A, 3>,                            // offset(80)  align(8)  size(72)
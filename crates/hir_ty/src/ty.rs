@@ -73,6 +73,17 @@ impl Type {
         self.kind(db).is_convertible_to(&r#type.kind(db), db)
     }
 
+    /// Returns the [conversion rank](https://www.w3.org/TR/WGSL/#conversion-rank) from `self` to `r#type`.
+    ///
+    /// Returns `None` if there is no feasible automatic conversion.
+    pub fn conversion_rank(
+        self,
+        r#type: Self,
+        db: &dyn HirDatabase,
+    ) -> Option<u32> {
+        conversion_rank(&self.kind(db), &r#type.kind(db), db)
+    }
+
     #[expect(clippy::doc_paragraphs_missing_punctuation, reason = "false positive")]
     /// The type T is the concretization of type S if:
     /// - T is concrete, and
@@ -0,0 +1,9 @@
+//! Settings for tweaking assists.
+
+use crate::AssistKind;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssistConfig {
+    /// The kinds of assists the client asked for, or `None` for all of them.
+    pub allowed: Option<Vec<AssistKind>>,
+}
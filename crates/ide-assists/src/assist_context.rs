@@ -0,0 +1,202 @@
+//! See [`AssistContext`].
+
+use base_db::{EditionedFileId, FileId, FileRange, TextRange, TextSize};
+use hir::Semantics;
+use ide_db::{
+    RootDatabase,
+    assists::{Assist, AssistId, AssistKind, AssistResolveStrategy, GroupLabel},
+    source_change::SourceChange,
+    text_edit::TextEditBuilder,
+};
+use syntax::{AstNode, SyntaxKind, SyntaxToken, algorithms, ast};
+
+use crate::AssistConfig;
+
+/// `AssistContext` allows to check whether an assist applies, and to compute its edit.
+///
+/// The assists workflow consists of two phases. In the first phase, the client asks
+/// for the list of available assists, which only needs their labels. In the second
+/// phase, the user picks a particular assist and the client resolves its edit.
+/// There is no guarantee that the file did not change in between, so each phase
+/// runs the handler from scratch, and only computes the edit when it is asked for.
+pub(crate) struct AssistContext<'db> {
+    pub(crate) config: &'db AssistConfig,
+    pub(crate) semantics: Semantics<'db>,
+    frange: FileRange,
+    file_id: EditionedFileId,
+    source_file: ast::SourceFile,
+}
+
+impl<'db> AssistContext<'db> {
+    pub(crate) fn new(
+        db: &'db RootDatabase,
+        config: &'db AssistConfig,
+        frange: FileRange,
+    ) -> Self {
+        let semantics = Semantics::new(db);
+        let file_id = EditionedFileId::from_file(db, frange.file_id);
+        let source_file = semantics.parse(file_id);
+        Self {
+            config,
+            semantics,
+            frange,
+            file_id,
+            source_file,
+        }
+    }
+
+    pub(crate) const fn file_id(&self) -> EditionedFileId {
+        self.file_id
+    }
+
+    pub(crate) const fn offset(&self) -> TextSize {
+        self.frange.range.start()
+    }
+
+    pub(crate) fn find_token_syntax_at_offset(
+        &self,
+        kind: SyntaxKind,
+    ) -> Option<SyntaxToken> {
+        self.source_file
+            .syntax()
+            .token_at_offset(self.offset())
+            .find(|token| token.kind() == kind)
+    }
+
+    pub(crate) fn find_node_at_offset<Node: AstNode>(&self) -> Option<Node> {
+        algorithms::find_node_at_offset(self.source_file.syntax(), self.offset())
+    }
+}
+
+/// Collects the assists of all handlers, resolving only those asked for.
+pub(crate) struct Assists {
+    file: FileId,
+    resolve: AssistResolveStrategy,
+    assists: Vec<Assist>,
+    allowed: Option<Vec<AssistKind>>,
+}
+
+impl Assists {
+    pub(crate) fn new(
+        context: &AssistContext<'_>,
+        resolve: AssistResolveStrategy,
+    ) -> Self {
+        Self {
+            file: context.frange.file_id,
+            resolve,
+            assists: Vec::new(),
+            allowed: context.config.allowed.clone(),
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Vec<Assist> {
+        self.assists.sort_by_key(|assist| assist.target.len());
+        self.assists
+    }
+
+    pub(crate) fn add(
+        &mut self,
+        id: AssistId,
+        label: impl Into<String>,
+        target: TextRange,
+        edit: impl FnOnce(&mut AssistBuilder),
+    ) -> Option<()> {
+        self.add_impl(None, id, label.into(), target, edit)
+    }
+
+    /// Adds an assist which is shown together with the other assists of the `group`.
+    pub(crate) fn add_group(
+        &mut self,
+        group: &GroupLabel,
+        id: AssistId,
+        label: impl Into<String>,
+        target: TextRange,
+        edit: impl FnOnce(&mut AssistBuilder),
+    ) -> Option<()> {
+        self.add_impl(Some(group), id, label.into(), target, edit)
+    }
+
+    fn add_impl(
+        &mut self,
+        group: Option<&GroupLabel>,
+        id: AssistId,
+        label: String,
+        target: TextRange,
+        edit: impl FnOnce(&mut AssistBuilder),
+    ) -> Option<()> {
+        if !self.is_allowed(&id) {
+            return None;
+        }
+
+        let source_change = self.resolve.should_resolve(&id).then(|| {
+            let mut builder = AssistBuilder::new(self.file);
+            edit(&mut builder);
+            builder.finish()
+        });
+
+        self.assists.push(Assist {
+            id,
+            label,
+            group: group.cloned(),
+            target,
+            source_change,
+        });
+        Some(())
+    }
+
+    fn is_allowed(
+        &self,
+        id: &AssistId,
+    ) -> bool {
+        self.allowed.as_ref().is_none_or(|allowed| {
+            allowed
+                .iter()
+                .any(|allowed_kind| allowed_kind.contains(id.kind))
+        })
+    }
+}
+
+/// Records the edits of a single assist.
+pub(crate) struct AssistBuilder {
+    file_id: FileId,
+    edit: TextEditBuilder,
+}
+
+impl AssistBuilder {
+    fn new(file_id: FileId) -> Self {
+        Self {
+            file_id,
+            edit: TextEditBuilder::default(),
+        }
+    }
+
+    /// Remove specified `range` of text.
+    pub(crate) fn delete(
+        &mut self,
+        range: TextRange,
+    ) {
+        self.edit.delete(range);
+    }
+
+    /// Append specified `text` at the given `offset`.
+    pub(crate) fn insert(
+        &mut self,
+        offset: TextSize,
+        text: impl Into<String>,
+    ) {
+        self.edit.insert(offset, text.into());
+    }
+
+    /// Replaces specified `range` of text with a given string.
+    pub(crate) fn replace(
+        &mut self,
+        range: TextRange,
+        replace_with: impl Into<String>,
+    ) {
+        self.edit.replace(range, replace_with.into());
+    }
+
+    fn finish(self) -> SourceChange {
+        SourceChange::from_text_edit(self.file_id, self.edit.finish())
+    }
+}
@@ -0,0 +1,141 @@
+use base_db::Intern as _;
+use hir_ty::ty::pretty::pretty_type;
+use syntax::{AstNode as _, HasName as _, ast};
+
+use crate::{AssistContext, AssistId, Assists};
+
+// Assist: add_explicit_type
+//
+// Specify the type of a `let` or `var` declaration.
+//
+// ```
+// fn brightness() -> f32 { return 1.0; }
+// fn main() {
+//     let $0value = brightness();
+// }
+// ```
+// ->
+// ```
+// fn brightness() -> f32 { return 1.0; }
+// fn main() {
+//     let value: f32 = brightness();
+// }
+// ```
+pub(crate) fn add_explicit_type(
+    accumulator: &mut Assists,
+    context: &AssistContext<'_>,
+) -> Option<()> {
+    let (name, r#type, init) = context
+        .find_node_at_offset::<ast::LetDeclaration>()
+        .map(|declaration| (declaration.name(), declaration.r#type(), declaration.init()))
+        .or_else(|| {
+            let declaration = context.find_node_at_offset::<ast::VariableDeclaration>()?;
+            Some((declaration.name(), declaration.r#type(), declaration.init()))
+        })?;
+    let name = name?;
+    if r#type.is_some() {
+        return None;
+    }
+    // The assist is about the declaration, not about the expression it is initialized with.
+    if init.is_some_and(|init| {
+        init.syntax()
+            .text_range()
+            .contains_inclusive(context.offset())
+    }) {
+        return None;
+    }
+
+    let db = context.semantics.db;
+    let container = context
+        .semantics
+        .find_container(context.file_id(), name.syntax())?
+        .as_def_with_body_id()?;
+    let r#type = context
+        .semantics
+        .analyze(container)
+        .type_of_binding(&name)?;
+    if r#type.is_err(db) {
+        return None;
+    }
+    // `var` bindings are references to their store type, and abstract values are concretized.
+    let mut kind = r#type.kind(db).unref(db).into_owned();
+    if kind.is_abstract(db) {
+        kind = kind.concretize(db)?;
+    }
+    let type_text = pretty_type(db, kind.intern(db));
+
+    let name_range = name.syntax().text_range();
+    accumulator.add(
+        AssistId::refactor_rewrite("add_explicit_type"),
+        format!("Insert explicit type `{type_text}`"),
+        name_range,
+        |builder| builder.insert(name_range.end(), format!(": {type_text}")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_explicit_type;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn add_explicit_type_concretizes_abstract_values() {
+        check_assist(
+            add_explicit_type,
+            "
+            fn main() {
+                var cou$0nt = 1;
+            }
+            ",
+            "
+            fn main() {
+                var count: i32 = 1;
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn add_explicit_type_uses_struct_name() {
+        check_assist(
+            add_explicit_type,
+            "
+            struct Light { intensity: f32 }
+            fn main() {
+                let$0 light = Light(1.0);
+            }
+            ",
+            "
+            struct Light { intensity: f32 }
+            fn main() {
+                let light: Light = Light(1.0);
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn add_explicit_type_not_applicable_with_type() {
+        check_assist_not_applicable(
+            add_explicit_type,
+            "
+            fn main() {
+                let $0value: f32 = 1.0;
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn add_explicit_type_not_applicable_in_initializer() {
+        check_assist_not_applicable(
+            add_explicit_type,
+            "
+            fn brightness() -> f32 { return 1.0; }
+            fn main() {
+                let value = bright$0ness();
+            }
+            ",
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use syntax::SyntaxKind;
+
+use crate::{AssistContext, AssistId, Assists, GroupLabel};
+
+// Assist: convert_integer_literal
+//
+// Converts an integer literal to another base, or changes its suffix.
+//
+// ```
+// const MASK = 255$0u;
+// ```
+// ->
+// ```
+// const MASK = 0xffu;
+// ```
+pub(crate) fn convert_integer_literal(
+    accumulator: &mut Assists,
+    context: &AssistContext<'_>,
+) -> Option<()> {
+    let literal = context.find_token_syntax_at_offset(SyntaxKind::IntLiteral)?;
+    let text = literal.text();
+    let (number, suffix) = text.split_at(text.trim_end_matches(['i', 'u']).len());
+    let (radix, digits) = match number
+        .strip_prefix("0x")
+        .or_else(|| number.strip_prefix("0X"))
+    {
+        Some(digits) => (Radix::Hexadecimal, digits),
+        None => (Radix::Decimal, number),
+    };
+    let value = u64::from_str_radix(digits, radix.value()).ok()?;
+
+    let other_bases = [Radix::Decimal, Radix::Hexadecimal]
+        .into_iter()
+        .filter(|&other| other != radix)
+        .map(|other| other.render(value, suffix));
+    let other_suffixes = ["", "i", "u"]
+        .into_iter()
+        .filter(|&other| other != suffix && fits(value, other))
+        .map(|other| radix.render(value, other));
+
+    let range = literal.text_range();
+    let group = GroupLabel("Convert integer literal".to_owned());
+    for (index, converted) in other_bases.chain(other_suffixes).enumerate() {
+        let id = AssistId {
+            group_index: Some(index),
+            ..AssistId::refactor_rewrite("convert_integer_literal")
+        };
+        let label = format!("Convert {text} to {converted}");
+        accumulator.add_group(&group, id, label, range, |builder| {
+            builder.replace(range, converted);
+        });
+    }
+    Some(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Radix {
+    Decimal,
+    Hexadecimal,
+}
+
+impl Radix {
+    const fn value(self) -> u32 {
+        match self {
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+        }
+    }
+
+    fn render(
+        self,
+        value: u64,
+        suffix: &str,
+    ) -> String {
+        match self {
+            Self::Decimal => format!("{value}{suffix}"),
+            Self::Hexadecimal => format!("{value:#x}{suffix}"),
+        }
+    }
+}
+
+/// Whether the value can be represented by a literal with the suffix.
+fn fits(
+    value: u64,
+    suffix: &str,
+) -> bool {
+    match suffix {
+        "i" => value <= u64::from(i32::MAX.unsigned_abs()),
+        "u" => value <= u64::from(u32::MAX),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convert_integer_literal;
+    use crate::tests::{check_assist_by_label, check_assist_not_applicable};
+
+    #[test]
+    fn convert_hexadecimal_to_decimal() {
+        check_assist_by_label(
+            convert_integer_literal,
+            "const MASK = 0x$0FF;",
+            "const MASK = 255;",
+            "Convert 0xFF to 255",
+        );
+    }
+
+    #[test]
+    fn convert_integer_literal_suffix() {
+        check_assist_by_label(
+            convert_integer_literal,
+            "const COUNT = 1$024;",
+            "const COUNT = 1024u;",
+            "Convert 1024 to 1024u",
+        );
+    }
+
+    #[test]
+    fn convert_integer_literal_not_applicable_for_float() {
+        check_assist_not_applicable(convert_integer_literal, "const SCALE = 1.5$0;");
+    }
+}
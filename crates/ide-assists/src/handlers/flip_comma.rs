@@ -0,0 +1,92 @@
+use syntax::{Direction, SyntaxKind, algorithms::non_trivia_sibling};
+
+use crate::{AssistContext, AssistId, Assists};
+
+// Assist: flip_comma
+//
+// Flips two comma-separated items.
+//
+// ```
+// fn main() {
+//     let color = vec3f(0.1,$0 0.2, 0.3);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let color = vec3f(0.2, 0.1, 0.3);
+// }
+// ```
+pub(crate) fn flip_comma(
+    accumulator: &mut Assists,
+    context: &AssistContext<'_>,
+) -> Option<()> {
+    let comma = context.find_token_syntax_at_offset(SyntaxKind::Comma)?;
+    let previous = non_trivia_sibling(comma.clone().into(), Direction::Prev)?.into_node()?;
+    // A trailing comma is followed by a token, such as `)`, and has nothing to flip with.
+    let next = non_trivia_sibling(comma.clone().into(), Direction::Next)?.into_node()?;
+
+    accumulator.add(
+        AssistId::refactor_rewrite("flip_comma"),
+        "Flip comma",
+        comma.text_range(),
+        |builder| {
+            builder.replace(previous.text_range(), next.to_string());
+            builder.replace(next.text_range(), previous.to_string());
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::flip_comma;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn flip_comma_works_for_function_parameters() {
+        check_assist(
+            flip_comma,
+            "
+            fn shade(normal: vec3f,$0 light: vec3f) -> f32 {
+                return dot(normal, light);
+            }
+            ",
+            "
+            fn shade(light: vec3f, normal: vec3f) -> f32 {
+                return dot(normal, light);
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn flip_comma_works_for_struct_members() {
+        check_assist(
+            flip_comma,
+            "
+            struct Light {
+                color: vec3f,$0
+                intensity: f32,
+            }
+            ",
+            "
+            struct Light {
+                intensity: f32,
+                color: vec3f,
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn flip_comma_not_applicable_for_trailing_comma() {
+        check_assist_not_applicable(
+            flip_comma,
+            "
+            fn main() {
+                let color = vec3f(0.1, 0.2, 0.3,$0);
+            }
+            ",
+        );
+    }
+}
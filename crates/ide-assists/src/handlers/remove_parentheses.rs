@@ -0,0 +1,132 @@
+use syntax::{AstNode as _, ast};
+
+use crate::{AssistContext, AssistId, Assists};
+
+// Assist: remove_parentheses
+//
+// Removes redundant parentheses.
+//
+// ```
+// fn main() {
+//     let value = $0(1.0 + 2.0);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let value = 1.0 + 2.0;
+// }
+// ```
+pub(crate) fn remove_parentheses(
+    accumulator: &mut Assists,
+    context: &AssistContext<'_>,
+) -> Option<()> {
+    let parentheses = context.find_node_at_offset::<ast::ParenthesisExpression>()?;
+    let left_parenthesis = parentheses.left_parenthesis_token()?;
+    let right_parenthesis = parentheses.right_parenthesis_token()?;
+
+    let offset = context.offset();
+    if !left_parenthesis.text_range().contains_inclusive(offset)
+        && !right_parenthesis.text_range().contains_inclusive(offset)
+    {
+        return None;
+    }
+
+    let inner = parentheses.inner()?;
+    let is_operand = parentheses
+        .syntax()
+        .parent()
+        .is_some_and(|parent| ast::Expression::can_cast(parent.kind()));
+    // WGSL has no precedence between most binary operators, and `-(-x)` would become `--x`,
+    // so operations only lose their parentheses when they are not an operand themselves.
+    if is_operand && !is_primary(&inner) {
+        return None;
+    }
+
+    accumulator.add(
+        AssistId::refactor_rewrite("remove_parentheses"),
+        "Remove redundant parentheses",
+        parentheses.syntax().text_range(),
+        |builder| {
+            builder.delete(left_parenthesis.text_range());
+            builder.delete(right_parenthesis.text_range());
+        },
+    )
+}
+
+const fn is_primary(expression: &ast::Expression) -> bool {
+    match expression {
+        ast::Expression::Literal(_)
+        | ast::Expression::IdentExpression(_)
+        | ast::Expression::FunctionCall(_)
+        | ast::Expression::ParenthesisExpression(_)
+        | ast::Expression::FieldExpression(_)
+        | ast::Expression::IndexExpression(_) => true,
+        ast::Expression::InfixExpression(_) | ast::Expression::PrefixExpression(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_parentheses;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn remove_parentheses_around_operand() {
+        check_assist(
+            remove_parentheses,
+            "
+            fn main() {
+                let value = 2.0 * $0(1.0);
+            }
+            ",
+            "
+            fn main() {
+                let value = 2.0 * 1.0;
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn remove_parentheses_around_argument() {
+        check_assist(
+            remove_parentheses,
+            "
+            fn main() {
+                let value = max(1.0, (2.0 * 3.0)$0);
+            }
+            ",
+            "
+            fn main() {
+                let value = max(1.0, 2.0 * 3.0);
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn remove_parentheses_not_applicable_for_operation_operand() {
+        check_assist_not_applicable(
+            remove_parentheses,
+            "
+            fn main() {
+                let value = 2.0 * $0(1.0 + 3.0);
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn remove_parentheses_not_applicable_for_negation() {
+        check_assist_not_applicable(
+            remove_parentheses,
+            "
+            fn main() {
+                let value = 1;
+                let negated = -$0(-value);
+            }
+            ",
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! `assists` crate provides a bunch of code assists, also known as code actions
+//! (in LSP) or intentions (in `IntelliJ`).
+//!
+//! An assist is a micro-refactoring, which is automatically activated in
+//! certain context. For example, if the cursor is over `,`, a "flip `,`" assist
+//! becomes available.
+//!
+//! Each assist lives in its own module under `handlers`, and documents itself
+//! with an `// Assist: name` comment. `cargo xtask codegen assists-doc-tests`
+//! turns these comments into the tests in `tests/generated.rs`.
+
+mod assist_config;
+mod assist_context;
+#[cfg(test)]
+mod tests;
+
+use base_db::FileRange;
+use ide_db::RootDatabase;
+
+pub use crate::assist_config::AssistConfig;
+pub(crate) use crate::assist_context::{AssistContext, Assists};
+pub use ide_db::assists::{
+    Assist, AssistId, AssistKind, AssistResolveStrategy, GroupLabel, SingleResolve,
+};
+
+/// Return all the assists applicable at the given position.
+///
+/// Assists are returned in the "unresolved" state, that is only labels are
+/// returned, without actual edits, unless `resolve` asks for them.
+pub fn assists(
+    db: &RootDatabase,
+    config: &AssistConfig,
+    resolve: AssistResolveStrategy,
+    range: FileRange,
+) -> Vec<Assist> {
+    let _p = tracing::info_span!("assists").entered();
+    let context = AssistContext::new(db, config, range);
+    let mut accumulator = Assists::new(&context, resolve);
+    for handler in handlers::all() {
+        handler(&mut accumulator, &context);
+    }
+    accumulator.finish()
+}
+
+mod handlers {
+    use crate::{AssistContext, Assists};
+
+    pub(crate) type Handler = fn(&mut Assists, &AssistContext<'_>) -> Option<()>;
+
+    mod add_explicit_type;
+    mod convert_integer_literal;
+    mod flip_comma;
+    mod remove_parentheses;
+
+    pub(crate) fn all() -> &'static [Handler] {
+        &[
+            // These are alphabetic for the foolish consistency
+            add_explicit_type::add_explicit_type,
+            convert_integer_literal::convert_integer_literal,
+            flip_comma::flip_comma,
+            remove_parentheses::remove_parentheses,
+        ]
+    }
+}
@@ -0,0 +1,127 @@
+//! Test utilities for assists.
+//!
+//! Each handler tests itself with the helpers here, while `generated.rs` checks
+//! the examples in the `// Assist:` documentation comments of the handlers.
+
+mod generated;
+
+use base_db::{FileRange, SourceDatabase as _};
+use ide_db::RootDatabase;
+use stdx::trim_indent;
+use test_fixture::WithFixture as _;
+use test_utils::assert_eq_text;
+
+use crate::{
+    Assist, AssistConfig, AssistContext, AssistResolveStrategy, Assists, handlers::Handler,
+};
+
+pub(crate) const TEST_CONFIG: AssistConfig = AssistConfig { allowed: None };
+
+#[track_caller]
+pub(crate) fn check_assist(
+    assist: Handler,
+    before: &str,
+    after: &str,
+) {
+    check(assist, before, ExpectedResult::After(after), None);
+}
+
+/// Checks the assist of a group with the given `label`.
+#[track_caller]
+pub(crate) fn check_assist_by_label(
+    assist: Handler,
+    before: &str,
+    after: &str,
+    label: &str,
+) {
+    check(assist, before, ExpectedResult::After(after), Some(label));
+}
+
+#[track_caller]
+pub(crate) fn check_assist_not_applicable(
+    assist: Handler,
+    before: &str,
+) {
+    check(assist, before, ExpectedResult::NotApplicable, None);
+}
+
+/// Checks the example of an assist's documentation, by running all assists like the client would.
+#[track_caller]
+pub(crate) fn check_doc_test(
+    assist_id: &str,
+    before: &str,
+    after: &str,
+) {
+    let (db, file_id, range_or_offset) = RootDatabase::with_range_or_offset(before);
+    let frange = FileRange {
+        file_id,
+        range: range_or_offset.into(),
+    };
+    let assist = crate::assists(&db, &TEST_CONFIG, AssistResolveStrategy::All, frange)
+        .into_iter()
+        .find(|assist| assist.id.name == assist_id)
+        .unwrap_or_else(|| panic!("\n\nAssist is not applicable: {assist_id}\n\n"));
+    assert_eq_text!(&trim_indent(after), &apply(&db, frange, assist));
+}
+
+enum ExpectedResult<'text> {
+    NotApplicable,
+    After(&'text str),
+}
+
+#[track_caller]
+fn check(
+    handler: Handler,
+    before: &str,
+    expected: ExpectedResult<'_>,
+    label: Option<&str>,
+) {
+    let (db, file_id, range_or_offset) = RootDatabase::with_range_or_offset(before);
+    let frange = FileRange {
+        file_id,
+        range: range_or_offset.into(),
+    };
+    let context = AssistContext::new(&db, &TEST_CONFIG, frange);
+    let mut accumulator = Assists::new(&context, AssistResolveStrategy::All);
+    handler(&mut accumulator, &context);
+    let mut assists = accumulator.finish().into_iter();
+    let assist = match label {
+        Some(label) => assists.find(|assist| assist.label == label),
+        None => assists.next(),
+    };
+
+    match (assist, expected) {
+        (Some(assist), ExpectedResult::After(after)) => {
+            assert_eq_text!(&trim_indent(after), &apply(&db, frange, assist));
+        },
+        (Some(assist), ExpectedResult::NotApplicable) => {
+            panic!(
+                "assist should not be applicable, but offers `{}`",
+                assist.label
+            );
+        },
+        (None, ExpectedResult::After(_)) => panic!("code action is not applicable"),
+        (None, ExpectedResult::NotApplicable) => (),
+    }
+}
+
+fn apply(
+    db: &RootDatabase,
+    frange: FileRange,
+    assist: Assist,
+) -> String {
+    let source_change = assist
+        .source_change
+        .expect("assist did not contain any source changes");
+    assert_eq!(
+        source_change.source_file_edits.len(),
+        1,
+        "assists only edit the file they are applied in"
+    );
+    let mut text = db.file_text(frange.file_id).text(db).to_string();
+    source_change
+        .get_source_edit(frange.file_id)
+        .expect("assist edits another file")
+        .apply(&mut text);
+    text
+}
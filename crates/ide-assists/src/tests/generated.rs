@@ -0,0 +1,69 @@
+//! Generated by `cargo xtask codegen assists-doc-tests`, do not edit by hand.
+
+use super::check_doc_test;
+
+#[test]
+fn doctest_add_explicit_type() {
+    check_doc_test(
+        "add_explicit_type",
+        r#####"
+fn brightness() -> f32 { return 1.0; }
+fn main() {
+    let $0value = brightness();
+}
+"#####,
+        r#####"
+fn brightness() -> f32 { return 1.0; }
+fn main() {
+    let value: f32 = brightness();
+}
+"#####,
+    );
+}
+
+#[test]
+fn doctest_convert_integer_literal() {
+    check_doc_test(
+        "convert_integer_literal",
+        r#####"
+const MASK = 255$0u;
+"#####,
+        r#####"
+const MASK = 0xffu;
+"#####,
+    );
+}
+
+#[test]
+fn doctest_flip_comma() {
+    check_doc_test(
+        "flip_comma",
+        r#####"
+fn main() {
+    let color = vec3f(0.1,$0 0.2, 0.3);
+}
+"#####,
+        r#####"
+fn main() {
+    let color = vec3f(0.2, 0.1, 0.3);
+}
+"#####,
+    );
+}
+
+#[test]
+fn doctest_remove_parentheses() {
+    check_doc_test(
+        "remove_parentheses",
+        r#####"
+fn main() {
+    let value = $0(1.0 + 2.0);
+}
+"#####,
+        r#####"
+fn main() {
+    let value = 1.0 + 2.0;
+}
+"#####,
+    );
+}
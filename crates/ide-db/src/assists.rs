@@ -0,0 +1,178 @@
+//! This module defines the `Assist` data structure. The actual assists live in
+//! the `ide-assists` downstream crate. The data structures are defined in this
+//! low-level crate, because `ide-diagnostics` needs them as well: fixes for
+//! diagnostics and assists are the same thing under the hood.
+
+use std::{fmt, str::FromStr};
+
+use base_db::TextRange;
+
+use crate::source_change::SourceChange;
+
+#[derive(Debug, Clone)]
+pub struct Assist {
+    pub id: AssistId,
+    /// Short description of the assist, as shown in the UI.
+    pub label: String,
+    pub group: Option<GroupLabel>,
+    /// Target ranges are used to sort assists: the smaller the target range,
+    /// the more specific assist is, and so it should be sorted first.
+    pub target: TextRange,
+    /// Computing source change sometimes is much more costly then computing the
+    /// other fields. Additionally, the actual change is not required to show
+    /// the lightbulb UI, it only is needed when the user tries to apply an
+    /// assist. So, we compute it lazily: the API allow requesting assists with
+    /// or without source change. We could (and in fact, used to) distinguish
+    /// between resolved and unresolved assists at the type level, but this is
+    /// cumbersome, especially if you want to embed an assist into another data
+    /// structure, such as a diagnostic.
+    pub source_change: Option<SourceChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssistKind {
+    QuickFix,
+    Refactor,
+    RefactorRewrite,
+}
+
+impl AssistKind {
+    /// Whether assists of this kind are allowed when the client asked for `other`.
+    ///
+    /// Kinds form a hierarchy, so asking for `Refactor` also includes `RefactorRewrite`.
+    #[must_use]
+    pub const fn contains(
+        self,
+        other: Self,
+    ) -> bool {
+        match (self, other) {
+            (Self::QuickFix, Self::QuickFix)
+            | (Self::Refactor, Self::Refactor | Self::RefactorRewrite)
+            | (Self::RefactorRewrite, Self::RefactorRewrite) => true,
+            (Self::QuickFix, Self::Refactor | Self::RefactorRewrite)
+            | (Self::Refactor | Self::RefactorRewrite, Self::QuickFix)
+            | (Self::RefactorRewrite, Self::Refactor) => false,
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::QuickFix => "QuickFix",
+            Self::Refactor => "Refactor",
+            Self::RefactorRewrite => "RefactorRewrite",
+        }
+    }
+}
+
+impl FromStr for AssistKind {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "QuickFix" => Ok(Self::QuickFix),
+            "Refactor" => Ok(Self::Refactor),
+            "RefactorRewrite" => Ok(Self::RefactorRewrite),
+            unknown => Err(format!("Unknown AssistKind: '{unknown}'")),
+        }
+    }
+}
+
+/// Unique identifier of the assist, should not be shown to the user
+/// directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssistId {
+    /// The name of the handler which produced the assist, like `flip_comma`.
+    pub name: &'static str,
+    pub kind: AssistKind,
+    /// The index of the assist within its group, for assists offering several alternatives.
+    pub group_index: Option<usize>,
+}
+
+impl AssistId {
+    #[must_use]
+    pub const fn quick_fix(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: AssistKind::QuickFix,
+            group_index: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn refactor(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: AssistKind::Refactor,
+            group_index: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn refactor_rewrite(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: AssistKind::RefactorRewrite,
+            group_index: None,
+        }
+    }
+}
+
+/// A way to control how many assists to resolve during the assist resolution.
+///
+/// When an assist is resolved, its edits are calculated that might be costly to always do by default.
+#[derive(Debug, Clone, Default)]
+pub enum AssistResolveStrategy {
+    /// No assists should be resolved.
+    #[default]
+    None,
+    /// All assists should be resolved.
+    All,
+    /// Only a certain assist should be resolved.
+    Single(SingleResolve),
+}
+
+/// Hold the [`AssistId`] data of a certain assist to resolve.
+///
+/// The original id object cannot be used due to a `'static` lifetime
+/// and the requirement to construct this struct dynamically during the resolve handling.
+#[derive(Debug, Clone)]
+pub struct SingleResolve {
+    /// The id of the assist.
+    pub assist_id: String,
+    /// The kind of the assist.
+    pub assist_kind: AssistKind,
+    /// Subtype of the assist. When many assists have the same id, it differentiates among them.
+    pub assist_subtype: Option<usize>,
+}
+
+impl AssistResolveStrategy {
+    #[must_use]
+    pub fn should_resolve(
+        &self,
+        id: &AssistId,
+    ) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Single(single_resolve) => {
+                single_resolve.assist_id == id.name
+                    && single_resolve.assist_kind == id.kind
+                    && single_resolve.assist_subtype == id.group_index
+            },
+        }
+    }
+}
+
+/// The label of a group of related assists, shown as a submenu by clients which support it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupLabel(pub String);
+
+impl fmt::Display for GroupLabel {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
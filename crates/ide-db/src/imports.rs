@@ -0,0 +1,363 @@
+//! Finds the declarations which a WESL module can import, and builds the edits
+//! which add `import` statements for them.
+//!
+//! New names are merged into an existing import of the same module when there is
+//! one, so importing `Light` next to `import package::lighting::shade;` produces
+//! `import package::lighting::{shade, Light};` rather than a second statement.
+
+use std::iter;
+
+use base_db::{EditionedFileId, SourceDatabase, TextSize, file_package};
+use hir_def::name_resolution::ModulesMap;
+use itertools::Itertools as _;
+use syntax::{AstNode as _, ast};
+
+use crate::{
+    symbol_index::{FileSymbol, Query, module_symbols},
+    text_edit::TextEdit,
+};
+
+/// A declaration of another module, together with the path which imports it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportCandidate {
+    pub symbol: FileSymbol,
+    /// The segments of the import path, like `package`, `lighting` and `Light`.
+    ///
+    /// Declarations of dependencies start with the name of the dependency instead of `package`.
+    pub path: Vec<String>,
+}
+
+impl ImportCandidate {
+    #[must_use]
+    pub fn import_path(&self) -> String {
+        self.path.join("::")
+    }
+}
+
+/// Searches the declarations which `file_id` can import.
+///
+/// These are the declarations of the other modules of its package, and of the modules of its direct
+/// dependencies. The results are sorted by how well they match, exact matches first.
+#[must_use]
+pub fn import_candidates(
+    db: &dyn SourceDatabase,
+    file_id: EditionedFileId,
+    query: &Query,
+) -> Vec<ImportCandidate> {
+    let _p = tracing::info_span!("import_candidates").entered();
+    let Some(package) = file_package(db, file_id.file_id(db)) else {
+        return Vec::new();
+    };
+    let roots = iter::once(("package".to_owned(), package)).chain(
+        package
+            .data(db)
+            .dependencies
+            .iter()
+            .map(|dependency| (dependency.name.as_str().to_owned(), dependency.package(db))),
+    );
+
+    let mut matches = Vec::new();
+    for (root, package) in roots {
+        for (module_path, module) in &ModulesMap::of(db, package).modules {
+            let Some(module_file) = module.file else {
+                continue;
+            };
+            if module_file.file_id(db) == file_id.file_id(db) {
+                continue;
+            }
+            let container = iter::once(root.clone())
+                .chain(
+                    module_path
+                        .segments()
+                        .iter()
+                        .map(|segment| segment.as_str().to_owned()),
+                )
+                .collect::<Vec<_>>();
+            for symbol in module_symbols(db, module_file) {
+                let Some(rank) = query.rank(symbol) else {
+                    continue;
+                };
+                let path = container
+                    .iter()
+                    .cloned()
+                    .chain(iter::once(symbol.name.as_str().to_owned()))
+                    .collect();
+                let symbol = FileSymbol {
+                    container_name: Some(container.iter().join("::")),
+                    ..symbol.clone()
+                };
+                matches.push((rank, ImportCandidate { symbol, path }));
+            }
+        }
+    }
+
+    matches.sort_by(|(rank_a, candidate_a), (rank_b, candidate_b)| {
+        rank_a
+            .cmp(rank_b)
+            .then_with(|| candidate_a.symbol.name.cmp(&candidate_b.symbol.name))
+            .then_with(|| candidate_a.path.cmp(&candidate_b.path))
+    });
+    matches
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .take(query.limit)
+        .collect()
+}
+
+/// Builds the edit which imports the declaration at `path` into `file`.
+///
+/// The name is added to an existing import of the same module if there is one. Otherwise, a new
+/// `import` statement is added after the last one, or at the start of the file.
+#[must_use]
+pub fn insert_import(
+    file: &ast::SourceFile,
+    path: &[String],
+) -> Option<TextEdit> {
+    let (name, module_path) = path.split_last()?;
+    let imports = file
+        .syntax()
+        .children()
+        .filter_map(ast::ImportStatement::cast)
+        .collect::<Vec<_>>();
+
+    for statement in &imports {
+        let Some(tree) = statement.item() else {
+            continue;
+        };
+        match merge_target(tree, relative_prefix(statement), module_path) {
+            Some(MergeTarget::Collection(collection)) => {
+                return Some(extend_collection(&collection, name));
+            },
+            Some(MergeTarget::Item(item)) => {
+                let range = item.syntax().text_range();
+                let merged = format!("{{{}, {name}}}", item.syntax().text());
+                return Some(TextEdit::replace(range, merged));
+            },
+            None => {},
+        }
+    }
+
+    let statement = format!("import {};", path.join("::"));
+    Some(match imports.last() {
+        Some(last) => TextEdit::insert(last.syntax().text_range().end(), format!("\n{statement}")),
+        None => TextEdit::insert(TextSize::from(0), format!("{statement}\n\n")),
+    })
+}
+
+/// The part of an existing import which a new name can be added to.
+enum MergeTarget {
+    /// `{a, b}` becomes `{a, b, name}`.
+    Collection(ast::ImportCollection),
+    /// `a` becomes `{a, name}`.
+    Item(ast::ImportItem),
+}
+
+/// The segments which `package::` or `super::` at the start of `statement` stand for.
+fn relative_prefix(statement: &ast::ImportStatement) -> Vec<String> {
+    match statement.relative() {
+        Some(ast::ImportRelative::ImportPackageRelative(_)) => vec!["package".to_owned()],
+        Some(ast::ImportRelative::ImportSuperRelative(relative)) => {
+            vec!["super".to_owned(); usize::from(relative.super_count())]
+        },
+        None => Vec::new(),
+    }
+}
+
+/// Finds the place in `tree` which imports from `module_path`, given the segments before `tree`.
+fn merge_target(
+    tree: ast::ImportTree,
+    mut prefix: Vec<String>,
+    module_path: &[String],
+) -> Option<MergeTarget> {
+    match tree {
+        ast::ImportTree::ImportPath(path) => {
+            prefix.push(String::from(path.name()?.text()));
+            if !module_path.starts_with(&prefix) {
+                return None;
+            }
+            merge_target(path.item()?, prefix, module_path)
+        },
+        ast::ImportTree::ImportCollection(collection) => {
+            if prefix == module_path {
+                return Some(MergeTarget::Collection(collection));
+            }
+            collection
+                .items()
+                .find_map(|item| merge_target(item, prefix.clone(), module_path))
+        },
+        ast::ImportTree::ImportItem(item) => {
+            (prefix == module_path).then_some(MergeTarget::Item(item))
+        },
+    }
+}
+
+fn extend_collection(
+    collection: &ast::ImportCollection,
+    name: &str,
+) -> TextEdit {
+    match collection.items().last() {
+        Some(last) => TextEdit::insert(last.syntax().text_range().end(), format!(", {name}")),
+        // Right after the `{` of an empty collection.
+        None => TextEdit::insert(
+            collection.syntax().text_range().start() + TextSize::of('{'),
+            name.to_owned(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::EditionedFileId;
+    use expect_test::{Expect, expect};
+    use syntax::Edition;
+    use test_fixture::ChangeFixture;
+
+    use super::{import_candidates, insert_import};
+    use crate::{RootDatabase, symbol_index::Query};
+
+    #[track_caller]
+    fn check_candidates(
+        wa_fixture: &str,
+        query: &str,
+        expect: &Expect,
+    ) {
+        let mut db = RootDatabase::default();
+        let fixture = ChangeFixture::parse(wa_fixture);
+        db.apply_change(fixture.change);
+        let file_id = EditionedFileId::from_file(&db, fixture.files[0]);
+
+        let actual = import_candidates(&db, file_id, &Query::new(query.to_owned()))
+            .iter()
+            .map(|candidate| format!("{}\n", candidate.import_path()))
+            .collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    #[track_caller]
+    fn check_insert(
+        path: &str,
+        before: &str,
+        expect: &Expect,
+    ) {
+        let file = syntax::parse(before, Edition::Wesl2025Unstable).tree();
+        let path = path.split("::").map(ToOwned::to_owned).collect::<Vec<_>>();
+        let mut actual = before.to_owned();
+        insert_import(&file, &path).unwrap().apply(&mut actual);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn candidates_from_package_and_dependencies() {
+        check_candidates(
+            "
+//- /main.wesl package:app dependencies:shadows edition:2026_pre
+fn shade() {}
+
+//- /lighting/shadow.wesl edition:2026_pre
+const SHADOW_CASCADES: u32 = 4;
+fn sample_shadow() -> f32 { return 1.0; }
+
+//- /package.wesl package:shadows library edition:2026_pre
+fn sample_shadow_cascade() -> f32 { return 1.0; }
+
+//- /unrelated.wesl package:unrelated edition:2026_pre
+fn sample_shadow_unrelated() -> f32 { return 1.0; }
+",
+            "sample_sha",
+            &expect![[r#"
+                package::lighting::shadow::sample_shadow
+                shadows::sample_shadow_cascade
+            "#]],
+        );
+    }
+
+    #[test]
+    fn candidates_skip_own_module() {
+        check_candidates(
+            "
+//- /main.wesl edition:2026_pre
+fn shade() {}
+
+//- /lighting.wesl edition:2026_pre
+fn shade_area() {}
+",
+            "shade",
+            &expect![[r#"
+                package::lighting::shade_area
+            "#]],
+        );
+    }
+
+    #[test]
+    fn insert_first_import() {
+        check_insert(
+            "package::lighting::Light",
+            "fn main() {}\n",
+            &expect![[r#"
+                import package::lighting::Light;
+
+                fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn insert_after_last_import() {
+        check_insert(
+            "package::lighting::Light",
+            "import package::camera::Camera;\nimport shadows::sample;\n\nfn main() {}\n",
+            &expect![[r#"
+                import package::camera::Camera;
+                import shadows::sample;
+                import package::lighting::Light;
+
+                fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn merge_into_single_item() {
+        check_insert(
+            "package::lighting::Light",
+            "import package::lighting::shade;\n",
+            &expect![[r#"
+                import package::lighting::{shade, Light};
+            "#]],
+        );
+    }
+
+    #[test]
+    fn merge_into_collection() {
+        check_insert(
+            "package::lighting::Light",
+            "import package::lighting::{shade, intensity};\n",
+            &expect![[r#"
+                import package::lighting::{shade, intensity, Light};
+            "#]],
+        );
+    }
+
+    #[test]
+    fn merge_into_nested_collection() {
+        check_insert(
+            "package::lighting::Light",
+            "import package::{camera::Camera, lighting::{shade}};\n",
+            &expect![[r#"
+                import package::{camera::Camera, lighting::{shade, Light}};
+            "#]],
+        );
+    }
+
+    #[test]
+    fn merge_dependency_import() {
+        check_insert(
+            "shadows::filtering::pcf",
+            "import package::lighting::shade;\nimport shadows::filtering::poisson;\n",
+            &expect![[r#"
+                import package::lighting::shade;
+                import shadows::filtering::{poisson, pcf};
+            "#]],
+        );
+    }
+}
@@ -13,7 +13,11 @@ pub use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use salsa::{Database as _, Durability};
 use triomphe::Arc;
 
+pub mod assists;
+pub mod imports;
+pub mod search;
 pub mod source_change;
+pub mod symbol_index;
 pub mod text_edit;
 
 #[salsa_macros::db]
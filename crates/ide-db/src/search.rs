@@ -0,0 +1,450 @@
+//! Implementation of find-usages functionality.
+//!
+//! It is based on the standard ide trick: first, we run a fast text search to
+//! get a super-set of matches. Then, we confirm each match using precise
+//! name resolution.
+
+use base_db::{
+    EditionedFileId, FileExtension, FileId, Package, SourceDatabase as _, TextRange, TextSize,
+    all_packages, file_package,
+};
+use hir::{HasSource as _, HirDatabase, ModuleDef, Semantics, definition::Definition};
+use rustc_hash::{FxHashMap, FxHashSet};
+use syntax::{AstNode as _, SyntaxKind, SyntaxNode, SyntaxToken, ast};
+
+#[derive(Debug, Default, Clone)]
+pub struct UsageSearchResult {
+    pub references: FxHashMap<FileId, Vec<FileReference>>,
+}
+
+impl UsageSearchResult {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.references.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.references.values().map(Vec::len).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (FileId, &[FileReference])> + '_ {
+        self.references
+            .iter()
+            .map(|(&file_id, references)| (file_id, &**references))
+    }
+}
+
+impl IntoIterator for UsageSearchResult {
+    type Item = (FileId, Vec<FileReference>);
+    type IntoIter = std::collections::hash_map::IntoIter<FileId, Vec<FileReference>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.references.into_iter()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileReference {
+    /// The range of the reference in the original file.
+    pub range: TextRange,
+    pub category: Option<ReferenceCategory>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferenceCategory {
+    /// The reference assigns to the definition, e.g. `a = 1;` or `a += 1;`.
+    Write,
+    /// The reference reads the value of the definition.
+    Read,
+    /// The reference is the item of a WESL `import` statement.
+    Import,
+}
+
+/// A set of files, or ranges within files, to search for references in.
+///
+/// See [`SearchScope::for_definition`] for the files that might contain references to a definition.
+#[derive(Clone, Debug, Default)]
+pub struct SearchScope {
+    entries: FxHashMap<EditionedFileId, Option<TextRange>>,
+}
+
+impl SearchScope {
+    /// Builds an empty search scope.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Builds a search scope spanning the given file.
+    #[must_use]
+    pub fn single_file(file_id: EditionedFileId) -> Self {
+        Self {
+            entries: std::iter::once((file_id, None)).collect(),
+        }
+    }
+
+    /// Builds a search scope spanning the given range of a file.
+    #[must_use]
+    pub fn file_range(
+        file_id: EditionedFileId,
+        range: TextRange,
+    ) -> Self {
+        Self {
+            entries: std::iter::once((file_id, Some(range))).collect(),
+        }
+    }
+
+    /// Builds a search scope spanning the given files.
+    #[must_use]
+    pub fn files(files: impl IntoIterator<Item = EditionedFileId>) -> Self {
+        Self {
+            entries: files.into_iter().map(|file_id| (file_id, None)).collect(),
+        }
+    }
+
+    /// Builds a search scope spanning all files of all packages.
+    #[must_use]
+    pub fn all_packages(db: &dyn HirDatabase) -> Self {
+        Self::files(
+            all_packages(db)
+                .iter()
+                .flat_map(|&package| package_files(db, package)),
+        )
+    }
+
+    /// Builds a search scope spanning all files of the package and of all packages
+    /// which (transitively) depend on it.
+    #[must_use]
+    pub fn package_and_dependents(
+        db: &dyn HirDatabase,
+        package: Package,
+    ) -> Self {
+        let packages = all_packages(db);
+        let mut reached = FxHashSet::default();
+        reached.insert(package);
+        // Dependencies may be cyclic, so iterate until no new package is found.
+        loop {
+            let previous_len = reached.len();
+            for &candidate in &*packages {
+                if !reached.contains(&candidate)
+                    && candidate
+                        .data(db)
+                        .dependencies
+                        .iter()
+                        .any(|dependency| reached.contains(&dependency.package(db)))
+                {
+                    reached.insert(candidate);
+                }
+            }
+            if reached.len() == previous_len {
+                break;
+            }
+        }
+        Self::files(
+            reached
+                .into_iter()
+                .flat_map(|package| package_files(db, package)),
+        )
+    }
+
+    /// The files in which a definition can be referenced.
+    ///
+    /// For locals, this is the function the local is declared in.
+    /// For items and fields, these are all files of the package declaring them,
+    /// and of every package which (transitively) depends on it, since WESL
+    /// imports can bring them into scope there.
+    #[must_use]
+    pub fn for_definition(
+        db: &dyn HirDatabase,
+        definition: &Definition,
+    ) -> Self {
+        let file_id = match definition {
+            Definition::Local(local) => {
+                let Some(binding) = local.source(db) else {
+                    return Self::empty();
+                };
+                let function = binding
+                    .value
+                    .syntax()
+                    .ancestors()
+                    .find_map(ast::FunctionDeclaration::cast);
+                return match function {
+                    Some(function) => {
+                        Self::file_range(binding.file_id, function.syntax().text_range())
+                    },
+                    None => Self::single_file(binding.file_id),
+                };
+            },
+            Definition::Field(field) => field.source(db).map(|source| source.file_id),
+            Definition::ModuleDef(definition) => module_def_file(db, definition),
+            Definition::BuiltinFunction(_)
+            | Definition::BuiltinType(_)
+            | Definition::BuiltinTypeGenerator(_)
+            | Definition::BuiltinEnumerant(_)
+            | Definition::BuiltinDeclaration(_) => return Self::all_packages(db),
+        };
+        let Some(file_id) = file_id else {
+            return Self::empty();
+        };
+        match file_package(db, file_id.file_id(db)) {
+            Some(package) => Self::package_and_dependents(db, package),
+            None => Self::single_file(file_id),
+        }
+    }
+
+    /// Restricts the scope to the parts covered by both scopes.
+    #[must_use]
+    pub fn intersection(
+        &self,
+        other: &Self,
+    ) -> Self {
+        let entries = self
+            .entries
+            .iter()
+            .filter_map(|(file_id, &range)| {
+                let other_range = *other.entries.get(file_id)?;
+                let range = match (range, other_range) {
+                    (None, None) => None,
+                    (None, Some(range)) | (Some(range), None) => Some(range),
+                    (Some(range), Some(other_range)) => Some(range.intersect(other_range)?),
+                };
+                Some((*file_id, range))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EditionedFileId, Option<TextRange>)> + '_ {
+        self.entries
+            .iter()
+            .map(|(&file_id, &range)| (file_id, range))
+    }
+}
+
+fn package_files(
+    db: &dyn HirDatabase,
+    package: Package,
+) -> impl Iterator<Item = EditionedFileId> + '_ {
+    let source_root = package.data(db).source_root(db);
+    let files: Vec<_> = source_root
+        .iter()
+        .filter_map(|file_id| {
+            let extension = FileExtension::from_file(&source_root, file_id).ok()?;
+            Some(EditionedFileId::from_file_with_extension(
+                db, file_id, extension,
+            ))
+        })
+        .collect();
+    files.into_iter()
+}
+
+fn module_def_file(
+    db: &dyn HirDatabase,
+    definition: &ModuleDef,
+) -> Option<EditionedFileId> {
+    let file_id = match *definition {
+        ModuleDef::Function(function) => function.source(db)?.file_id,
+        ModuleDef::GlobalVariable(variable) => variable.source(db)?.file_id,
+        ModuleDef::GlobalConstant(constant) => constant.source(db)?.file_id,
+        ModuleDef::Override(r#override) => r#override.source(db)?.file_id,
+        ModuleDef::Struct(r#struct) => r#struct.source(db)?.file_id,
+        ModuleDef::TypeAlias(type_alias) => type_alias.source(db)?.file_id,
+        ModuleDef::GlobalAssertStatement(assert_statement) => assert_statement.source(db)?.file_id,
+    };
+    Some(file_id)
+}
+
+#[derive(Clone)]
+pub struct FindUsages<'a> {
+    definition: &'a Definition,
+    semantics: &'a Semantics<'a>,
+    scope: Option<&'a SearchScope>,
+}
+
+impl<'a> FindUsages<'a> {
+    #[must_use]
+    pub const fn new(
+        definition: &'a Definition,
+        semantics: &'a Semantics<'a>,
+    ) -> Self {
+        Self {
+            definition,
+            semantics,
+            scope: None,
+        }
+    }
+
+    /// Limits the search to the given scope.
+    ///
+    /// The scope is intersected with the files the definition can be referenced in.
+    #[must_use]
+    pub const fn in_scope(
+        self,
+        scope: &'a SearchScope,
+    ) -> Self {
+        Self {
+            scope: Some(scope),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn at_least_one(&self) -> bool {
+        let mut found = false;
+        self.search(&mut |_, _| {
+            found = true;
+            true
+        });
+        found
+    }
+
+    #[must_use]
+    pub fn all(self) -> UsageSearchResult {
+        let mut result = UsageSearchResult::default();
+        let db = self.semantics.db;
+        self.search(&mut |file_id, reference| {
+            result
+                .references
+                .entry(file_id.file_id(db))
+                .or_default()
+                .push(reference);
+            false
+        });
+        for references in result.references.values_mut() {
+            references.sort_by_key(|reference| reference.range.start());
+        }
+        result
+    }
+
+    /// Calls `sink` for every reference to the definition.
+    ///
+    /// The search stops once `sink` returns `true`.
+    pub fn search(
+        &self,
+        sink: &mut dyn FnMut(EditionedFileId, FileReference) -> bool,
+    ) {
+        let _p = tracing::info_span!("FindUsages::search").entered();
+        let db = self.semantics.db;
+        let Some(name) = self.definition.name(db) else {
+            return;
+        };
+
+        let mut search_scope = SearchScope::for_definition(db, self.definition);
+        if let Some(scope) = self.scope {
+            search_scope = search_scope.intersection(scope);
+        }
+
+        for (file_id, range) in search_scope.iter() {
+            let text = db.file_text(file_id.file_id(db)).text(db);
+            let search_range = range.unwrap_or_else(|| TextRange::up_to(TextSize::of(&*text)));
+            let tree = self.semantics.parse(file_id);
+
+            // Imports can bring the definition into scope under a different name.
+            let mut names = vec![name.as_str().to_owned()];
+            names.extend(
+                tree.syntax()
+                    .descendants()
+                    .filter_map(ast::ImportItem::cast)
+                    .filter_map(|item| {
+                        let alias = item.alias()?;
+                        let definition = Definition::from_name(self.semantics, file_id, &alias)?;
+                        if definition != *self.definition {
+                            return None;
+                        }
+                        Some(alias.ident_token()?.text().to_owned())
+                    }),
+            );
+
+            for name in &names {
+                for (index, _) in text.match_indices(name.as_str()) {
+                    let Ok(offset) = TextSize::try_from(index) else {
+                        continue;
+                    };
+                    if !search_range.contains(offset) {
+                        continue;
+                    }
+                    let Some(token) = tree.syntax().token_at_offset(offset).find(|token| {
+                        token.kind() == SyntaxKind::Identifier
+                            && token.text_range().start() == offset
+                            && token.text() == name.as_str()
+                    }) else {
+                        continue;
+                    };
+                    if let Some(reference) = self.classify(file_id, &token)
+                        && sink(file_id, reference)
+                    {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks whether the identifier refers to the definition we are searching for.
+    fn classify(
+        &self,
+        file_id: EditionedFileId,
+        token: &SyntaxToken,
+    ) -> Option<FileReference> {
+        let parent = token.parent()?;
+        let (definition, category) = if let Some(path) = ast::Path::cast(parent.clone()) {
+            // Only the last segment of `module::item` refers to the item.
+            if path.segments().last().as_ref() != Some(token) {
+                return None;
+            }
+            let definition = Definition::from_node(self.semantics, file_id, path.syntax())?;
+            let category = path
+                .syntax()
+                .parent()
+                .and_then(ast::IdentExpression::cast)
+                .map(|expression| access_category(expression.syntax()));
+            (definition, category)
+        } else if let Some(field_expression) = ast::FieldExpression::cast(parent.clone()) {
+            if field_expression.field().as_ref() != Some(token) {
+                return None;
+            }
+            let category = access_category(field_expression.syntax());
+            let definition =
+                Definition::from_node(self.semantics, file_id, field_expression.syntax())?;
+            (definition, Some(category))
+        } else if let Some(name) = ast::Name::cast(parent) {
+            // Declarations are not references, but the items of import statements are.
+            // The alias of an import is a new name, and therefore not a reference either.
+            let item = name.syntax().parent().and_then(ast::ImportItem::cast)?;
+            if item.name().as_ref() != Some(&name) {
+                return None;
+            }
+            let definition = Definition::from_name(self.semantics, file_id, &name)?;
+            (definition, Some(ReferenceCategory::Import))
+        } else {
+            return None;
+        };
+
+        (definition == *self.definition).then_some(FileReference {
+            range: token.text_range(),
+            category,
+        })
+    }
+}
+
+/// Whether the expression is the target of an assignment.
+fn access_category(expression: &SyntaxNode) -> ReferenceCategory {
+    let Some(parent) = expression.parent() else {
+        return ReferenceCategory::Read;
+    };
+    let left_side = if let Some(statement) = ast::AssignmentStatement::cast(parent.clone()) {
+        statement.left_side()
+    } else if let Some(statement) = ast::CompoundAssignmentStatement::cast(parent.clone()) {
+        statement.left_side()
+    } else if let Some(statement) = ast::IncrementDecrementStatement::cast(parent) {
+        statement.expression()
+    } else {
+        None
+    };
+    if left_side.is_some_and(|left_side| left_side.syntax() == expression) {
+        ReferenceCategory::Write
+    } else {
+        ReferenceCategory::Read
+    }
+}
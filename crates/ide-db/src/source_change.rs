@@ -3,7 +3,11 @@
 //!
 //! It can be viewed as a dual for `Change`.
 
-use std::fmt;
+use std::{collections::hash_map::Entry, fmt, iter};
+
+use base_db::FileId;
+
+use crate::{FxHashMap, text_edit::TextEdit};
 
 /// An annotation ID associated with an indel, to describe changes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -24,3 +28,76 @@ pub struct ChangeAnnotation {
     pub needs_confirmation: bool,
     pub description: Option<String>,
 }
+
+/// A set of edits to the source files, produced by features such as rename or assists.
+#[derive(Default, Debug, Clone)]
+pub struct SourceChange {
+    pub source_file_edits: FxHashMap<FileId, TextEdit>,
+    pub annotations: FxHashMap<ChangeAnnotationId, ChangeAnnotation>,
+    pub is_snippet: bool,
+}
+
+impl SourceChange {
+    #[must_use]
+    pub fn from_text_edit(
+        file_id: FileId,
+        edit: TextEdit,
+    ) -> Self {
+        Self {
+            source_file_edits: iter::once((file_id, edit)).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Inserts a [`TextEdit`] for the given [`FileId`].
+    /// This merges the edit with an already existing edit for that file, if any.
+    pub fn insert_source_edit(
+        &mut self,
+        file_id: FileId,
+        edit: TextEdit,
+    ) {
+        match self.source_file_edits.entry(file_id) {
+            Entry::Occupied(mut entry) => {
+                let result = entry.get_mut().union(edit);
+                debug_assert!(result.is_ok(), "overlapping edits for the same file");
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(edit);
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn get_source_edit(
+        &self,
+        file_id: FileId,
+    ) -> Option<&TextEdit> {
+        self.source_file_edits.get(&file_id)
+    }
+
+    /// Appends all edits and annotations of `other` to `self`.
+    pub fn merge(
+        &mut self,
+        other: Self,
+    ) {
+        for (file_id, edit) in other.source_file_edits {
+            self.insert_source_edit(file_id, edit);
+        }
+        self.annotations.extend(other.annotations);
+        self.is_snippet |= other.is_snippet;
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.source_file_edits.values().all(TextEdit::is_empty)
+    }
+}
+
+impl From<FxHashMap<FileId, TextEdit>> for SourceChange {
+    fn from(source_file_edits: FxHashMap<FileId, TextEdit>) -> Self {
+        Self {
+            source_file_edits,
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! This module handles fuzzy-searching of functions, structs and other symbols
+//! by name across the whole workspace and its dependencies.
+//!
+//! The index is built per module: [`module_symbols`] is a salsa query over the
+//! `ItemTree` of a single file, so an edit only invalidates the symbols of the
+//! edited file. [`world_symbols`] then walks the modules of every package in
+//! the package graph and matches the indexed names against the [`Query`].
+
+use std::iter;
+
+use base_db::{
+    EditionedFileId, FileId, SourceDatabase, TextRange, all_packages, input::PackageData,
+};
+use hir_def::{
+    AstIdMap,
+    item_tree::{ItemTree, ModuleItemId, Name},
+    mod_path::AbsoluteModPath,
+    name_resolution::ModulesMap,
+};
+use itertools::Itertools as _;
+use syntax::{AstNode as _, HasName as _};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    query: String,
+    lowercased: String,
+    only_types: bool,
+    libs: bool,
+    exact: bool,
+    pub(crate) limit: usize,
+}
+
+impl Query {
+    #[must_use]
+    pub fn new(query: String) -> Self {
+        let lowercased = query.to_lowercase();
+        Self {
+            query,
+            lowercased,
+            only_types: false,
+            libs: false,
+            exact: false,
+            limit: usize::MAX,
+        }
+    }
+
+    /// Only match structs and type aliases.
+    pub const fn only_types(&mut self) {
+        self.only_types = true;
+    }
+
+    /// Also search the packages which are not members of the workspace.
+    pub const fn libs(&mut self) {
+        self.libs = true;
+    }
+
+    /// Only match symbols whose name is exactly the query.
+    pub const fn exact(&mut self) {
+        self.exact = true;
+    }
+
+    pub const fn limit(
+        &mut self,
+        limit: usize,
+    ) {
+        self.limit = limit;
+    }
+
+    /// How well `symbol` matches the query, lower is better.
+    pub(crate) fn rank(
+        &self,
+        symbol: &FileSymbol,
+    ) -> Option<MatchRank> {
+        if self.only_types && !symbol.kind.is_type() {
+            return None;
+        }
+        self.match_rank(symbol.name.as_str())
+    }
+
+    /// How well the name matches the query, lower is better.
+    ///
+    /// Matching is case-insensitive. Names match if the query is a subsequence of them,
+    /// so `ssc` finds `sample_shadow_cascade`.
+    fn match_rank(
+        &self,
+        name: &str,
+    ) -> Option<MatchRank> {
+        if self.exact {
+            return (name == self.query).then_some(MatchRank::Exact);
+        }
+        let lowercased = name.to_lowercase();
+        if lowercased == self.lowercased {
+            Some(MatchRank::Exact)
+        } else if lowercased.starts_with(&self.lowercased) {
+            Some(MatchRank::Prefix)
+        } else if lowercased.contains(&self.lowercased) {
+            Some(MatchRank::Substring)
+        } else if is_subsequence(&self.lowercased, &lowercased) {
+            Some(MatchRank::Subsequence)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum MatchRank {
+    Exact,
+    Prefix,
+    Substring,
+    Subsequence,
+}
+
+fn is_subsequence(
+    needle: &str,
+    haystack: &str,
+) -> bool {
+    let mut haystack = haystack.chars();
+    needle
+        .chars()
+        .all(|character| haystack.any(|candidate| candidate == character))
+}
+
+/// A module-level declaration which can be found by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSymbol {
+    pub name: Name,
+    pub kind: FileSymbolKind,
+    pub file_id: FileId,
+    /// The range of the whole declaration.
+    pub full_range: TextRange,
+    /// The range of the name of the declaration.
+    pub focus_range: TextRange,
+    /// The module path of the declaring module, like `package::lighting`.
+    ///
+    /// Modules of dependencies start with the name of their package instead of `package`.
+    pub container_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileSymbolKind {
+    Function,
+    Struct,
+    GlobalVariable,
+    GlobalConstant,
+    Override,
+    TypeAlias,
+}
+
+impl FileSymbolKind {
+    #[must_use]
+    pub const fn is_type(self) -> bool {
+        matches!(self, Self::Struct | Self::TypeAlias)
+    }
+}
+
+/// The symbols declared at the top level of a module.
+///
+/// The container name is filled in by [`world_symbols`], since it depends on the package.
+#[salsa::tracked(returns(ref))]
+pub fn module_symbols(
+    db: &dyn SourceDatabase,
+    file_id: EditionedFileId,
+) -> Vec<FileSymbol> {
+    let _p = tracing::info_span!("module_symbols").entered();
+    let item_tree = ItemTree::of(db, file_id);
+    let ast_id_map = AstIdMap::of(db, file_id);
+    let root = file_id.parse(db).syntax();
+
+    let mut symbols = Vec::new();
+    macro_rules! push_symbol {
+        ($id:expr, $kind:expr) => {{
+            let name = &item_tree[$id].name;
+            let node = ast_id_map.get($id).to_node(&root);
+            if !Name::is_missing(name.as_str())
+                && let Some(name_node) = node.name()
+            {
+                symbols.push(FileSymbol {
+                    name: name.clone(),
+                    kind: $kind,
+                    file_id: file_id.file_id(db),
+                    full_range: node.syntax().text_range(),
+                    focus_range: name_node.syntax().text_range(),
+                    container_name: None,
+                });
+            }
+        }};
+    }
+
+    for item in item_tree.top_level_items() {
+        match *item {
+            ModuleItemId::Function(id) => push_symbol!(id, FileSymbolKind::Function),
+            ModuleItemId::Struct(id) => push_symbol!(id, FileSymbolKind::Struct),
+            ModuleItemId::GlobalVariable(id) => push_symbol!(id, FileSymbolKind::GlobalVariable),
+            ModuleItemId::GlobalConstant(id) => push_symbol!(id, FileSymbolKind::GlobalConstant),
+            ModuleItemId::Override(id) => push_symbol!(id, FileSymbolKind::Override),
+            ModuleItemId::TypeAlias(id) => push_symbol!(id, FileSymbolKind::TypeAlias),
+            ModuleItemId::ImportStatement(_) | ModuleItemId::GlobalAssertStatement(_) => {},
+        }
+    }
+    symbols
+}
+
+/// Searches the symbols of all modules of the local packages, and of the
+/// dependencies if [`Query::libs`] is set.
+///
+/// The results are sorted by how well they match, exact matches first.
+#[must_use]
+pub fn world_symbols(
+    db: &dyn SourceDatabase,
+    query: &Query,
+) -> Vec<FileSymbol> {
+    let _p = tracing::info_span!("world_symbols", query = ?query.query).entered();
+
+    let mut matches = Vec::new();
+    for &package in all_packages(db).iter() {
+        let package_data = package.data(db);
+        if !query.libs && !package_data.origin.is_local() {
+            continue;
+        }
+        for (module_path, module) in &ModulesMap::of(db, package).modules {
+            let Some(file_id) = module.file else {
+                continue;
+            };
+            for symbol in module_symbols(db, file_id) {
+                let Some(rank) = query.rank(symbol) else {
+                    continue;
+                };
+                let symbol = FileSymbol {
+                    container_name: Some(container_name(package_data, module_path)),
+                    ..symbol.clone()
+                };
+                matches.push((rank, symbol));
+            }
+        }
+    }
+
+    matches.sort_by(|(rank_a, symbol_a), (rank_b, symbol_b)| {
+        rank_a
+            .cmp(rank_b)
+            .then_with(|| symbol_a.name.cmp(&symbol_b.name))
+            .then_with(|| symbol_a.container_name.cmp(&symbol_b.container_name))
+    });
+    matches
+        .into_iter()
+        .map(|(_, symbol)| symbol)
+        .take(query.limit)
+        .collect()
+}
+
+fn container_name(
+    package_data: &PackageData,
+    module_path: &AbsoluteModPath,
+) -> String {
+    let root = match &package_data.display_name {
+        Some(name) if !package_data.origin.is_local() => name.as_str(),
+        Some(_) | None => "package",
+    };
+    iter::once(root)
+        .chain(module_path.segments().iter().map(Name::as_str))
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write as _;
+
+    use expect_test::{Expect, expect};
+    use test_fixture::ChangeFixture;
+
+    use super::{Query, world_symbols};
+    use crate::RootDatabase;
+
+    #[track_caller]
+    #[expect(clippy::use_debug, reason = "useful in tests")]
+    fn check(
+        wa_fixture: &str,
+        query: Query,
+        expect: &Expect,
+    ) {
+        let mut db = RootDatabase::default();
+        db.apply_change(ChangeFixture::parse(wa_fixture).change);
+
+        let mut actual = String::new();
+        for symbol in world_symbols(&db, &query) {
+            writeln!(
+                actual,
+                "{} {:?} {:?} {:?} {}",
+                symbol.name.as_str(),
+                symbol.kind,
+                symbol.file_id,
+                symbol.focus_range,
+                symbol.container_name.unwrap_or_default()
+            )
+            .unwrap();
+        }
+        expect.assert_eq(&actual);
+    }
+
+    const FIXTURE: &str = "
+//- /main.wesl package:app dependencies:shadows edition:2026_pre
+import shadows::sample_shadow_cascade;
+struct ShadowSettings { cascades: u32 }
+fn main() {}
+
+//- /lighting/shadow.wesl edition:2026_pre
+alias ShadowMap = texture_depth_2d_array;
+const SHADOW_CASCADES: u32 = 4;
+fn sample_shadow() -> f32 { return 1.0; }
+
+//- /package.wesl package:shadows library edition:2026_pre
+fn sample_shadow_cascade() -> f32 { return 1.0; }
+";
+
+    #[test]
+    fn fuzzy_search_local_packages() {
+        check(
+            FIXTURE,
+            Query::new("shadow".to_owned()),
+            &expect![[r#"
+                SHADOW_CASCADES GlobalConstant FileId(2) 48..63 package::lighting::shadow
+                ShadowMap TypeAlias FileId(2) 6..15 package::lighting::shadow
+                ShadowSettings Struct FileId(1) 46..60 package::main
+                sample_shadow Function FileId(2) 77..90 package::lighting::shadow
+            "#]],
+        );
+    }
+
+    #[test]
+    fn search_dependencies() {
+        let mut query = Query::new("ssc".to_owned());
+        query.libs();
+        check(
+            FIXTURE,
+            query,
+            &expect![[r#"
+                SHADOW_CASCADES GlobalConstant FileId(2) 48..63 package::lighting::shadow
+                sample_shadow_cascade Function FileId(4) 3..24 shadows
+            "#]],
+        );
+    }
+
+    #[test]
+    fn search_only_types() {
+        let mut query = Query::new("shadow".to_owned());
+        query.only_types();
+        check(
+            FIXTURE,
+            query,
+            &expect![[r#"
+                ShadowMap TypeAlias FileId(2) 6..15 package::lighting::shadow
+                ShadowSettings Struct FileId(1) 46..60 package::main
+            "#]],
+        );
+    }
+}
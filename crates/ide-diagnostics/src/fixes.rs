@@ -0,0 +1,92 @@
+//! Quick fixes for semantic diagnostics.
+//!
+//! Each module offers the fixes of one kind of diagnostic, which are attached to it with
+//! [`Diagnostic::with_fixes`](crate::Diagnostic::with_fixes).
+
+pub(crate) mod missing_address_space;
+pub(crate) mod no_such_field;
+pub(crate) mod precedence_parens_required;
+pub(crate) mod store_type_must_be_storable;
+pub(crate) mod unexpected_return_value;
+pub(crate) mod unresolved_import;
+
+use base_db::TextRange;
+use ide_db::{
+    assists::{Assist, AssistId},
+    source_change::SourceChange,
+    text_edit::TextEdit,
+};
+use vfs::FileId;
+
+/// Creates a fix which applies `edit` to the file of the diagnostic.
+fn fix(
+    id: AssistId,
+    label: String,
+    file_id: FileId,
+    edit: TextEdit,
+    target: TextRange,
+) -> Assist {
+    Assist {
+        id,
+        label,
+        group: None,
+        target,
+        source_change: Some(SourceChange::from_text_edit(file_id, edit)),
+    }
+}
+
+/// The `candidates` which are likely what the user meant to write instead of `name`, best first.
+fn similar_names<'name>(
+    name: &str,
+    candidates: impl Iterator<Item = &'name str>,
+) -> Vec<&'name str> {
+    // Allow roughly one typo for every three characters.
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut similar: Vec<_> = candidates
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    similar.sort_unstable();
+    similar.dedup();
+    similar
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .take(3)
+        .collect()
+}
+
+/// The number of inserted, deleted, substituted or swapped characters which turn `left` into `right`.
+fn edit_distance(
+    left: &str,
+    right: &str,
+) -> usize {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    // `distances[i][j]` is the distance between the first `i` characters of `left`
+    // and the first `j` characters of `right`.
+    let mut distances = vec![vec![0; right.len() + 1]; left.len() + 1];
+    for (index, row) in distances.iter_mut().enumerate() {
+        row[0] = index;
+    }
+    for (index, distance) in distances[0].iter_mut().enumerate() {
+        *distance = index;
+    }
+    for left_index in 1..=left.len() {
+        for right_index in 1..=right.len() {
+            let substitution_cost = usize::from(left[left_index - 1] != right[right_index - 1]);
+            let mut distance = (distances[left_index - 1][right_index] + 1)
+                .min(distances[left_index][right_index - 1] + 1)
+                .min(distances[left_index - 1][right_index - 1] + substitution_cost);
+            if left_index > 1
+                && right_index > 1
+                && left[left_index - 1] == right[right_index - 2]
+                && left[left_index - 2] == right[right_index - 1]
+            {
+                distance = distance.min(distances[left_index - 2][right_index - 2] + 1);
+            }
+            distances[left_index][right_index] = distance;
+        }
+    }
+    distances[left.len()][right.len()]
+}
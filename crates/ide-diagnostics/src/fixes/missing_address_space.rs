@@ -0,0 +1,33 @@
+use ide_db::{
+    assists::{Assist, AssistId},
+    text_edit::TextEdit,
+};
+use syntax::{AstNode as _, HasTemplateParameters as _, ast};
+use vfs::FileId;
+
+use crate::fixes::fix;
+
+/// Inserts the address space which a declaration without one would get in its scope.
+pub(crate) fn fixes(
+    file_id: FileId,
+    variable: &ast::VariableDeclaration,
+) -> Option<Vec<Assist>> {
+    if variable.template_parameters().is_some() {
+        return None;
+    }
+    let var_token = variable.var_token()?;
+    let in_function = variable
+        .syntax()
+        .ancestors()
+        .any(|ancestor| ast::FunctionDeclaration::can_cast(ancestor.kind()));
+    let address_space = if in_function { "function" } else { "private" };
+
+    let edit = TextEdit::insert(var_token.text_range().end(), format!("<{address_space}>"));
+    Some(vec![fix(
+        AssistId::quick_fix("add_address_space"),
+        format!("Add `<{address_space}>` address space"),
+        file_id,
+        edit,
+        var_token.text_range(),
+    )])
+}
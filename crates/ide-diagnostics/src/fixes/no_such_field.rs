@@ -0,0 +1,70 @@
+use hir::{HirDatabase, Struct};
+use hir_ty::ty::{Pointer, Type, TypeKind};
+use ide_db::{
+    assists::{Assist, AssistId},
+    text_edit::TextEdit,
+};
+use syntax::ast;
+use vfs::FileId;
+
+use crate::fixes::{fix, similar_names};
+
+/// Replaces a misspelled field with the fields of the type which are spelled similarly.
+pub(crate) fn fixes(
+    db: &dyn HirDatabase,
+    file_id: FileId,
+    field_expression: &ast::FieldExpression,
+    r#type: Type,
+) -> Option<Vec<Assist>> {
+    let field = field_expression.field()?;
+    let fields = field_names(db, r#type);
+    let range = field.text_range();
+    let fixes: Vec<_> = similar_names(field.text(), fields.iter().map(String::as_str))
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let id = AssistId {
+                group_index: Some(index),
+                ..AssistId::quick_fix("change_field_name")
+            };
+            fix(
+                id,
+                format!("Change to `{name}`"),
+                file_id,
+                TextEdit::replace(range, name.to_owned()),
+                range,
+            )
+        })
+        .collect();
+    (!fixes.is_empty()).then_some(fixes)
+}
+
+fn field_names(
+    db: &dyn HirDatabase,
+    r#type: Type,
+) -> Vec<String> {
+    let kind = r#type.kind(db);
+    let kind = if let TypeKind::Pointer(Pointer { inner, .. }) = kind {
+        inner.kind(db)
+    } else {
+        kind.unref(db).into_owned()
+    };
+    #[expect(
+        clippy::wildcard_enum_match_arm,
+        reason = "only structs have named fields"
+    )]
+    match kind {
+        TypeKind::Struct(_) => Struct::from_type(db, r#type)
+            .map(|r#struct| r#struct.fields(db))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|field| field.name(db).as_str().to_owned())
+            .collect(),
+        TypeKind::BuiltinStruct(builtin_struct) => builtin_struct
+            .fields
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,26 @@
+use ide_db::{
+    assists::{Assist, AssistId},
+    text_edit::TextEdit,
+};
+use syntax::{AstNode as _, ast};
+use vfs::FileId;
+
+use crate::fixes::fix;
+
+/// Parenthesizes the operand of an operation which may only have unary operands.
+pub(crate) fn fixes(
+    file_id: FileId,
+    operand: &ast::Expression,
+) -> Option<Vec<Assist>> {
+    let range = operand.syntax().text_range();
+    let mut builder = TextEdit::builder();
+    builder.insert(range.start(), "(".to_owned());
+    builder.insert(range.end(), ")".to_owned());
+    Some(vec![fix(
+        AssistId::quick_fix("add_parentheses"),
+        "Add parentheses".to_owned(),
+        file_id,
+        builder.finish(),
+        range,
+    )])
+}
@@ -0,0 +1,41 @@
+use base_db::TextRange;
+use hir::HirDatabase;
+use hir_ty::ty::{Type, TypeKind};
+use ide_db::{
+    assists::{Assist, AssistId},
+    text_edit::TextEdit,
+};
+use syntax::{AstNode as _, HasTemplateParameters as _, ast};
+use vfs::FileId;
+
+use crate::fixes::fix;
+
+/// Turns a `var` which is initialized with a pointer into a `let`, which can hold pointers.
+pub(crate) fn fixes(
+    db: &dyn HirDatabase,
+    file_id: FileId,
+    initializer: &ast::Expression,
+    actual: Type,
+) -> Option<Vec<Assist>> {
+    if !matches!(actual.kind(db), TypeKind::Pointer(_)) {
+        return None;
+    }
+    let variable = initializer
+        .syntax()
+        .parent()
+        .and_then(ast::VariableDeclaration::cast)?;
+    let var_token = variable.var_token()?;
+    let end = variable.template_parameters().map_or_else(
+        || var_token.text_range().end(),
+        |template| template.syntax().text_range().end(),
+    );
+    let range = TextRange::new(var_token.text_range().start(), end);
+
+    Some(vec![fix(
+        AssistId::quick_fix("change_var_to_let"),
+        "Change `var` to `let`".to_owned(),
+        file_id,
+        TextEdit::replace(range, "let".to_owned()),
+        range,
+    )])
+}
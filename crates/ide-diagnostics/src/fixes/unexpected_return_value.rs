@@ -0,0 +1,73 @@
+use base_db::{Intern as _, TextRange};
+use hir::HirDatabase;
+use hir_ty::ty::{Type, pretty::pretty_type};
+use ide_db::{
+    assists::{Assist, AssistId},
+    text_edit::TextEdit,
+};
+use syntax::{AstNode as _, SyntaxKind, ast};
+use vfs::FileId;
+
+use crate::fixes::fix;
+
+/// Either drops the returned value, or declares that the function returns it.
+pub(crate) fn fixes(
+    db: &dyn HirDatabase,
+    file_id: FileId,
+    value: &ast::Expression,
+    actual: Type,
+) -> Option<Vec<Assist>> {
+    let statement = value
+        .syntax()
+        .parent()
+        .and_then(ast::ReturnStatement::cast)?;
+    let return_token = statement
+        .syntax()
+        .children_with_tokens()
+        .find(|element| element.kind() == SyntaxKind::Return)?;
+    let value_range = value.syntax().text_range();
+
+    let mut fixes = vec![fix(
+        AssistId::quick_fix("remove_return_value"),
+        "Remove the returned value".to_owned(),
+        file_id,
+        TextEdit::delete(TextRange::new(
+            return_token.text_range().end(),
+            value_range.end(),
+        )),
+        value_range,
+    )];
+
+    let parameters = statement
+        .syntax()
+        .ancestors()
+        .find_map(ast::FunctionDeclaration::cast)
+        .and_then(|function| function.parameter_list());
+    if let Some(parameters) = parameters
+        && let Some(type_text) = return_type_text(db, actual)
+    {
+        fixes.push(fix(
+            AssistId::quick_fix("add_return_type"),
+            format!("Add return type `{type_text}`"),
+            file_id,
+            TextEdit::insert(
+                parameters.syntax().text_range().end(),
+                format!(" -> {type_text}"),
+            ),
+            value_range,
+        ));
+    }
+    Some(fixes)
+}
+
+/// The type of the returned value, as it would be written in the signature of the function.
+fn return_type_text(
+    db: &dyn HirDatabase,
+    actual: Type,
+) -> Option<String> {
+    let mut kind = actual.kind(db).unref(db).into_owned();
+    if kind.is_abstract(db) {
+        kind = kind.concretize(db)?;
+    }
+    Some(pretty_type(db, kind.intern(db)))
+}
@@ -0,0 +1,43 @@
+use hir_def::item_tree::Name;
+use ide_db::{
+    assists::{Assist, AssistId},
+    text_edit::TextEdit,
+};
+use syntax::{AstNode as _, ast};
+use vfs::FileId;
+
+use crate::fixes::{fix, similar_names};
+
+/// Replaces the unresolved last segment of an import with similarly spelled modules or items.
+pub(crate) fn fixes(
+    file_id: FileId,
+    import: &ast::ImportStatement,
+    name: &Name,
+    alternatives: &[Name],
+) -> Option<Vec<Assist>> {
+    let item_name = import
+        .syntax()
+        .descendants()
+        .filter_map(ast::ImportItem::cast)
+        .filter_map(|item| item.name())
+        .find(|item_name| item_name.text().as_str() == name.as_str())?;
+    let range = item_name.syntax().text_range();
+    let fixes: Vec<_> = similar_names(name.as_str(), alternatives.iter().map(Name::as_str))
+        .into_iter()
+        .enumerate()
+        .map(|(index, alternative)| {
+            let id = AssistId {
+                group_index: Some(index),
+                ..AssistId::quick_fix("change_import")
+            };
+            fix(
+                id,
+                format!("Change to `{alternative}`"),
+                file_id,
+                TextEdit::replace(range, alternative.to_owned()),
+                range,
+            )
+        })
+        .collect();
+    (!fixes.is_empty()).then_some(fixes)
+}
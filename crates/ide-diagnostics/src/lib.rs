@@ -1,3 +1,4 @@
+mod fixes;
 mod naga;
 #[cfg(test)]
 mod tests;
@@ -15,11 +16,14 @@ use hir_ty::ty::{
     self,
     pretty::{pretty_fn, pretty_type},
 };
-use ide_db::RootDatabase;
+use ide_db::{
+    RootDatabase,
+    assists::{Assist, AssistResolveStrategy},
+};
 use itertools::Itertools as _;
 use paths::{AbsPathBuf, Utf8PathBuf};
 use rowan::NodeOrToken;
-use syntax::{AstNode as _, Edition};
+use syntax::{AstNode as _, Edition, ast};
 use vfs::FileId;
 
 use crate::{
@@ -85,6 +89,7 @@ pub struct Diagnostic {
     pub severity: Severity,
     pub related: Vec<(String, FileRange)>,
     pub source: DiagnosticSource,
+    pub fixes: Option<Vec<Assist>>,
 }
 
 #[derive(Default)]
@@ -139,6 +144,7 @@ impl Diagnostic {
             severity: Severity::Error,
             related: Vec::new(),
             source: DiagnosticSource::WgslAnalyzer,
+            fixes: None,
         }
     }
 
@@ -157,6 +163,14 @@ impl Diagnostic {
             ..self
         }
     }
+
+    #[must_use]
+    pub fn with_fixes(
+        self,
+        fixes: Option<Vec<Assist>>,
+    ) -> Self {
+        Self { fixes, ..self }
+    }
 }
 
 /// # Panics
@@ -166,6 +180,7 @@ impl Diagnostic {
 pub fn diagnostics(
     db: &RootDatabase,
     config: &DiagnosticsConfig,
+    resolve: &AssistResolveStrategy,
     file_id: FileId,
 ) -> Vec<Diagnostic> {
     let file_id = EditionedFileId::from_file(db, file_id);
@@ -261,6 +276,9 @@ pub fn diagnostics(
                 } => {
                     debug_assert!(!r#type.is_err(db));
                     let source = expression.value.to_node(&root).syntax().parent().unwrap();
+                    let fixes = ast::FieldExpression::cast(source.clone()).and_then(|field_expression| {
+                        fixes::no_such_field::fixes(db, file_id.file_id(db), &field_expression, r#type)
+                    });
                     let r#type = ty::pretty::pretty_type(db, r#type);
                     let frange = original_file_range(db, expression.file_id, source.syntax());
                     Diagnostic::new(
@@ -268,6 +286,7 @@ pub fn diagnostics(
                         format!("no field `{}` on type {type}", name.as_ref()),
                         frange.range,
                     )
+                    .with_fixes(fixes)
                 },
                 AnyDiagnostic::ArrayAccessInvalidType { expression, r#type } => {
                     debug_assert!(!r#type.is_err(db));
@@ -317,6 +336,10 @@ pub fn diagnostics(
                         "missing address space on global variable".to_owned(),
                         frange.range,
                     )
+                    .with_fixes(fixes::missing_address_space::fixes(
+                        file_id.file_id(db),
+                        &variable_declaration,
+                    ))
                 },
                 AnyDiagnostic::InvalidAddressSpace { variable, error } => {
                     let variable_declaration = variable.value.to_node(&root);
@@ -445,7 +468,9 @@ pub fn diagnostics(
                             "{symbol} expressions may only have unary operands. More complex operands must be this with parenthesized `()`"
                         )
                     };
-                    Diagnostic::new(DiagnosticCode("19"), message, frange.range)
+                    Diagnostic::new(DiagnosticCode("19"), message, frange.range).with_fixes(
+                        fixes::precedence_parens_required::fixes(file_id.file_id(db), &source),
+                    )
                 },
                 AnyDiagnostic::CyclicType { name, range, .. } => Diagnostic::new(
                     DiagnosticCode("20"),
@@ -508,7 +533,11 @@ pub fn diagnostics(
                         frange.range,
                     )
                 },
-                AnyDiagnostic::UnresolvedImport { id } => {
+                AnyDiagnostic::UnresolvedImport {
+                    id,
+                    name,
+                    alternatives,
+                } => {
                     let source = id.value.to_node(&root);
                     let frange = original_file_range(db, id.file_id, source.syntax());
                     Diagnostic::new(
@@ -516,6 +545,12 @@ pub fn diagnostics(
                         "could not resolve import".to_owned(),
                         frange.range,
                     )
+                    .with_fixes(fixes::unresolved_import::fixes(
+                        file_id.file_id(db),
+                        &source,
+                        &name,
+                        &alternatives,
+                    ))
                 },
                 AnyDiagnostic::TooManySupers { id } => {
                     let source = id.value.to_node(&root);
@@ -550,6 +585,12 @@ pub fn diagnostics(
                 AnyDiagnostic::StoreTypeMustBeStorable { expression, actual } => {
                     debug_assert!(!actual.is_err(db));
                     let source = expression.value.to_node(&root);
+                    let fixes = fixes::store_type_must_be_storable::fixes(
+                        db,
+                        file_id.file_id(db),
+                        &source,
+                        actual,
+                    );
                     let r#type = ty::pretty::pretty_type(db, actual);
                     let frange = original_file_range(db, expression.file_id, source.syntax());
                     Diagnostic::new(
@@ -557,10 +598,17 @@ pub fn diagnostics(
                         format!("store type must be storable, found {type}"),
                         frange.range,
                     )
+                    .with_fixes(fixes)
                 },
                 AnyDiagnostic::UnexpectedReturnValue { expression, actual } => {
                     debug_assert!(!actual.is_err(db));
                     let source = expression.value.to_node(&root);
+                    let fixes = fixes::unexpected_return_value::fixes(
+                        db,
+                        file_id.file_id(db),
+                        &source,
+                        actual,
+                    );
                     let r#type = ty::pretty::pretty_type(db, actual);
                     let frange = original_file_range(db, expression.file_id, source.syntax());
                     Diagnostic::new(
@@ -568,12 +616,27 @@ pub fn diagnostics(
                         format!("unexpected return value of type `{type}` in function with no return type"),
                         frange.range,
                     )
+                    .with_fixes(fixes)
                 },
             }
         })
+        .map(|diagnostic| unresolve_fixes(diagnostic, resolve))
         .collect()
 }
 
+/// Drops the source changes of the fixes which were not asked to be resolved.
+fn unresolve_fixes(
+    mut diagnostic: Diagnostic,
+    resolve: &AssistResolveStrategy,
+) -> Diagnostic {
+    for fix in diagnostic.fixes.iter_mut().flatten() {
+        if !resolve.should_resolve(&fix.id) {
+            fix.source_change = None;
+        }
+    }
+    diagnostic
+}
+
 fn error_message_cause_chain(error: &dyn error::Error) -> String {
     let mut message = error.to_string();
 
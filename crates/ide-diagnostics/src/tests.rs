@@ -1,13 +1,14 @@
 use std::fmt::Write as _;
 
 use expect_test::{Expect, expect};
-use ide_db::RootDatabase;
+use ide_db::{RootDatabase, assists::AssistResolveStrategy};
 use itertools::Itertools;
 use syntax::ExtensionsConfig;
 use test_fixture::WithFixture as _;
 
 use crate::{Diagnostic, DiagnosticsConfig, Severity};
 
+mod fixes;
 mod tint;
 
 fn check_diagnostics(
@@ -32,7 +33,12 @@ fn check_diagnostics_with_config(
     expect: Expect,
 ) {
     let (db, file_id) = RootDatabase::with_single_file(source);
-    let diagnostics = crate::diagnostics(&db, config, file_id.file_id(&db));
+    let diagnostics = crate::diagnostics(
+        &db,
+        config,
+        &AssistResolveStrategy::None,
+        file_id.file_id(&db),
+    );
     let mut actual = String::new();
     for Diagnostic {
         code,
@@ -0,0 +1,115 @@
+use base_db::SourceDatabase as _;
+use expect_test::{Expect, expect};
+use ide_db::{RootDatabase, assists::AssistResolveStrategy};
+use test_fixture::WithFixture as _;
+
+use crate::DiagnosticsConfig;
+
+/// Applies the fix with the given `label` of the diagnostic at the cursor.
+#[track_caller]
+#[expect(clippy::needless_pass_by_value, reason = "Matches expect! macro")]
+fn check_fix(
+    before: &str,
+    label: &str,
+    expect: Expect,
+) {
+    let (db, position) = RootDatabase::with_position(before);
+    let config = DiagnosticsConfig {
+        naga_parsing_enabled: false,
+        naga_validation_enabled: false,
+        ..Default::default()
+    };
+    let fix = crate::diagnostics(&db, &config, &AssistResolveStrategy::All, position.file_id)
+        .into_iter()
+        .filter(|diagnostic| diagnostic.range.contains_inclusive(position.offset))
+        .filter_map(|diagnostic| diagnostic.fixes)
+        .flatten()
+        .find(|fix| fix.label == label)
+        .unwrap_or_else(|| panic!("no fix `{label}` at the cursor"));
+
+    let mut text = db.file_text(position.file_id).text(&db).to_string();
+    fix.source_change
+        .expect("fix should be resolved")
+        .get_source_edit(position.file_id)
+        .expect("fix should edit the file of the diagnostic")
+        .apply(&mut text);
+    expect.assert_eq(&text);
+}
+
+#[test]
+fn missing_address_space() {
+    check_fix(
+        "var count$0: u32;",
+        "Add `<private>` address space",
+        expect!["var<private> count: u32;"],
+    );
+}
+
+#[test]
+fn precedence_parens_required() {
+    check_fix(
+        "fn foo() { let x = tr$0ue == true == true; }",
+        "Add parentheses",
+        expect!["fn foo() { let x = (true == true) == true; }"],
+    );
+}
+
+#[test]
+fn no_such_field() {
+    check_fix(
+        "
+struct Light { intensity: f32, color: vec3f }
+fn foo(light: Light) -> f32 { return light.intensty$0; }
+",
+        "Change to `intensity`",
+        expect![[r#"
+            struct Light { intensity: f32, color: vec3f }
+            fn foo(light: Light) -> f32 { return light.intensity; }
+        "#]],
+    );
+}
+
+#[test]
+fn unexpected_return_value_remove_value() {
+    check_fix(
+        "fn foo() { return $00; }",
+        "Remove the returned value",
+        expect!["fn foo() { return; }"],
+    );
+}
+
+#[test]
+fn unexpected_return_value_add_return_type() {
+    check_fix(
+        "fn foo() { return $00; }",
+        "Add return type `i32`",
+        expect!["fn foo() -> i32 { return 0; }"],
+    );
+}
+
+#[test]
+fn store_type_must_be_storable() {
+    check_fix(
+        "fn foo() { var x = 1; var y = $0&x; }",
+        "Change `var` to `let`",
+        expect!["fn foo() { var x = 1; let y = &x; }"],
+    );
+}
+
+#[test]
+fn unresolved_import() {
+    check_fix(
+        "
+//- /package.wesl edition:2026_pre
+import package::lighting::brfd$0;
+
+//- /lighting.wesl
+fn brdf() -> f32 { return 1.0; }
+",
+        "Change to `brdf`",
+        expect![[r#"
+            import package::lighting::brdf;
+
+        "#]],
+    );
+}
@@ -0,0 +1,259 @@
+//! Code lenses above the declarations of a file.
+
+use base_db::{EditionedFileId, FileId, FilePosition, FileRange, TextRange};
+use hir::{Function, GlobalVariable, Module, ModuleDef, definition::Definition};
+use ide_db::RootDatabase;
+
+use crate::{goto_definition::TryToNavigationTarget as _, references};
+
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    /// The name of the annotated declaration.
+    pub range: TextRange,
+    pub kind: AnnotationKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum AnnotationKind {
+    /// The references of a function or struct.
+    ///
+    /// `data` is `None` until the annotation is resolved.
+    HasReferences {
+        position: FilePosition,
+        data: Option<Vec<FileRange>>,
+    },
+    /// The resources which an entry point uses, directly or through the functions it calls.
+    HasResources {
+        position: FilePosition,
+        resources: Vec<Resource>,
+    },
+}
+
+/// A resource variable with a `@group` and `@binding`.
+#[derive(Debug, Clone)]
+pub struct Resource {
+    pub name: String,
+    pub group: u32,
+    pub binding: u32,
+    /// The name of the declaration of the variable.
+    pub declaration: FileRange,
+}
+
+// Feature: Annotations
+//
+// Shows the number of references above each function and struct.
+//
+// Above each entry point, lists the `@group` and `@binding` of every resource
+// which the entry point uses, including those used by the functions it calls.
+// This helps to keep bind group layouts on the host side in sync with the shader.
+pub(crate) fn annotations(
+    db: &RootDatabase,
+    file_id: FileId,
+) -> Vec<Annotation> {
+    let _p = tracing::info_span!("annotations").entered();
+    let module = Module {
+        file_id: EditionedFileId::from_file(db, file_id),
+    };
+
+    let mut annotations = Vec::new();
+    for item in module.items(db) {
+        let function = match item {
+            ModuleDef::Function(function) => Some(function),
+            ModuleDef::Struct(_) => None,
+            ModuleDef::GlobalVariable(_)
+            | ModuleDef::GlobalConstant(_)
+            | ModuleDef::Override(_)
+            | ModuleDef::TypeAlias(_)
+            | ModuleDef::GlobalAssertStatement(_) => continue,
+        };
+        let Some(target) = Definition::ModuleDef(item).try_to_navigation_target(db) else {
+            continue;
+        };
+        if target.file_id != file_id {
+            continue;
+        }
+        let range = target.focus_or_full_range();
+        let position = FilePosition {
+            file_id,
+            offset: range.start(),
+        };
+
+        if let Some(function) = function
+            && function.shader_stage(db).is_some()
+        {
+            annotations.push(Annotation {
+                range,
+                kind: AnnotationKind::HasResources {
+                    position,
+                    resources: resources(db, function),
+                },
+            });
+        }
+        annotations.push(Annotation {
+            range,
+            kind: AnnotationKind::HasReferences {
+                position,
+                data: None,
+            },
+        });
+    }
+    annotations
+}
+
+pub(crate) fn resolve_annotation(
+    db: &RootDatabase,
+    mut annotation: Annotation,
+) -> Annotation {
+    match &mut annotation.kind {
+        AnnotationKind::HasReferences { position, data } => {
+            *data = references::find_all_refs(db, *position, None).map(|result| {
+                result
+                    .references
+                    .into_iter()
+                    .flat_map(|(file_id, references)| {
+                        references
+                            .into_iter()
+                            .map(move |(range, _)| FileRange { file_id, range })
+                    })
+                    .collect()
+            });
+        },
+        AnnotationKind::HasResources { .. } => {},
+    }
+    annotation
+}
+
+fn resources(
+    db: &RootDatabase,
+    function: Function,
+) -> Vec<Resource> {
+    function
+        .resources(db)
+        .into_iter()
+        .filter_map(|(variable, binding)| {
+            Some(Resource {
+                name: variable.name(db).as_str().to_owned(),
+                group: binding.group,
+                binding: binding.binding,
+                declaration: declaration(db, variable)?,
+            })
+        })
+        .collect()
+}
+
+fn declaration(
+    db: &RootDatabase,
+    variable: GlobalVariable,
+) -> Option<FileRange> {
+    let target =
+        Definition::ModuleDef(ModuleDef::GlobalVariable(variable)).try_to_navigation_target(db)?;
+    Some(FileRange {
+        file_id: target.file_id,
+        range: target.focus_or_full_range(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use itertools::Itertools as _;
+    use stdx::format_to;
+
+    use super::AnnotationKind;
+    use crate::fixture;
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, file_id) = fixture::single_file_db(wa_fixture);
+        let text = analysis.file_text(file_id).unwrap();
+
+        let mut actual = String::new();
+        for annotation in analysis.annotations(file_id).unwrap() {
+            let annotation = analysis.resolve_annotation(annotation).unwrap();
+            format_to!(
+                actual,
+                "{} {:?}: ",
+                &text[annotation.range],
+                annotation.range
+            );
+            match annotation.kind {
+                AnnotationKind::HasReferences { data, .. } => {
+                    let references = data
+                        .unwrap_or_default()
+                        .into_iter()
+                        .sorted_by_key(|reference| (reference.file_id, reference.range.start()))
+                        .map(|reference| format!("{:?}", reference.range))
+                        .join(", ");
+                    format_to!(actual, "references [{references}]");
+                },
+                AnnotationKind::HasResources { resources, .. } => {
+                    let resources = resources
+                        .iter()
+                        .map(|resource| {
+                            format!(
+                                "@group({}) @binding({}) {} {:?}",
+                                resource.group,
+                                resource.binding,
+                                resource.name,
+                                resource.declaration.range
+                            )
+                        })
+                        .join(", ");
+                    format_to!(actual, "resources [{resources}]");
+                },
+            }
+            actual.push('\n');
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn references_of_functions_and_structs() {
+        check(
+            "
+struct Light { intensity: f32 }
+fn brightness(light: Light) -> f32 { return light.intensity; }
+fn unused() {}
+fn main() {
+    let light = Light(1.0);
+    let a = brightness(light) + brightness(light);
+}
+",
+            &expect![[r#"
+                Light 7..12: references [53..58, 138..143]
+                brightness 35..45: references [162..172, 182..192]
+                unused 98..104: references []
+                main 113..117: references []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn resources_of_entry_point() {
+        check(
+            "
+struct Camera { view: mat4x4f }
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(1) @binding(0) var albedo: texture_2d<f32>;
+@group(0) @binding(1) var albedo_sampler: sampler;
+@group(2) @binding(0) var<storage> unused: array<f32>;
+fn sample(uv: vec2f) -> vec4f {
+    return textureSample(albedo, albedo_sampler, uv);
+}
+@fragment
+fn main(@location(0) uv: vec2f) -> @location(0) vec4f {
+    return camera.view * sample(uv);
+}
+",
+            &expect![[r#"
+                Camera 7..13: references [75..81]
+                sample 243..249: references [419..425]
+                main 341..345: resources [@group(0) @binding(0) camera 67..73, @group(0) @binding(1) albedo_sampler 160..174, @group(1) @binding(0) albedo 109..115]
+                main 341..345: references []
+            "#]],
+        );
+    }
+}
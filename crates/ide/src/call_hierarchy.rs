@@ -0,0 +1,377 @@
+//! Entry point for call-hierarchy
+
+use base_db::{
+    EditionedFileId, FileId, FilePosition, FileRange, RangeInfo, TextRange, all_packages,
+};
+use hir::{Function, HirDatabase, Module, ModuleDef, Semantics, definition::Definition};
+use hir_def::name_resolution::ModulesMap;
+use ide_db::RootDatabase;
+use itertools::Itertools as _;
+use syntax::{AstNode as _, SyntaxKind};
+
+use crate::{NavigationTarget, goto_definition::TryToNavigationTarget as _, helpers};
+
+/// A function in the call hierarchy.
+#[derive(Debug, Clone)]
+pub struct CallItem {
+    pub name: String,
+    /// The declaration of the function.
+    ///
+    /// Its description lists the entry points which transitively call the function.
+    pub target: NavigationTarget,
+    /// The ranges of the calls between the function and the function the hierarchy was requested for.
+    pub ranges: Vec<FileRange>,
+}
+
+// Feature: Call Hierarchy
+//
+// Shows the incoming and outgoing calls of a function, across WESL modules and packages.
+//
+// Each function is annotated with the entry points which reach it, since builtins
+// like `textureSample` or `workgroupBarrier` can only be called from some shader stages.
+pub(crate) fn call_hierarchy(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<RangeInfo<Vec<CallItem>>> {
+    let (range, function) = function_at(db, position)?;
+    let entry_points = entry_points(db);
+    let item = call_item(db, &entry_points, function, Vec::new())?;
+    Some(RangeInfo::new(range, vec![item]))
+}
+
+pub(crate) fn incoming_calls(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<Vec<CallItem>> {
+    let (_, function) = function_at(db, position)?;
+    let entry_points = entry_points(db);
+
+    let items = workspace_functions(db)
+        .into_iter()
+        .filter_map(|caller| {
+            let file_id = caller_file(db, caller)?;
+            let ranges = caller
+                .calls(db)
+                .into_iter()
+                .filter(|(callee, _)| *callee == function)
+                .map(|(_, range)| FileRange { file_id, range })
+                .collect_vec();
+            if ranges.is_empty() {
+                return None;
+            }
+            call_item(db, &entry_points, caller, ranges)
+        })
+        .sorted_by_key(|item| (item.target.file_id, item.target.full_range.start()))
+        .collect();
+    Some(items)
+}
+
+pub(crate) fn outgoing_calls(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<Vec<CallItem>> {
+    let (_, function) = function_at(db, position)?;
+    let file_id = caller_file(db, function)?;
+    let entry_points = entry_points(db);
+
+    // Group the calls by callee, in the order of the first call of each callee.
+    let mut callees: Vec<(Function, Vec<FileRange>)> = Vec::new();
+    for (callee, range) in function
+        .calls(db)
+        .into_iter()
+        .sorted_by_key(|(_, range)| range.start())
+    {
+        let range = FileRange { file_id, range };
+        match callees.iter_mut().find(|(existing, _)| *existing == callee) {
+            Some((_, ranges)) => ranges.push(range),
+            None => callees.push((callee, vec![range])),
+        }
+    }
+
+    let items = callees
+        .into_iter()
+        .filter_map(|(callee, ranges)| call_item(db, &entry_points, callee, ranges))
+        .collect();
+    Some(items)
+}
+
+fn function_at(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<(TextRange, Function)> {
+    let semantics = &Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, position.file_id);
+    let file = semantics.parse(file_id);
+    let token = file.syntax().token_at_offset(position.offset);
+    let token = helpers::pick_best_token(token, |kind| usize::from(kind == SyntaxKind::Identifier))
+        .filter(|token| token.kind() == SyntaxKind::Identifier)?;
+    match Definition::from_token(semantics, file_id, &token)? {
+        Definition::ModuleDef(ModuleDef::Function(function)) => {
+            Some((token.text_range(), function))
+        },
+        Definition::ModuleDef(_)
+        | Definition::Local(_)
+        | Definition::Field(_)
+        | Definition::BuiltinFunction(_)
+        | Definition::BuiltinType(_)
+        | Definition::BuiltinTypeGenerator(_)
+        | Definition::BuiltinEnumerant(_)
+        | Definition::BuiltinDeclaration(_) => None,
+    }
+}
+
+fn call_item(
+    db: &RootDatabase,
+    entry_points: &[Function],
+    function: Function,
+    ranges: Vec<FileRange>,
+) -> Option<CallItem> {
+    let mut target =
+        Definition::ModuleDef(ModuleDef::Function(function)).try_to_navigation_target(db)?;
+    let reached_from = entry_points
+        .iter()
+        .filter(|entry_point| entry_point.reaches(db, function))
+        .filter_map(|entry_point| {
+            let stage = entry_point.shader_stage(db)?;
+            Some(format!(
+                "@{} {}",
+                stage.as_str(),
+                entry_point.name(db).as_str()
+            ))
+        })
+        .join(", ");
+    if !reached_from.is_empty() {
+        target.description = Some(reached_from);
+    }
+    Some(CallItem {
+        name: function.name(db).as_str().to_owned(),
+        target,
+        ranges,
+    })
+}
+
+fn caller_file(
+    db: &RootDatabase,
+    function: Function,
+) -> Option<FileId> {
+    Definition::ModuleDef(ModuleDef::Function(function))
+        .try_to_navigation_target(db)
+        .map(|target| target.file_id)
+}
+
+/// The functions declared in the modules of all packages.
+fn workspace_functions(db: &dyn HirDatabase) -> Vec<Function> {
+    all_packages(db)
+        .iter()
+        .flat_map(|&package| {
+            ModulesMap::of(db, package)
+                .modules
+                .values()
+                .filter_map(|module| module.file)
+                .collect_vec()
+        })
+        .unique()
+        .flat_map(|file_id| Module { file_id }.items(db))
+        .filter_map(|definition| match definition {
+            ModuleDef::Function(function) => Some(function),
+            ModuleDef::GlobalVariable(_)
+            | ModuleDef::GlobalConstant(_)
+            | ModuleDef::Override(_)
+            | ModuleDef::Struct(_)
+            | ModuleDef::TypeAlias(_)
+            | ModuleDef::GlobalAssertStatement(_) => None,
+        })
+        .collect()
+}
+
+fn entry_points(db: &dyn HirDatabase) -> Vec<Function> {
+    workspace_functions(db)
+        .into_iter()
+        .filter(|function| function.shader_stage(db).is_some())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use stdx::format_to;
+
+    use super::CallItem;
+    use crate::fixture;
+
+    #[expect(clippy::use_debug, reason = "useful in tests")]
+    fn render(items: &[CallItem]) -> String {
+        let mut actual = String::new();
+        for item in items {
+            format_to!(
+                actual,
+                "{} {:?} [{}]",
+                item.name,
+                item.target.file_id,
+                item.target.description.as_deref().unwrap_or_default()
+            );
+            for range in &item.ranges {
+                format_to!(actual, " {:?}:{:?}", range.file_id, range.range);
+            }
+            actual.push('\n');
+        }
+        actual
+    }
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect_prepare: &Expect,
+        expect_incoming: &Expect,
+        expect_outgoing: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let prepared = analysis
+            .call_hierarchy(position)
+            .unwrap()
+            .expect("no function at position");
+        expect_prepare.assert_eq(&render(&prepared.info));
+        let incoming = analysis.incoming_calls(position).unwrap().unwrap();
+        expect_incoming.assert_eq(&render(&incoming));
+        let outgoing = analysis.outgoing_calls(position).unwrap().unwrap();
+        expect_outgoing.assert_eq(&render(&outgoing));
+    }
+
+    #[test]
+    fn call_hierarchy_across_modules() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+import package::lighting::shade;
+@fragment
+fn fs_main() -> @location(0) vec4f {
+    return vec4f(shade(), 1.0);
+}
+@compute @workgroup_size(1)
+fn cs_main() {
+    _ = shade() + shade();
+}
+//- /lighting.wesl edition:2026_pre
+fn ambient() -> f32 { return 0.1; }
+fn sh$0ade() -> f32 { return ambient() + ambient(); }
+fn unused() -> f32 { return shade(); }
+",
+            &expect![[r#"
+                shade FileId(2) [@fragment fs_main, @compute cs_main]
+            "#]],
+            &expect![[r#"
+                fs_main FileId(1) [@fragment fs_main] FileId(1):97..102
+                cs_main FileId(1) [@compute cs_main] FileId(1):165..170 FileId(1):175..180
+                unused FileId(2) [] FileId(2):116..121
+            "#]],
+            &expect![[r#"
+                ambient FileId(2) [@fragment fs_main, @compute cs_main] FileId(2):63..70 FileId(2):75..82
+            "#]],
+        );
+    }
+
+    /// A directly and two mutually recursive functions called from an entry point, with the cursor on the declaration of `function`.
+    fn recursion(function: &str) -> String {
+        let fixture = "
+//- /main.wesl edition:2026_pre
+fn factorial(n: i32) -> i32 {
+    if n <= 1 {
+        return 1;
+    }
+    return n * factorial(n - 1);
+}
+fn is_even(n: u32) -> bool {
+    if n == 0u {
+        return true;
+    }
+    return is_odd(n - 1u);
+}
+fn is_odd(n: u32) -> bool {
+    if n == 0u {
+        return false;
+    }
+    return is_even(n - 1u);
+}
+@compute @workgroup_size(1)
+fn main() {
+    _ = factorial(3);
+    _ = is_even(4u);
+}
+";
+        fixture.replacen(&format!("fn {function}("), &format!("fn $0{function}("), 1)
+    }
+
+    #[test]
+    fn call_hierarchy_of_direct_recursion() {
+        check(
+            &recursion("factorial"),
+            &expect![[r#"
+                factorial FileId(1) [@compute main]
+            "#]],
+            &expect![[r#"
+                factorial FileId(1) [@compute main] FileId(1):85..94
+                main FileId(1) [@compute main] FileId(1):358..367
+            "#]],
+            &expect![[r#"
+                factorial FileId(1) [@compute main] FileId(1):85..94
+            "#]],
+        );
+    }
+
+    #[test]
+    fn call_hierarchy_of_mutual_recursion() {
+        check(
+            &recursion("is_even"),
+            &expect![[r#"
+                is_even FileId(1) [@compute main]
+            "#]],
+            &expect![[r#"
+                is_odd FileId(1) [@compute main] FileId(1):291..298
+                main FileId(1) [@compute main] FileId(1):380..387
+            "#]],
+            &expect![[r#"
+                is_odd FileId(1) [@compute main] FileId(1):189..195
+            "#]],
+        );
+    }
+
+    #[test]
+    fn call_hierarchy_groups_calls_and_skips_builtins() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+fn helper(x: f32) -> f32 {
+    return x;
+}
+fn sha$0de(x: f32) -> f32 {
+    let a = helper(x);
+    let b = max(helper(a), 0.0);
+    return clamp(b, 0.0, helper(1.0));
+}
+",
+            &expect![[r#"
+                shade FileId(1) []
+            "#]],
+            &expect![[""]],
+            &expect![[r#"
+                helper FileId(1) [] FileId(1):81..87 FileId(1):108..114 FileId(1):150..156
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_call_hierarchy_outside_of_functions() {
+        let (analysis, position) = fixture::position(
+            "
+//- /main.wesl edition:2026_pre
+struct Li$0ght {
+    intensity: f32,
+}
+fn main() {}
+",
+        );
+        assert!(analysis.call_hierarchy(position).unwrap().is_none());
+        assert!(analysis.incoming_calls(position).unwrap().is_none());
+        assert!(analysis.outgoing_calls(position).unwrap().is_none());
+    }
+}
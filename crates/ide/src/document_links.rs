@@ -0,0 +1,280 @@
+//! Links from the segments of WESL import paths to the files they refer to.
+
+use base_db::{EditionedFileId, FileId, Package, TextRange, file_package};
+use hir_def::{item_tree::Name, mod_path::AbsoluteModPath, name_resolution::resolve_module};
+use ide_db::RootDatabase;
+use syntax::{AstNode as _, SyntaxKind, SyntaxNode, SyntaxToken, ast};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DocumentLink {
+    pub range: TextRange,
+    pub target: FileId,
+}
+
+/// The module which the segments of an import path before the current one refer to.
+#[derive(Debug)]
+enum Prefix {
+    /// The start of a path without `package::` or `super::`, which names a dependency.
+    Dependency,
+    Module(Package, Vec<Name>),
+    /// A dependency which does not exist. Nothing after it can be resolved.
+    Unresolved,
+}
+
+// Feature: Document Links
+//
+// Makes each segment of a WESL `import` path a link to the module file it resolves to.
+// The name of a dependency links to the `wesl.toml` of the dependency.
+pub(crate) fn document_links(
+    db: &RootDatabase,
+    file_id: FileId,
+) -> Vec<DocumentLink> {
+    let _p = tracing::info_span!("document_links").entered();
+    let Some(package) = file_package(db, file_id) else {
+        return Vec::new();
+    };
+    let editioned_file_id = EditionedFileId::from_file(db, file_id);
+    let file = editioned_file_id.parse(db).tree();
+
+    let mut links = Vec::new();
+    for import in file
+        .syntax()
+        .descendants()
+        .filter_map(ast::ImportStatement::cast)
+    {
+        let prefix = match import.relative() {
+            None => Prefix::Dependency,
+            Some(ast::ImportRelative::ImportPackageRelative(relative)) => {
+                for keyword in keywords(relative.syntax(), SyntaxKind::Package) {
+                    module_link(db, &mut links, keyword.text_range(), package, &[]);
+                }
+                Prefix::Module(package, Vec::new())
+            },
+            Some(ast::ImportRelative::ImportSuperRelative(relative)) => {
+                let Some(mut module_path) =
+                    AbsoluteModPath::for_file(db, package, editioned_file_id)
+                else {
+                    continue;
+                };
+                let mut prefix = Prefix::Module(package, Vec::new());
+                for keyword in keywords(relative.syntax(), SyntaxKind::Super) {
+                    if module_path.pop_segment().is_none() {
+                        prefix = Prefix::Unresolved;
+                        break;
+                    }
+                    module_link(
+                        db,
+                        &mut links,
+                        keyword.text_range(),
+                        package,
+                        module_path.segments(),
+                    );
+                    prefix = Prefix::Module(package, module_path.segments().to_vec());
+                }
+                prefix
+            },
+        };
+        if let Some(tree) = import.item() {
+            tree_links(db, package, &prefix, &tree, &mut links);
+        }
+    }
+    links
+}
+
+fn tree_links(
+    db: &RootDatabase,
+    package: Package,
+    prefix: &Prefix,
+    tree: &ast::ImportTree,
+    links: &mut Vec<DocumentLink>,
+) {
+    match tree {
+        ast::ImportTree::ImportPath(path) => {
+            let Some(name) = path.name() else {
+                return;
+            };
+            let prefix = segment_link(db, package, prefix, &name, links);
+            if let Some(item) = path.item() {
+                tree_links(db, package, &prefix, &item, links);
+            }
+        },
+        ast::ImportTree::ImportItem(item) => {
+            if let Some(name) = item.name() {
+                segment_link(db, package, prefix, &name, links);
+            }
+        },
+        ast::ImportTree::ImportCollection(collection) => {
+            for item in collection.items() {
+                tree_links(db, package, prefix, &item, links);
+            }
+        },
+    }
+}
+
+/// Links the segment `name` which comes after `prefix`, and returns the prefix of the next segment.
+fn segment_link(
+    db: &RootDatabase,
+    package: Package,
+    prefix: &Prefix,
+    name: &ast::Name,
+    links: &mut Vec<DocumentLink>,
+) -> Prefix {
+    let range = name.syntax().text_range();
+    let name = Name::from(name.clone());
+    match prefix {
+        Prefix::Dependency => {
+            let Some(dependency) = package
+                .data(db)
+                .dependencies
+                .iter()
+                .find(|dependency| dependency.name.as_str() == name.as_str())
+            else {
+                return Prefix::Unresolved;
+            };
+            let dependency = dependency.package(db);
+            links.push(DocumentLink {
+                range,
+                target: dependency.data(db).manifest_file_id,
+            });
+            Prefix::Module(dependency, Vec::new())
+        },
+        Prefix::Module(module_package, segments) => {
+            let mut segments = segments.clone();
+            segments.push(name);
+            module_link(db, links, range, *module_package, &segments);
+            Prefix::Module(*module_package, segments)
+        },
+        Prefix::Unresolved => Prefix::Unresolved,
+    }
+}
+
+pub(crate) fn keywords(
+    node: &SyntaxNode,
+    kind: SyntaxKind,
+) -> impl Iterator<Item = SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(rowan::NodeOrToken::into_token)
+        .filter(move |token| token.kind() == kind)
+}
+
+/// Links `range` to the module at `segments`, if it exists.
+fn module_link(
+    db: &RootDatabase,
+    links: &mut Vec<DocumentLink>,
+    range: TextRange,
+    package: Package,
+    segments: &[Name],
+) {
+    if let Some(module) = resolve_module(db, package, segments) {
+        links.push(DocumentLink {
+            range,
+            target: module.file_id(db),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use stdx::format_to;
+    use test_fixture::ChangeFixture;
+
+    use crate::AnalysisHost;
+
+    /// Lists the links of the first file of the fixture.
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let mut host = AnalysisHost::default();
+        let fixture = ChangeFixture::parse(wa_fixture);
+        host.apply_change(fixture.change);
+        let analysis = host.analysis();
+        let file_id = fixture.files[0];
+        let text = analysis.file_text(file_id).unwrap();
+
+        let mut actual = String::new();
+        for link in analysis.document_links(file_id).unwrap() {
+            format_to!(
+                actual,
+                "{} {:?} -> {:?}\n",
+                &text[link.range],
+                link.range,
+                link.target
+            );
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn links_package_paths() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+import package::lighting::{shade, brdf::ggx, missing::item};
+import package::lighting;
+
+//- /package.wesl
+
+//- /lighting.wesl
+fn shade() -> f32 { return 1.0; }
+
+//- /lighting/brdf.wesl
+fn ggx() -> f32 { return 1.0; }
+",
+            &expect![[r#"
+                package 7..14 -> FileId(2)
+                lighting 16..24 -> FileId(3)
+                brdf 34..38 -> FileId(4)
+                package 68..75 -> FileId(2)
+                lighting 77..85 -> FileId(3)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn links_super_paths() {
+        check(
+            "
+//- /lighting/shade.wesl package:app root:/ edition:2026_pre
+import super::brdf::ggx;
+import super::super::utils::clamp01;
+
+//- /lighting/brdf.wesl
+fn ggx() -> f32 { return 1.0; }
+
+//- /lighting.wesl
+
+//- /utils.wesl
+fn clamp01(value: f32) -> f32 { return value; }
+",
+            &expect![[r#"
+                super 7..12 -> FileId(3)
+                brdf 14..18 -> FileId(2)
+                super 32..37 -> FileId(3)
+                utils 46..51 -> FileId(4)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn links_dependencies_to_manifest() {
+        check(
+            "
+//- /main.wesl package:app dependencies:lighting edition:2026_pre
+import lighting::brdf::ggx;
+import unknown::brdf::ggx;
+
+//- /lighting/package.wesl package:lighting root:/lighting edition:2026_pre
+
+//- /lighting/brdf.wesl
+fn ggx() -> f32 { return 1.0; }
+",
+            &expect![[r#"
+                lighting 7..15 -> FileId(2)
+                brdf 17..21 -> FileId(4)
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use base_db::{EditionedFileId, FileRange, TextRange};
+use ide_db::RootDatabase;
+use rowan::NodeOrToken;
+use syntax::{
+    AstNode as _, Direction, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, algorithms, ast,
+};
+
+use crate::helpers;
+
+// Feature: Expand and Shrink Selection
+//
+// Extends or shrinks the current selection to the encompassing syntactic construct
+// (expression, statement, item, etc.).
+//
+// Inside template lists, call arguments, attribute parameters and WESL import
+// collections, an element is first selected together with its separating comma, then
+// the contents between the delimiters, and only then the whole list. Swizzle chains
+// like `color.rgb.x` grow one field access at a time.
+pub(crate) fn extend_selection(
+    db: &RootDatabase,
+    frange: FileRange,
+) -> TextRange {
+    let file = EditionedFileId::from_file(db, frange.file_id)
+        .parse(db)
+        .tree();
+    try_extend_selection(file.syntax(), frange.range).unwrap_or(frange.range)
+}
+
+fn try_extend_selection(
+    root: &SyntaxNode,
+    range: TextRange,
+) -> Option<TextRange> {
+    if range.is_empty() {
+        #[expect(
+            clippy::wildcard_enum_match_arm,
+            reason = "infeasible to list all cases"
+        )]
+        let token =
+            helpers::pick_best_token(root.token_at_offset(range.start()), |kind| match kind {
+                SyntaxKind::Identifier => 2,
+                kind if kind.is_trivia() => 0,
+                _ => 1,
+            })?;
+        return Some(token.text_range());
+    }
+
+    let node = match root.covering_element(range) {
+        NodeOrToken::Token(token) => {
+            if token.text_range() != range {
+                return Some(token.text_range());
+            }
+            token.parent()?
+        },
+        NodeOrToken::Node(node) => node,
+    };
+
+    // The child of the current ancestor which is exactly the selection, if any.
+    let mut selected_child: Option<SyntaxElement> = None;
+    for ancestor in node.ancestors() {
+        if is_list(ancestor.kind()) {
+            if let Some(child) = &selected_child
+                && let Some(item) = extend_list_item(child)
+            {
+                return Some(item);
+            }
+            if let Some(inner) = delimited_contents(&ancestor)
+                && inner.contains_range(range)
+                && inner != range
+            {
+                return Some(inner);
+            }
+        }
+        if ancestor.text_range() != range {
+            return Some(ancestor.text_range());
+        }
+        selected_child = Some(SyntaxElement::Node(ancestor));
+    }
+    None
+}
+
+/// Nodes whose children are separated by commas or enclosed in delimiters.
+fn is_list(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::TemplateList
+            | SyntaxKind::Arguments
+            | SyntaxKind::FunctionParameters
+            | SyntaxKind::DiagnosticControl
+            | SyntaxKind::ImportCollection
+            | SyntaxKind::StructBody
+            | SyntaxKind::SwitchCaseSelectors
+            | SyntaxKind::CompoundStatement
+            | SyntaxKind::ParenthesisExpression
+    ) || ast::Attribute::can_cast(kind)
+}
+
+/// Extends the range of a list element to include its separating comma.
+///
+/// The following comma (and the whitespace after it) is preferred, so that deleting the
+/// selection leaves a well-formed list.
+fn extend_list_item(element: &SyntaxElement) -> Option<TextRange> {
+    if let Some(comma) = nearby_comma(element, Direction::Next) {
+        let end = comma
+            .next_token()
+            .filter(|token| token.kind().is_whitespace())
+            .unwrap_or(comma);
+        return Some(TextRange::new(
+            element.text_range().start(),
+            end.text_range().end(),
+        ));
+    }
+    let comma = nearby_comma(element, Direction::Prev)?;
+    Some(TextRange::new(
+        comma.text_range().start(),
+        element.text_range().end(),
+    ))
+}
+
+fn nearby_comma(
+    element: &SyntaxElement,
+    direction: Direction,
+) -> Option<SyntaxToken> {
+    algorithms::non_trivia_sibling(element.clone(), direction)?
+        .into_token()
+        .filter(|token| token.kind() == SyntaxKind::Comma)
+}
+
+/// The range between the opening and closing delimiters of the node, excluding trivia.
+fn delimited_contents(node: &SyntaxNode) -> Option<TextRange> {
+    let mut tokens = node
+        .children_with_tokens()
+        .filter_map(NodeOrToken::into_token);
+    let open = tokens.find(|token| is_opening_delimiter(token.kind()))?;
+    let close = tokens
+        .filter(|token| is_closing_delimiter(token.kind()))
+        .last()?;
+    let first = algorithms::non_trivia_sibling(open.into(), Direction::Next)?;
+    let last = algorithms::non_trivia_sibling(close.clone().into(), Direction::Prev)?;
+    if first.text_range().start() >= close.text_range().start() {
+        return None;
+    }
+    Some(TextRange::new(
+        first.text_range().start(),
+        last.text_range().end(),
+    ))
+}
+
+const fn is_opening_delimiter(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::ParenthesisLeft
+            | SyntaxKind::BraceLeft
+            | SyntaxKind::BracketLeft
+            | SyntaxKind::TemplateStart
+    )
+}
+
+const fn is_closing_delimiter(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::ParenthesisRight
+            | SyntaxKind::BraceRight
+            | SyntaxKind::BracketRight
+            | SyntaxKind::TemplateEnd
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{FileRange, TextRange};
+
+    use crate::fixture;
+
+    /// Repeatedly extends the selection at the cursor, checking each step.
+    #[track_caller]
+    fn check(
+        before: &str,
+        afters: &[&str],
+    ) {
+        let (analysis, position) = fixture::position(before);
+        let text = analysis.file_text(position.file_id).unwrap();
+        let mut range = TextRange::empty(position.offset);
+        for &after in afters {
+            range = analysis
+                .extend_selection(FileRange {
+                    file_id: position.file_id,
+                    range,
+                })
+                .unwrap();
+            assert_eq!(&text[range], after);
+        }
+    }
+
+    #[test]
+    fn extend_selection_expression() {
+        check(
+            "fn foo() -> f32 { return 1.0 + 2.$00 * 3.0; }",
+            &[
+                "2.0",
+                "2.0 * 3.0",
+                "1.0 + 2.0 * 3.0",
+                "return 1.0 + 2.0 * 3.0;",
+            ],
+        );
+    }
+
+    #[test]
+    fn extend_selection_template_list() {
+        check(
+            "var<private> lights: array<vec4<f$032>, 4>;",
+            &[
+                "f32",
+                "<f32>",
+                "vec4<f32>",
+                "vec4<f32>, ",
+                "vec4<f32>, 4",
+                "<vec4<f32>, 4>",
+                "array<vec4<f32>, 4>",
+            ],
+        );
+    }
+
+    #[test]
+    fn extend_selection_last_template_argument() {
+        check(
+            "var<private> lights: array<f32, 4$0>;",
+            &["4", ", 4", "f32, 4", "<f32, 4>"],
+        );
+    }
+
+    #[test]
+    fn extend_selection_swizzle_chain() {
+        check(
+            "fn foo(color: vec4f) -> f32 { return color.rgb$0.x; }",
+            &["rgb", "color.rgb", "color.rgb.x", "return color.rgb.x;"],
+        );
+    }
+
+    #[test]
+    fn extend_selection_function_arguments() {
+        check(
+            "fn foo() -> f32 { return max(1.0, $02.0); }",
+            &["2.0", ", 2.0", "1.0, 2.0", "(1.0, 2.0)", "max(1.0, 2.0)"],
+        );
+    }
+
+    #[test]
+    fn extend_selection_attribute_parameters() {
+        check(
+            "@compute @workgroup_size(8, $08, 1) fn main() {}",
+            &["8", "8, ", "8, 8, 1", "@workgroup_size(8, 8, 1)"],
+        );
+    }
+
+    #[test]
+    fn extend_selection_import_tree() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+import package::lighting::{brdf::{diffuse, spec$0ular}, shadows};
+",
+            &[
+                "specular",
+                ", specular",
+                "diffuse, specular",
+                "{diffuse, specular}",
+                "brdf::{diffuse, specular}",
+                "brdf::{diffuse, specular}, ",
+                "brdf::{diffuse, specular}, shadows",
+                "{brdf::{diffuse, specular}, shadows}",
+            ],
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use base_db::{EditionedFileId, FileId, TextRange};
+use hir_def::{
+    AstIdMap,
+    item_tree::{ItemTree, ModuleItemId},
+};
+use ide_db::RootDatabase;
+use itertools::Itertools as _;
+use rustc_hash::FxHashMap;
+use syntax::{
+    AstNode as _, HasAttributes as _, HasName, HasTemplateParameters as _, SyntaxNode, ast,
+};
+
+#[derive(Debug, Clone)]
+pub struct StructureNode {
+    pub parent: Option<usize>,
+    pub label: String,
+    pub navigation_range: TextRange,
+    pub node_range: TextRange,
+    pub kind: StructureNodeKind,
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureNodeKind {
+    Function,
+    Struct,
+    Field,
+    GlobalVariable,
+    Constant,
+    Override,
+    TypeAlias,
+    Import,
+    /// A block of items guarded by `@if`, `@elif` or `@else`.
+    Conditional,
+}
+
+// Feature: File Structure
+//
+// Provides a tree of the symbols defined in the file.
+// Entry points show their shader stage, global variables their address space,
+// and items inside of conditional `@if`/`@elif`/`@else` blocks are grouped under their condition.
+//
+// The same tree is printed by the `wgsl-analyzer symbols` command.
+pub(crate) fn file_structure(
+    db: &RootDatabase,
+    file_id: FileId,
+) -> Vec<StructureNode> {
+    let file_id = EditionedFileId::from_file(db, file_id);
+    let root = file_id.parse(db).syntax();
+    let item_tree = ItemTree::of(db, file_id);
+    let ast_id_map = AstIdMap::of(db, file_id);
+
+    let mut builder = StructureBuilder::default();
+    for item in item_tree.top_level_items() {
+        match *item {
+            ModuleItemId::ImportStatement(id) => {
+                builder.import(&ast_id_map.get(id).to_node(&root));
+            },
+            ModuleItemId::Function(id) => builder.function(&ast_id_map.get(id).to_node(&root)),
+            ModuleItemId::Struct(id) => builder.r#struct(&ast_id_map.get(id).to_node(&root)),
+            ModuleItemId::GlobalVariable(id) => {
+                let variable = ast_id_map.get(id).to_node(&root);
+                let mut detail = String::from("var");
+                if let Some(template) = variable.template_parameters() {
+                    detail.push_str(&collapse_whitespace(template.syntax()));
+                }
+                if let Some(r#type) = variable.r#type() {
+                    detail.push_str(": ");
+                    detail.push_str(&collapse_whitespace(r#type.syntax()));
+                }
+                builder.item(&variable, StructureNodeKind::GlobalVariable, Some(detail));
+            },
+            ModuleItemId::GlobalConstant(id) => {
+                let constant = ast_id_map.get(id).to_node(&root);
+                let detail = constant
+                    .r#type()
+                    .map(|r#type| collapse_whitespace(r#type.syntax()));
+                builder.item(&constant, StructureNodeKind::Constant, detail);
+            },
+            ModuleItemId::Override(id) => {
+                let r#override = ast_id_map.get(id).to_node(&root);
+                let detail = r#override
+                    .r#type()
+                    .map(|r#type| collapse_whitespace(r#type.syntax()));
+                builder.item(&r#override, StructureNodeKind::Override, detail);
+            },
+            ModuleItemId::TypeAlias(id) => {
+                let type_alias = ast_id_map.get(id).to_node(&root);
+                let detail = type_alias
+                    .type_declaration()
+                    .map(|r#type| collapse_whitespace(r#type.syntax()));
+                builder.item(&type_alias, StructureNodeKind::TypeAlias, detail);
+            },
+            ModuleItemId::GlobalAssertStatement(_) => {},
+        }
+    }
+    builder.nodes
+}
+
+#[derive(Default)]
+struct StructureBuilder {
+    nodes: Vec<StructureNode>,
+    /// Maps conditional blocks to the index of their node.
+    conditionals: FxHashMap<TextRange, usize>,
+}
+
+impl StructureBuilder {
+    fn push(
+        &mut self,
+        node: StructureNode,
+    ) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    /// Returns the innermost conditional block containing the item,
+    /// adding nodes for the conditional blocks if necessary.
+    fn conditional_parent(
+        &mut self,
+        item: &SyntaxNode,
+    ) -> Option<usize> {
+        let blocks = item
+            .ancestors()
+            .skip(1)
+            .filter_map(ast::GlobalCompoundDeclaration::cast)
+            .collect::<Vec<_>>();
+
+        let mut parent = None;
+        for block in blocks.iter().rev() {
+            let Some(condition) = block
+                .attributes()
+                .into_iter()
+                .flatten()
+                .find(ast::Attribute::is_conditional_compilation)
+            else {
+                continue;
+            };
+            let range = block.syntax().text_range();
+            let index = if let Some(&index) = self.conditionals.get(&range) {
+                index
+            } else {
+                let index = self.push(StructureNode {
+                    parent,
+                    label: collapse_whitespace(condition.syntax()),
+                    navigation_range: condition.syntax().text_range(),
+                    node_range: condition.syntax().text_range().cover(range),
+                    kind: StructureNodeKind::Conditional,
+                    detail: None,
+                });
+                self.conditionals.insert(range, index);
+                index
+            };
+            parent = Some(index);
+        }
+        parent
+    }
+
+    fn item<Node: HasName>(
+        &mut self,
+        node: &Node,
+        kind: StructureNodeKind,
+        detail: Option<String>,
+    ) -> Option<usize> {
+        let name = node.name()?;
+        let parent = self.conditional_parent(node.syntax());
+        Some(self.push(StructureNode {
+            parent,
+            label: name.text().as_str().to_owned(),
+            navigation_range: name.syntax().text_range(),
+            node_range: node.syntax().text_range(),
+            kind,
+            detail,
+        }))
+    }
+
+    fn import(
+        &mut self,
+        import: &ast::ImportStatement,
+    ) -> Option<usize> {
+        let tree = import.item()?;
+        let mut label = import
+            .relative()
+            .map(|relative| collapse_whitespace(relative.syntax()))
+            .unwrap_or_default();
+        label.push_str(&collapse_whitespace(tree.syntax()));
+        let parent = self.conditional_parent(import.syntax());
+        Some(self.push(StructureNode {
+            parent,
+            label,
+            navigation_range: tree.syntax().text_range(),
+            node_range: import.syntax().text_range(),
+            kind: StructureNodeKind::Import,
+            detail: None,
+        }))
+    }
+
+    fn function(
+        &mut self,
+        function: &ast::FunctionDeclaration,
+    ) {
+        let stage = function
+            .attributes()
+            .into_iter()
+            .flatten()
+            .find(|attribute| {
+                matches!(
+                    attribute,
+                    ast::Attribute::VertexAttribute(_)
+                        | ast::Attribute::FragmentAttribute(_)
+                        | ast::Attribute::ComputeAttribute(_)
+                )
+            });
+        let mut detail = String::new();
+        if let Some(stage) = stage {
+            detail.push_str(&collapse_whitespace(stage.syntax()));
+            detail.push(' ');
+        }
+        detail.push_str("fn(");
+        if let Some(parameters) = function.parameter_list() {
+            let parameters = parameters.parameters().map(|parameter| {
+                let name = parameter
+                    .name()
+                    .map(|name| name.text().as_str().to_owned())
+                    .unwrap_or_default();
+                match parameter.r#type() {
+                    Some(r#type) => format!("{name}: {}", collapse_whitespace(r#type.syntax())),
+                    None => name,
+                }
+            });
+            detail.push_str(&parameters.format(", ").to_string());
+        }
+        detail.push(')');
+        if let Some(r#type) = function
+            .return_type()
+            .and_then(|return_type| return_type.r#type())
+        {
+            detail.push_str(" -> ");
+            detail.push_str(&collapse_whitespace(r#type.syntax()));
+        }
+        self.item(function, StructureNodeKind::Function, Some(detail));
+    }
+
+    fn r#struct(
+        &mut self,
+        r#struct: &ast::StructDeclaration,
+    ) {
+        let Some(parent) = self.item(r#struct, StructureNodeKind::Struct, None) else {
+            return;
+        };
+        for field in r#struct.body().iter().flat_map(ast::StructBody::fields) {
+            let Some(name) = field.name() else {
+                continue;
+            };
+            self.push(StructureNode {
+                parent: Some(parent),
+                label: name.text().as_str().to_owned(),
+                navigation_range: name.syntax().text_range(),
+                node_range: field.syntax().text_range(),
+                kind: StructureNodeKind::Field,
+                detail: field
+                    .r#type()
+                    .map(|r#type| collapse_whitespace(r#type.syntax())),
+            });
+        }
+    }
+}
+
+/// Renders the node on a single line.
+fn collapse_whitespace(node: &SyntaxNode) -> String {
+    node.text().to_string().split_whitespace().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use stdx::format_to;
+
+    use super::{StructureNode, file_structure};
+    use crate::fixture;
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, file_id) = fixture::single_file_db(wa_fixture);
+        let structure = analysis.with_db(|db| file_structure(db, file_id)).unwrap();
+
+        let mut actual = String::new();
+        for node in &structure {
+            let depth =
+                std::iter::successors(node.parent, |&parent| structure[parent].parent).count();
+            let StructureNode {
+                label,
+                navigation_range,
+                kind,
+                detail,
+                ..
+            } = node;
+            format_to!(
+                actual,
+                "{}{kind:?} {label} {navigation_range:?}",
+                "  ".repeat(depth)
+            );
+            if let Some(detail) = detail {
+                format_to!(actual, " {detail}");
+            }
+            actual.push('\n');
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn file_structure_items() {
+        check(
+            "
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec3f,
+}
+alias Color = vec3f;
+const SCALE: f32 = 2.0;
+override WORKGROUP = 64u;
+@group(0) @binding(0) var<storage, read_write> data: array<f32>;
+var<private> counter: u32;
+fn helper(a: f32, b: f32) -> f32 { return a + b; }
+@vertex
+fn main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    var output: VertexOutput;
+    return output;
+}
+",
+            &expect![[r#"
+                Struct VertexOutput 7..19
+                  Field position 45..53 vec4<f32>
+                  Field color 83..88 vec3f
+                TypeAlias Color 105..110 vec3f
+                Constant SCALE 126..131 f32
+                Override WORKGROUP 153..162
+                GlobalVariable data 217..221 var<storage, read_write>: array<f32>
+                GlobalVariable counter 248..255 var<private>: u32
+                Function helper 265..271 fn(a: f32, b: f32) -> f32
+                Function main 324..328 @vertex fn(index: u32) -> VertexOutput
+            "#]],
+        );
+    }
+
+    #[test]
+    fn file_structure_imports_and_conditionals() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+import package::lighting::{brdf, shade as diffuse};
+@if(SHADOWS) {
+    const SHADOW_BIAS = 0.01;
+    @if(SOFT_SHADOWS) {
+        fn pcf() {}
+    }
+}
+@elif(AMBIENT_OCCLUSION) {
+    fn ao() {}
+}
+",
+            &expect![[r#"
+                Import package::lighting::{brdf, shade as diffuse} 16..50
+                Conditional @if(SHADOWS) 52..64
+                  Constant SHADOW_BIAS 77..88
+                  Conditional @if(SOFT_SHADOWS) 101..118
+                    Function pcf 132..135 fn()
+                Conditional @elif(AMBIENT_OCCLUSION) 149..173
+                  Function ao 183..185 fn()
+            "#]],
+        );
+    }
+}
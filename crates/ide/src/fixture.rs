@@ -19,3 +19,27 @@ pub(crate) fn single_file_db(source: &str) -> (Analysis, FileId) {
 
     (host.analysis(), fixture.files[0])
 }
+
+/// Creates analysis from a multi-file fixture, returns the position marked with `$0`.
+pub(crate) fn position(wa_fixture: &str) -> (Analysis, FilePosition) {
+    let mut host = AnalysisHost::default();
+    let fixture = ChangeFixture::parse(wa_fixture);
+    host.apply_change(fixture.change);
+    let (file_id, range_or_offset) = fixture
+        .file_position
+        .expect("Could not find file position in fixture. Did you forget to add an `$0`?");
+    let offset = range_or_offset.expect_offset();
+    (host.analysis(), FilePosition { file_id, offset })
+}
+
+/// Creates analysis from a multi-file fixture, returns the range marked with two `$0`.
+pub(crate) fn range(wa_fixture: &str) -> (Analysis, FileRange) {
+    let mut host = AnalysisHost::default();
+    let fixture = ChangeFixture::parse(wa_fixture);
+    host.apply_change(fixture.change);
+    let (file_id, range_or_offset) = fixture
+        .file_position
+        .expect("Could not find file position in fixture. Did you forget to add an `$0`?");
+    let range = range_or_offset.expect_range();
+    (host.analysis(), FileRange { file_id, range })
+}
@@ -1,6 +1,5 @@
-use base_db::{EditionedFileId, FileId, SourceDatabase as _, TextRange};
-use rowan::NodeOrToken;
-use syntax::{AstNode as _, SyntaxNode, ast};
+use base_db::{EditionedFileId, FileId, FileRange, TextRange};
+use syntax::{AstNode as _, SyntaxNode};
 use wgsl_formatter::FormattingOptions;
 
 use crate::RootDatabase;
@@ -8,19 +7,21 @@ use crate::RootDatabase;
 pub(crate) fn format(
     db: &RootDatabase,
     file_id: FileId,
-    range: Option<TextRange>,
-) -> Option<SyntaxNode> {
+) -> SyntaxNode {
     let file_id = EditionedFileId::from_file(db, file_id);
-    let file = file_id.parse(db).tree();
-
-    let node = match range {
-        None => file.syntax().clone_for_update(),
-        Some(range) => match file.syntax().covering_element(range) {
-            NodeOrToken::Node(node) => node.clone_for_update(),
-            NodeOrToken::Token(_) => return None,
-        },
-    };
-
+    let node = file_id.parse(db).tree().syntax().clone_for_update();
     wgsl_formatter::format_recursive(&node, &FormattingOptions::default());
-    Some(node)
+    node
+}
+
+/// Formats the statements or items intersecting the range, returning the range of the file
+/// which changed and its formatted replacement.
+pub(crate) fn format_range(
+    db: &RootDatabase,
+    frange: FileRange,
+) -> Option<(TextRange, String)> {
+    let file = EditionedFileId::from_file(db, frange.file_id)
+        .parse(db)
+        .tree();
+    wgsl_formatter::format_range(file.syntax(), frange.range, &FormattingOptions::default())
 }
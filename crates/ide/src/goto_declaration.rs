@@ -0,0 +1,128 @@
+use base_db::{EditionedFileId, FilePosition, RangeInfo};
+use hir::{Semantics, definition::Definition};
+use hir_def::item_tree::Name;
+use ide_db::RootDatabase;
+use syntax::{AstNode as _, SyntaxKind, ast};
+
+use crate::{NavigationTarget, goto_definition, helpers};
+
+// Feature: Go to Declaration
+//
+// Navigates to the declaration of an identifier.
+//
+// For names which were brought into scope by a WESL `import`, this is the
+// import item, while go to definition continues to the module which defines the item.
+// Otherwise, this is the same as go to definition.
+pub(crate) fn goto_declaration(
+    db: &RootDatabase,
+    file_position: FilePosition,
+) -> Option<RangeInfo<NavigationTarget>> {
+    let semantics = &Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, file_position.file_id);
+    let file = semantics.parse(file_id);
+    let token = file.syntax().token_at_offset(file_position.offset);
+    let token = helpers::pick_best_token(token, |kind| usize::from(kind == SyntaxKind::Identifier))
+        .filter(|token| token.kind() == SyntaxKind::Identifier)?;
+
+    let import_item = token.parent().and_then(ast::Path::cast).and_then(|path| {
+        let mut segments = path.segments();
+        let first_segment = segments.next()?;
+        // A single segment can also refer to a local, which shadows the imported name.
+        if segments.next().is_none()
+            && !matches!(
+                Definition::from_token(semantics, file_id, &token),
+                Some(Definition::ModuleDef(_))
+            )
+        {
+            return None;
+        }
+        semantics.find_import_item(file_id, &Name::from(first_segment.text()))
+    });
+
+    let Some(import_item) = import_item else {
+        return goto_definition::goto_definition(db, file_position);
+    };
+    let focus_range = import_item
+        .value
+        .alias()
+        .or_else(|| import_item.value.name())
+        .map(|name| name.syntax().text_range());
+    let navigation = NavigationTarget::from_syntax(
+        import_item.file_id.file_id(db),
+        import_item.value.syntax().text_range(),
+        focus_range,
+    );
+    Some(RangeInfo::new(token.text_range(), navigation))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+
+    use crate::fixture;
+
+    #[track_caller]
+    #[expect(clippy::use_debug, reason = "useful in tests")]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let navigation = analysis
+            .goto_declaration(position)
+            .unwrap()
+            .expect("no declaration found");
+        let text = analysis.file_text(navigation.info.file_id).unwrap();
+        let actual = format!(
+            "{:?} {}",
+            navigation.info.file_id, &text[navigation.info.full_range]
+        );
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn goto_declaration_stops_at_import() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+import package::lighting::brdf as shade;
+fn main() -> f32 {
+    return sh$0ade();
+}
+//- /lighting.wesl edition:2026_pre
+fn brdf() -> f32 { return 1.0; }
+",
+            &expect!["FileId(1) brdf as shade"],
+        );
+    }
+
+    #[test]
+    fn goto_declaration_of_module_path() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+import package::lighting;
+fn main() -> f32 {
+    return lighting::am$0bient();
+}
+//- /lighting.wesl edition:2026_pre
+fn ambient() -> f32 { return 1.0; }
+",
+            &expect!["FileId(1) lighting"],
+        );
+    }
+
+    #[test]
+    fn goto_declaration_without_import() {
+        check(
+            "
+//- /main.wesl edition:2026_pre
+fn ambient() -> f32 { return 1.0; }
+fn main() -> f32 {
+    return amb$0ient();
+}
+",
+            &expect!["FileId(1) fn ambient() -> f32 { return 1.0; }"],
+        );
+    }
+}
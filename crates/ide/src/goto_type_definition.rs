@@ -0,0 +1,256 @@
+use base_db::{EditionedFileId, FilePosition, RangeInfo};
+use hir::{HasSource as _, ModuleDef, Semantics, Struct, definition::Definition};
+use hir_ty::ty::Type;
+use ide_db::RootDatabase;
+use rowan::NodeOrToken;
+use syntax::{AstNode as _, SyntaxKind, SyntaxToken, ast};
+
+use crate::{NavigationTarget, goto_definition::TryToNavigationTarget as _, helpers};
+
+// Feature: Go to Type Definition
+//
+// Navigates to the struct or alias which defines the type of an expression.
+// Written types are preferred, so a `var lamp: Lamp` navigates to `alias Lamp`.
+// References, pointers and arrays are looked through, so going to the type of
+// a `var<storage> lights: array<Light>` navigates to `Light`.
+pub(crate) fn goto_type_definition(
+    db: &RootDatabase,
+    file_position: FilePosition,
+) -> Option<RangeInfo<NavigationTarget>> {
+    let semantics = &Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, file_position.file_id);
+    let file = semantics.parse(file_id);
+    let token = file.syntax().token_at_offset(file_position.offset);
+
+    #[expect(
+        clippy::wildcard_enum_match_arm,
+        reason = "infeasible to list all cases"
+    )]
+    let token = helpers::pick_best_token(token, |token| match token {
+        SyntaxKind::Identifier => 2,
+        kind if kind.is_trivia() => 0,
+        _ => 1,
+    })?;
+
+    let definition = match Definition::from_token(semantics, file_id, &token) {
+        // Type names are their own type definition.
+        Some(
+            definition @ Definition::ModuleDef(ModuleDef::Struct(_) | ModuleDef::TypeAlias(_)),
+        ) => definition,
+        // The written type keeps aliases, which the inferred type has erased.
+        definition => definition
+            .as_ref()
+            .and_then(|definition| written_type(db, semantics, definition))
+            .or_else(|| {
+                let r#type = type_of_expression(semantics, file_id, &token)
+                    .or_else(|| type_of_definition(db, definition.as_ref()?))?;
+                Some(Definition::ModuleDef(ModuleDef::Struct(Struct::from_type(
+                    db, r#type,
+                )?)))
+            })?,
+    };
+
+    Some(RangeInfo::new(
+        token.text_range(),
+        definition.try_to_navigation_target(db)?,
+    ))
+}
+
+/// The first struct or alias named in the type annotation of a declaration.
+fn written_type(
+    db: &RootDatabase,
+    semantics: &Semantics<'_>,
+    definition: &Definition,
+) -> Option<Definition> {
+    let (file_id, declaration) = match definition {
+        Definition::Local(local) => {
+            let name = local.source(db)?;
+            (name.file_id, name.value.syntax().parent()?)
+        },
+        Definition::Field(field) => {
+            let member = field.source(db)?;
+            (member.file_id, member.value.syntax().clone())
+        },
+        Definition::ModuleDef(ModuleDef::GlobalVariable(variable)) => {
+            let declaration = variable.source(db)?;
+            (declaration.file_id, declaration.value.syntax().clone())
+        },
+        Definition::ModuleDef(ModuleDef::GlobalConstant(constant)) => {
+            let declaration = constant.source(db)?;
+            (declaration.file_id, declaration.value.syntax().clone())
+        },
+        Definition::ModuleDef(ModuleDef::Override(r#override)) => {
+            let declaration = r#override.source(db)?;
+            (declaration.file_id, declaration.value.syntax().clone())
+        },
+        Definition::ModuleDef(
+            ModuleDef::Function(_)
+            | ModuleDef::Struct(_)
+            | ModuleDef::TypeAlias(_)
+            | ModuleDef::GlobalAssertStatement(_),
+        )
+        | Definition::BuiltinFunction(_)
+        | Definition::BuiltinType(_)
+        | Definition::BuiltinTypeGenerator(_)
+        | Definition::BuiltinEnumerant(_)
+        | Definition::BuiltinDeclaration(_) => return None,
+    };
+    // Template parameters come after the name, so `array<Light>` finds `Light`.
+    declaration
+        .children()
+        .find_map(ast::TypeSpecifier::cast)?
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|token| token.kind() == SyntaxKind::Identifier)
+        .filter_map(|token| Definition::from_token(semantics, file_id, &token))
+        .find(|definition| {
+            matches!(
+                definition,
+                Definition::ModuleDef(ModuleDef::Struct(_) | ModuleDef::TypeAlias(_))
+            )
+        })
+}
+
+/// The type of the innermost expression containing the token.
+fn type_of_expression(
+    semantics: &Semantics<'_>,
+    file_id: EditionedFileId,
+    token: &SyntaxToken,
+) -> Option<Type> {
+    let expression = token.parent_ancestors().find_map(ast::Expression::cast)?;
+    let container = semantics
+        .find_container(file_id, expression.syntax())?
+        .as_def_with_body_id()?;
+    semantics.analyze(container).type_of_expression(&expression)
+}
+
+/// The type of a declaration, for when the cursor is on its name.
+fn type_of_definition(
+    db: &RootDatabase,
+    definition: &Definition,
+) -> Option<Type> {
+    let r#type = match definition {
+        Definition::Local(local) => local.ty(db),
+        Definition::Field(field) => field.ty(db),
+        Definition::ModuleDef(ModuleDef::GlobalVariable(variable)) => variable.ty(db),
+        Definition::ModuleDef(ModuleDef::GlobalConstant(constant)) => constant.ty(db),
+        Definition::ModuleDef(ModuleDef::Override(r#override)) => r#override.ty(db),
+        Definition::ModuleDef(
+            ModuleDef::Function(_)
+            | ModuleDef::Struct(_)
+            | ModuleDef::TypeAlias(_)
+            | ModuleDef::GlobalAssertStatement(_),
+        )
+        | Definition::BuiltinFunction(_)
+        | Definition::BuiltinType(_)
+        | Definition::BuiltinTypeGenerator(_)
+        | Definition::BuiltinEnumerant(_)
+        | Definition::BuiltinDeclaration(_) => return None,
+    };
+    Some(r#type)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+
+    use crate::fixture;
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let navigation = analysis
+            .goto_type_definition(position)
+            .unwrap()
+            .expect("no type definition found");
+        let text = analysis.file_text(navigation.info.file_id).unwrap();
+        let actual = &text[navigation.info.focus_or_full_range()];
+        expect.assert_eq(actual);
+    }
+
+    #[test]
+    fn goto_type_definition_of_local() {
+        check(
+            "
+struct Light { color: vec3f }
+fn main() {
+    let light = Light(vec3f(1.0));
+    let color = lig$0ht.color;
+}
+",
+            &expect!["Light"],
+        );
+    }
+
+    #[test]
+    fn goto_type_definition_through_pointer() {
+        check(
+            "
+struct Light { color: vec3f }
+fn shade(light: ptr<function, Light>) -> vec3f {
+    return (*lig$0ht).color;
+}
+",
+            &expect!["Light"],
+        );
+    }
+
+    #[test]
+    fn goto_type_definition_through_array() {
+        check(
+            "
+struct Light { color: vec3f }
+@group(0) @binding(0) var<storage> lights: array<Light>;
+fn main() {
+    let color = lig$0hts[0].color;
+}
+",
+            &expect!["Light"],
+        );
+    }
+
+    #[test]
+    fn goto_type_definition_of_alias() {
+        check(
+            "
+struct Light { color: vec3f }
+alias Lamp = Light;
+fn main() {
+    var lamp: La$0mp;
+}
+",
+            &expect!["Lamp"],
+        );
+    }
+
+    #[test]
+    fn goto_type_definition_of_variable_with_alias() {
+        check(
+            "
+struct Light { color: vec3f }
+alias Lamp = Light;
+fn main() {
+    var lamp: Lamp;
+    let color = la$0mp.color;
+}
+",
+            &expect!["Lamp"],
+        );
+    }
+
+    #[test]
+    fn goto_type_definition_of_alias_in_array() {
+        check(
+            "
+struct Light { color: vec3f }
+alias Lamp = Light;
+@group(0) @binding(0) var<storage> lam$0ps: array<Lamp>;
+",
+            &expect!["Lamp"],
+        );
+    }
+}
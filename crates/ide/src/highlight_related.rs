@@ -0,0 +1,376 @@
+//! Highlights the parts of a file which are related to the token at the cursor.
+//!
+//! On a name, these are the declaration and the usages of what it refers to. On `fn`, `return` or
+//! `discard`, these are the exit points of the function, and on `loop`, `for`, `while`, `break`
+//! or `continue`, the keywords which enter or leave the same loop.
+
+use base_db::{EditionedFileId, FilePosition, TextRange};
+use hir::{Semantics, definition::Definition};
+use ide_db::{
+    RootDatabase,
+    search::{FindUsages, ReferenceCategory, SearchScope},
+};
+use itertools::Itertools as _;
+use syntax::{AstNode as _, SyntaxKind, SyntaxNode, SyntaxToken, ast};
+
+use crate::{goto_definition::TryToNavigationTarget as _, helpers};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HighlightedRange {
+    pub range: TextRange,
+    /// Whether the range reads or writes the highlighted definition.
+    ///
+    /// This is `None` for declarations and for keywords.
+    pub category: Option<ReferenceCategory>,
+}
+
+// Feature: Highlight Related
+//
+// Highlights constructs related to the thing under the cursor:
+//
+// 1. if on an identifier, highlights all its references and its declaration in the file
+// 1. if on `fn`, `return` or `discard`, highlights all exit points of the function
+// 1. if on `loop`, `for`, `while`, `break` or `continue`, highlights the keywords of the loop,
+//    or of the `switch` statement which a `break` leaves
+pub(crate) fn highlight_related(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<Vec<HighlightedRange>> {
+    let _p = tracing::info_span!("highlight_related").entered();
+    let semantics = &Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, position.file_id);
+    let file = file_id.parse(db).tree();
+    let token = file.syntax().token_at_offset(position.offset);
+
+    #[expect(
+        clippy::wildcard_enum_match_arm,
+        reason = "infeasible to list all cases"
+    )]
+    let token = helpers::pick_best_token(token, |kind| match kind {
+        SyntaxKind::Identifier => 3,
+        SyntaxKind::Fn
+        | SyntaxKind::Return
+        | SyntaxKind::Discard
+        | SyntaxKind::Loop
+        | SyntaxKind::For
+        | SyntaxKind::While
+        | SyntaxKind::Break
+        | SyntaxKind::Continue => 2,
+        kind if kind.is_trivia() => 0,
+        _ => 1,
+    })?;
+
+    #[expect(
+        clippy::wildcard_enum_match_arm,
+        reason = "infeasible to list all cases"
+    )]
+    let highlights = match token.kind() {
+        SyntaxKind::Fn | SyntaxKind::Return | SyntaxKind::Discard => highlight_exit_points(&token),
+        SyntaxKind::Loop
+        | SyntaxKind::For
+        | SyntaxKind::While
+        | SyntaxKind::Break
+        | SyntaxKind::Continue => highlight_break_points(&token),
+        _ => highlight_references(semantics, file_id, &token),
+    }?;
+    Some(
+        highlights
+            .into_iter()
+            .sorted_by_key(|highlight| highlight.range.start())
+            .dedup()
+            .collect(),
+    )
+}
+
+fn highlight_references(
+    semantics: &Semantics<'_>,
+    file_id: EditionedFileId,
+    token: &SyntaxToken,
+) -> Option<Vec<HighlightedRange>> {
+    let db = semantics.db;
+    let definition = Definition::from_token(semantics, file_id, token)?;
+    let scope = SearchScope::single_file(file_id);
+    let usages = FindUsages::new(&definition, semantics)
+        .in_scope(&scope)
+        .all();
+
+    let declaration = definition
+        .try_to_navigation_target(db)
+        .filter(|target| target.file_id == file_id.file_id(db))
+        .map(|target| HighlightedRange {
+            range: target.focus_or_full_range(),
+            category: None,
+        });
+    let references = usages
+        .into_iter()
+        .flat_map(|(_, references)| references)
+        .map(|reference| HighlightedRange {
+            range: reference.range,
+            category: reference.category,
+        });
+    Some(declaration.into_iter().chain(references).collect())
+}
+
+/// Highlights `fn` and every `return` and `discard` of the function around `token`.
+fn highlight_exit_points(token: &SyntaxToken) -> Option<Vec<HighlightedRange>> {
+    let function = token
+        .parent_ancestors()
+        .find_map(ast::FunctionDeclaration::cast)?;
+    let function = function.syntax();
+    let exits = function
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                SyntaxKind::ReturnStatement | SyntaxKind::DiscardStatement
+            )
+        })
+        .filter_map(|node| keyword(&node, &[SyntaxKind::Return, SyntaxKind::Discard]));
+    Some(
+        keyword(function, &[SyntaxKind::Fn])
+            .into_iter()
+            .chain(exits)
+            .collect(),
+    )
+}
+
+/// Highlights the keyword of the loop or `switch` statement which the `break` or `continue` at
+/// `token` targets, or which the keyword at `token` starts, together with its `break`s and
+/// `continue`s.
+fn highlight_break_points(token: &SyntaxToken) -> Option<Vec<HighlightedRange>> {
+    let target = break_target(&token.parent()?)?;
+    let edges = target
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                SyntaxKind::BreakStatement
+                    | SyntaxKind::BreakIfStatement
+                    | SyntaxKind::ContinueStatement
+            )
+        })
+        .filter(|node| break_target(node).as_ref() == Some(&target))
+        .filter_map(|node| keyword(&node, &[SyntaxKind::Break, SyntaxKind::Continue]));
+    Some(
+        keyword(
+            &target,
+            &[
+                SyntaxKind::Loop,
+                SyntaxKind::For,
+                SyntaxKind::While,
+                SyntaxKind::Switch,
+            ],
+        )
+        .into_iter()
+        .chain(edges)
+        .collect(),
+    )
+}
+
+/// The statement which `node` leaves or continues, or which `node` is.
+///
+/// `continue` skips `switch` statements, which only `break` can leave.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn break_target(node: &SyntaxNode) -> Option<SyntaxNode> {
+    let is_continue = node.kind() == SyntaxKind::ContinueStatement;
+    node.ancestors()
+        .take_while(|ancestor| ancestor.kind() != SyntaxKind::FunctionDeclaration)
+        .find(|ancestor| match ancestor.kind() {
+            SyntaxKind::LoopStatement | SyntaxKind::ForStatement | SyntaxKind::WhileStatement => {
+                true
+            },
+            SyntaxKind::SwitchStatement => !is_continue,
+            _ => false,
+        })
+}
+
+/// The range of the first keyword among `kinds` which is a direct child of `node`.
+fn keyword(
+    node: &SyntaxNode,
+    kinds: &[SyntaxKind],
+) -> Option<HighlightedRange> {
+    let keyword = node
+        .children_with_tokens()
+        .filter_map(rowan::NodeOrToken::into_token)
+        .find(|token| kinds.contains(&token.kind()))?;
+    Some(HighlightedRange {
+        range: keyword.text_range(),
+        category: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use stdx::format_to;
+
+    use crate::fixture;
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let text = analysis.file_text(position.file_id).unwrap();
+        let highlights = analysis.highlight_related(position).unwrap().unwrap();
+
+        let mut actual = String::new();
+        for highlight in highlights {
+            format_to!(actual, "{} {:?}", &text[highlight.range], highlight.range);
+            if let Some(category) = highlight.category {
+                format_to!(actual, " {category:?}");
+            }
+            actual.push('\n');
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn highlight_local() {
+        check(
+            "
+fn main() {
+    var a$0 = 1;
+    a = a + 1;
+    a += 2;
+    let b = a;
+}
+",
+            &expect![[r#"
+                a 20..21
+                a 31..32 Write
+                a 35..36 Read
+                a 46..47 Write
+                a 66..67 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn highlight_field() {
+        check(
+            "
+struct Light { intensity: f32 }
+fn main() {
+    var light: Light;
+    light.intensity$0 = 2.0;
+    let a = light.intensity;
+}
+",
+            &expect![[r#"
+                intensity 15..24
+                intensity 76..85 Write
+                intensity 111..120 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn highlight_global() {
+        check(
+            "
+const SCALE = 2.0;
+fn main() {
+    let a = SCALE$0 * 2.0;
+}
+",
+            &expect![[r#"
+                SCALE 6..11
+                SCALE 43..48 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn highlight_exit_points() {
+        check(
+            "
+@fragment
+fn$0 main(@location(0) value: f32) -> @location(0) vec4<f32> {
+    if value < 0.0 {
+        discard;
+    }
+    if value > 1.0 {
+        return vec4(1.0);
+    }
+    return vec4(value);
+}
+",
+            &expect![[r#"
+                fn 10..12
+                discard 100..107
+                return 144..150
+                return 172..178
+            "#]],
+        );
+    }
+
+    #[test]
+    fn highlight_exit_points_from_return() {
+        check(
+            "
+fn other() -> f32 { return 2.0; }
+fn main() -> f32 {
+    return$0 1.0;
+}
+",
+            &expect![[r#"
+                fn 34..36
+                return 57..63
+            "#]],
+        );
+    }
+
+    #[test]
+    fn highlight_loop_edges() {
+        check(
+            "
+fn main() {
+    loop$0 {
+        if true { break; }
+        for (var i = 0; i < 4; i++) {
+            continue;
+        }
+        switch 1 {
+            default: { break; }
+        }
+        continue;
+        continuing {
+            break if false;
+        }
+    }
+}
+",
+            &expect![[r#"
+                loop 16..20
+                break 41..46
+                continue 189..197
+                break 232..237
+            "#]],
+        );
+    }
+
+    #[test]
+    fn highlight_switch_break() {
+        check(
+            "
+fn main() {
+    loop {
+        switch 1 {
+            default: { break$0; }
+        }
+        break;
+    }
+}
+",
+            &expect![[r#"
+                switch 31..37
+                break 65..70
+            "#]],
+        );
+    }
+}
@@ -1,8 +1,21 @@
 use base_db::{EditionedFileId, FilePosition, FileRange, RangeInfo, SourceDatabase as _};
-use hir::Semantics;
+use hir::{
+    Field, Function, GlobalConstant, GlobalVariable, HasSource as _, Local, ModuleDef, Override,
+    Semantics, Struct, TypeAlias, definition::Definition,
+};
+use hir_ty::ty::{
+    Type,
+    pretty::{TypeVerbosity, pretty_fn_with_verbosity, pretty_type_with_verbosity},
+};
 use ide_db::RootDatabase;
+use itertools::Itertools as _;
+use rowan::NodeOrToken;
+use stdx::{format_to, format_to_acc};
+use syntax::{
+    AstNode as _, Direction, HasAttributes, HasTemplateParameters as _, SyntaxKind, SyntaxNode, ast,
+};
 
-use crate::{NavigationTarget, markup::Markup};
+use crate::{NavigationTarget, helpers, markup::Markup};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HoverConfig {
@@ -72,11 +85,799 @@ pub struct HoverGotoTypeData {
 pub(crate) fn hover(
     db: &RootDatabase,
     file_range: FileRange,
-    _config: &HoverConfig,
+    config: &HoverConfig,
 ) -> Option<RangeInfo<HoverResult>> {
-    let _semantics = &Semantics::new(db);
+    let semantics = &Semantics::new(db);
     let file_id = EditionedFileId::from_file(db, file_range.file_id);
-    let _file = file_id.parse(db).tree();
-    // TODO: Implement hovering and https://github.com/wgsl-analyzer/wgsl-analyzer/issues/362
-    None
+    let file = file_id.parse(db).tree();
+
+    if !file_range.range.is_empty() {
+        return hover_range(semantics, file_id, file.syntax(), file_range, config);
+    }
+
+    let token = file.syntax().token_at_offset(file_range.range.start());
+
+    #[expect(
+        clippy::wildcard_enum_match_arm,
+        reason = "infeasible to list all cases"
+    )]
+    let token = helpers::pick_best_token(token, |token| match token {
+        SyntaxKind::Identifier => 2,
+        kind if kind.is_trivia() => 0,
+        _ => 1,
+    })?;
+
+    let definition = Definition::from_token(semantics, file_id, &token)?;
+    let sections = render_definition(db, &definition, config)?;
+    Some(RangeInfo::new(
+        token.text_range(),
+        HoverResult {
+            markup: sections.into_markup(&config.format),
+            actions: Vec::new(),
+        },
+    ))
+}
+
+/// Shows the type of the expression covering the selected range.
+fn hover_range(
+    semantics: &Semantics<'_>,
+    file_id: EditionedFileId,
+    root: &SyntaxNode,
+    file_range: FileRange,
+    config: &HoverConfig,
+) -> Option<RangeInfo<HoverResult>> {
+    let expression = match root.covering_element(file_range.range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    }
+    .ancestors()
+    .find_map(ast::Expression::cast)?;
+
+    let container = semantics.find_container(file_id, expression.syntax())?;
+    let r#type = semantics
+        .analyze(container.as_def_with_body_id()?)
+        .type_of_expression(&expression)?;
+
+    let sections = HoverSections {
+        code: vec![pretty_type(semantics.db, r#type)],
+        ..HoverSections::default()
+    };
+    Some(RangeInfo::new(
+        expression.syntax().text_range(),
+        HoverResult {
+            markup: sections.into_markup(&config.format),
+            actions: Vec::new(),
+        },
+    ))
+}
+
+/// The parts of a hover, before they are rendered in a [`HoverDocFormat`].
+#[derive(Default)]
+struct HoverSections {
+    /// Code blocks, for example the parent struct of a field and the signature.
+    code: Vec<String>,
+    /// Size, alignment and offset of the definition.
+    layout: Option<String>,
+    /// The comments directly preceding the definition.
+    documentation: Option<String>,
+}
+
+impl HoverSections {
+    fn into_markup(
+        self,
+        format: &HoverDocFormat,
+    ) -> Markup {
+        let (mut text, separator) = match format {
+            HoverDocFormat::Markdown => (
+                self.code
+                    .iter()
+                    .map(|code| Markup::fenced_block(code).to_string())
+                    .join("\n"),
+                "\n\n---\n\n",
+            ),
+            HoverDocFormat::PlainText => (self.code.join("\n"), "\n\n"),
+        };
+        for section in self.layout.into_iter().chain(self.documentation) {
+            text.push_str(separator);
+            text.push_str(&section);
+        }
+        text.into()
+    }
+}
+
+fn render_definition(
+    db: &RootDatabase,
+    definition: &Definition,
+    config: &HoverConfig,
+) -> Option<HoverSections> {
+    let mut sections = match definition {
+        Definition::Local(local) => render_local(db, *local)?,
+        Definition::Field(field) => render_field(db, *field, config)?,
+        Definition::ModuleDef(module_def) => match module_def {
+            ModuleDef::Function(function) => render_function(db, *function)?,
+            ModuleDef::GlobalVariable(variable) => render_global_variable(db, *variable)?,
+            ModuleDef::GlobalConstant(constant) => render_global_constant(db, *constant)?,
+            ModuleDef::Override(override_declaration) => {
+                render_override(db, *override_declaration)?
+            },
+            ModuleDef::Struct(r#struct) => render_struct(db, *r#struct, config)?,
+            ModuleDef::TypeAlias(type_alias) => render_type_alias(db, *type_alias)?,
+            ModuleDef::GlobalAssertStatement(_) => return None,
+        },
+        Definition::BuiltinFunction(name) => HoverSections {
+            code: vec![format!("fn {}", name.as_str())],
+            ..HoverSections::default()
+        },
+        Definition::BuiltinType(name)
+        | Definition::BuiltinTypeGenerator(name)
+        | Definition::BuiltinEnumerant(name)
+        | Definition::BuiltinDeclaration(name) => HoverSections {
+            code: vec![name.as_str().to_owned()],
+            ..HoverSections::default()
+        },
+    };
+    if !config.documentation {
+        sections.documentation = None;
+    }
+    Some(sections)
+}
+
+fn render_local(
+    db: &RootDatabase,
+    local: Local,
+) -> Option<HoverSections> {
+    let source = local.source(db)?;
+    let name = local.name(db);
+    let name = name.as_str();
+    let r#type = pretty_type(db, local.ty(db));
+    let parent = source.value.syntax().parent()?;
+
+    let signature = if let Some(parameter) = ast::Parameter::cast(parent.clone()) {
+        format!("{}{name}: {type}", render_attributes(&parameter, " "))
+    } else if ast::LetDeclaration::can_cast(parent.kind()) {
+        format!("let {name}: {type}")
+    } else if ast::ConstantDeclaration::can_cast(parent.kind()) {
+        format!("const {name}: {type}")
+    } else if let Some(variable) = ast::VariableDeclaration::cast(parent) {
+        format!("var{} {name}: {type}", render_template(&variable))
+    } else {
+        format!("{name}: {type}")
+    };
+
+    Some(HoverSections {
+        code: vec![signature],
+        ..HoverSections::default()
+    })
+}
+
+fn render_function(
+    db: &RootDatabase,
+    function: Function,
+) -> Option<HoverSections> {
+    let source = function.source(db)?;
+    let signature = pretty_fn_with_verbosity(db, &function.details(db), TypeVerbosity::Full);
+    Some(HoverSections {
+        code: vec![format!(
+            "{}{signature}",
+            render_attributes(&source.value, "\n")
+        )],
+        layout: None,
+        documentation: documentation(source.value.syntax()),
+    })
+}
+
+fn render_global_variable(
+    db: &RootDatabase,
+    variable: GlobalVariable,
+) -> Option<HoverSections> {
+    let source = variable.source(db)?;
+    Some(HoverSections {
+        code: vec![format!(
+            "{}var{} {}: {}",
+            render_attributes(&source.value, "\n"),
+            render_template(&source.value),
+            variable.name(db).as_str(),
+            pretty_type(db, variable.ty(db)),
+        )],
+        layout: None,
+        documentation: documentation(source.value.syntax()),
+    })
+}
+
+fn render_global_constant(
+    db: &RootDatabase,
+    constant: GlobalConstant,
+) -> Option<HoverSections> {
+    let source = constant.source(db)?;
+    Some(HoverSections {
+        code: vec![format!(
+            "{}const {}: {}",
+            render_attributes(&source.value, "\n"),
+            constant.name(db).as_str(),
+            pretty_type(db, constant.ty(db)),
+        )],
+        layout: None,
+        documentation: documentation(source.value.syntax()),
+    })
+}
+
+fn render_override(
+    db: &RootDatabase,
+    override_declaration: Override,
+) -> Option<HoverSections> {
+    let source = override_declaration.source(db)?;
+    Some(HoverSections {
+        code: vec![format!(
+            "{}override {}: {}",
+            render_attributes(&source.value, "\n"),
+            override_declaration.name(db).as_str(),
+            pretty_type(db, override_declaration.ty(db)),
+        )],
+        layout: None,
+        documentation: documentation(source.value.syntax()),
+    })
+}
+
+fn render_struct(
+    db: &RootDatabase,
+    r#struct: Struct,
+    config: &HoverConfig,
+) -> Option<HoverSections> {
+    let source = r#struct.source(db)?;
+    let fields = r#struct.fields(db);
+
+    let mut signature = format!(
+        "{}struct {} {{\n",
+        render_attributes(&source.value, "\n"),
+        r#struct.name(db).as_str()
+    );
+    let shown_fields = config.max_fields_count.unwrap_or(usize::MAX);
+    for field in fields.iter().take(shown_fields) {
+        format_to!(
+            signature,
+            "    {}: {},\n",
+            field.name(db).as_str(),
+            pretty_type(db, field.ty(db))
+        );
+    }
+    if fields.len() > shown_fields {
+        signature.push_str("    /* \u{2026} */\n");
+    }
+    signature.push('}');
+
+    let layout = config.memory_layout.and_then(|layout_config| {
+        let r#type = r#struct.ty(db);
+        if !r#type.kind(db).is_host_shareable(db) {
+            return None;
+        }
+        let address_space = r#struct.layout_address_space(db);
+        render_memory_layout(
+            layout_config,
+            r#type.size(address_space, db),
+            r#type.align(address_space, db),
+            None,
+        )
+    });
+
+    Some(HoverSections {
+        code: vec![signature],
+        layout,
+        documentation: documentation(source.value.syntax()),
+    })
+}
+
+fn render_field(
+    db: &RootDatabase,
+    field: Field,
+    config: &HoverConfig,
+) -> Option<HoverSections> {
+    let source = field.source(db)?;
+    let parent = field.parent_struct();
+
+    let layout = config.memory_layout.and_then(|layout_config| {
+        if !parent.ty(db).kind(db).is_host_shareable(db) {
+            return None;
+        }
+        let layout = field.layout(db, parent.layout_address_space(db))?;
+        render_memory_layout(
+            layout_config,
+            Some(layout.size),
+            Some(layout.align),
+            Some(layout.offset),
+        )
+    });
+
+    Some(HoverSections {
+        code: vec![
+            format!("struct {}", parent.name(db).as_str()),
+            format!(
+                "{}{}: {}",
+                render_attributes(&source.value, "\n"),
+                field.name(db).as_str(),
+                pretty_type(db, field.ty(db))
+            ),
+        ],
+        layout,
+        documentation: documentation(source.value.syntax()),
+    })
+}
+
+fn render_type_alias(
+    db: &RootDatabase,
+    type_alias: TypeAlias,
+) -> Option<HoverSections> {
+    let source = type_alias.source(db)?;
+    Some(HoverSections {
+        code: vec![format!(
+            "{}alias {} = {}",
+            render_attributes(&source.value, "\n"),
+            type_alias.name(db).as_str(),
+            pretty_type(db, type_alias.ty(db)),
+        )],
+        layout: None,
+        documentation: documentation(source.value.syntax()),
+    })
+}
+
+/// Pretty-prints a type the way it would be written in a declaration.
+///
+/// References are implicit in WGSL, so they are not shown.
+fn pretty_type(
+    db: &dyn hir::HirDatabase,
+    r#type: Type,
+) -> String {
+    pretty_type_with_verbosity(db, r#type.loaded(db), TypeVerbosity::Full)
+}
+
+fn render_attributes<Node>(
+    node: &Node,
+    separator: &str,
+) -> String
+where
+    Node: HasAttributes,
+{
+    node.attributes()
+        .into_iter()
+        .flatten()
+        .fold(String::new(), |mut accumulator, attribute| {
+            format_to_acc!(accumulator, "{}{separator}", attribute.syntax().text())
+        })
+}
+
+fn render_template(variable: &ast::VariableDeclaration) -> String {
+    variable
+        .template_parameters()
+        .map(|template| template.syntax().text().to_string())
+        .unwrap_or_default()
+}
+
+fn render_memory_layout(
+    config: MemoryLayoutHoverConfig,
+    size: Option<u32>,
+    align: Option<u32>,
+    offset: Option<u32>,
+) -> Option<String> {
+    let parts = [
+        ("size", size, config.size),
+        ("align", align, config.alignment),
+        ("offset", offset, config.offset),
+    ]
+    .into_iter()
+    .filter_map(|(label, value, kind)| {
+        Some(format!("{label} = {}", render_layout_value(value?, kind?)))
+    })
+    .join(", ");
+    (!parts.is_empty()).then_some(parts)
+}
+
+fn render_layout_value(
+    value: u32,
+    kind: MemoryLayoutHoverRenderKind,
+) -> String {
+    match kind {
+        MemoryLayoutHoverRenderKind::Decimal => format!("{value}"),
+        MemoryLayoutHoverRenderKind::Hexadecimal => format!("{value:#X}"),
+        MemoryLayoutHoverRenderKind::Both if value >= 10 => format!("{value} ({value:#X})"),
+        MemoryLayoutHoverRenderKind::Both => format!("{value}"),
+    }
+}
+
+/// Collects the line comments directly above a declaration, or above its attributes.
+fn documentation(node: &SyntaxNode) -> Option<String> {
+    let start = node
+        .prev_sibling()
+        .filter(|sibling| ast::AttributeList::can_cast(sibling.kind()))
+        .unwrap_or_else(|| node.clone());
+
+    let mut lines = Vec::new();
+    for element in start.siblings_with_tokens(Direction::Prev).skip(1) {
+        let NodeOrToken::Token(token) = element else {
+            break;
+        };
+        if token.kind() == SyntaxKind::LineEndingComment {
+            // A comment which trails code belongs to that code.
+            let starts_line = token.prev_token().is_none_or(|previous| {
+                previous.kind() == SyntaxKind::Blankspace && previous.text().contains('\n')
+            });
+            if !starts_line {
+                break;
+            }
+            let text = token.text().trim_start_matches('/').trim_end();
+            lines.push(text.strip_prefix(' ').unwrap_or(text).to_owned());
+        } else if token.kind() != SyntaxKind::Blankspace || token.text().matches('\n').count() > 1 {
+            break;
+        }
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use base_db::{FileRange, TextRange};
+    use expect_test::{Expect, expect};
+
+    use super::*;
+    use crate::fixture;
+
+    const HOVER_CONFIG: HoverConfig = HoverConfig {
+        links_in_hover: false,
+        memory_layout: Some(MemoryLayoutHoverConfig {
+            size: Some(MemoryLayoutHoverRenderKind::Both),
+            offset: Some(MemoryLayoutHoverRenderKind::Hexadecimal),
+            alignment: Some(MemoryLayoutHoverRenderKind::Hexadecimal),
+            padding: None,
+            niches: false,
+        }),
+        documentation: true,
+        keywords: true,
+        format: HoverDocFormat::Markdown,
+        max_fields_count: None,
+        max_enum_variants_count: None,
+        max_substitution_type_length: SubstitutionTypeLength::Unlimited,
+    };
+
+    #[track_caller]
+    fn check(
+        fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(fixture);
+        let hover = analysis
+            .hover(
+                &HOVER_CONFIG,
+                FileRange {
+                    file_id: position.file_id,
+                    range: TextRange::empty(position.offset),
+                },
+            )
+            .unwrap()
+            .unwrap();
+        expect.assert_eq(hover.info.markup.as_str());
+    }
+
+    #[track_caller]
+    fn check_hover_range(
+        fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, range) = fixture::range(fixture);
+        let hover = analysis.hover(&HOVER_CONFIG, range).unwrap().unwrap();
+        expect.assert_eq(hover.info.markup.as_str());
+    }
+
+    #[track_caller]
+    fn check_hover_no_result(fixture: &str) {
+        let (analysis, position) = fixture::position(fixture);
+        let hover = analysis
+            .hover(
+                &HOVER_CONFIG,
+                FileRange {
+                    file_id: position.file_id,
+                    range: TextRange::empty(position.offset),
+                },
+            )
+            .unwrap();
+        assert!(hover.is_none(), "hover not expected but found: {hover:?}");
+    }
+
+    #[test]
+    fn hover_local() {
+        check(
+            "
+fn main() {
+    let foo: vec3<f32> = vec3<f32>(1.0, 2.0, 3.0);
+    let bar = fo$0o;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                let foo: vec3<f32>
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_local_var_hides_reference() {
+        check(
+            "
+fn main() {
+    var foo: u32 = 1u;
+    fo$0o += 1u;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                var foo: u32
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_parameter_with_attributes() {
+        check(
+            "
+@fragment
+fn main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return posi$0tion;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                @builtin(position) position: vec4<f32>
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_function() {
+        check(
+            "
+// Mixes two colors.
+// Uses linear interpolation.
+@must_use
+fn mix_colors(first: vec3<f32>, second: vec3<f32>) -> vec3<f32> {
+    return mix(first, second, 0.5);
+}
+
+fn main() {
+    let color = mix_$0colors(vec3<f32>(), vec3<f32>());
+}
+",
+            &expect![[r#"
+                ```wgsl
+                @must_use
+                fn mix_colors(first: vec3<f32>, second: vec3<f32>) -> vec3<f32>
+                ```
+
+                ---
+
+                Mixes two colors.
+                Uses linear interpolation."#]],
+        );
+    }
+
+    #[test]
+    fn hover_function_declaration_name() {
+        check(
+            "
+@compute @workgroup_size(64)
+fn ma$0in() {}
+",
+            &expect![[r#"
+                ```wgsl
+                @compute
+                @workgroup_size(64)
+                fn main()
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_trailing_comment_is_not_documentation() {
+        check(
+            "
+const a: u32 = 1u; // not documentation
+fn fo$0o() {}
+",
+            &expect![[r#"
+                ```wgsl
+                fn foo()
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_global_variable() {
+        check(
+            "
+struct Camera {
+    view_projection: mat4x4<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+fn main() {
+    let matrix = cam$0era.view_projection;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                @group(0)
+                @binding(0)
+                var<uniform> camera: Camera
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_global_constant() {
+        check(
+            "
+const PI: f32 = 3.14159;
+fn main() {
+    let tau = 2.0 * P$0I;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                const PI: f32
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_override() {
+        check(
+            "
+@id(0) override block_size: u32 = 64u;
+fn main() {
+    let size = block_$0size;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                @id(0)
+                override block_size: u32
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_type_alias() {
+        check(
+            "
+alias Color = vec4<f32>;
+fn main() {
+    var color: Col$0or;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                alias Color = vec4<f32>
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_struct_with_layout() {
+        check(
+            "
+struct Li$0ght {
+    position: vec3<f32>,
+    intensity: f32,
+}
+",
+            &expect![[r#"
+                ```wgsl
+                struct Light {
+                    position: vec3<f32>,
+                    intensity: f32,
+                }
+                ```
+
+                ---
+
+                size = 16 (0x10), align = 0x10"#]],
+        );
+    }
+
+    #[test]
+    fn hover_struct_not_host_shareable_has_no_layout() {
+        check(
+            "
+struct Fl$0ags {
+    enabled: bool,
+}
+",
+            &expect![[r#"
+                ```wgsl
+                struct Flags {
+                    enabled: bool,
+                }
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_field_with_layout() {
+        check(
+            "
+struct Light {
+    position: vec3<f32>,
+    intensity: f32,
+}
+
+fn brightness(light: Light) -> f32 {
+    return light.inten$0sity;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                struct Light
+                ```
+                ```wgsl
+                intensity: f32
+                ```
+
+                ---
+
+                size = 4, align = 0x4, offset = 0xC"#]],
+        );
+    }
+
+    #[test]
+    fn hover_field_uses_uniform_layout() {
+        check(
+            "
+struct Uniforms {
+    scale: f32,
+    off$0set: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+",
+            &expect![[r#"
+                ```wgsl
+                struct Uniforms
+                ```
+                ```wgsl
+                offset: vec2<f32>
+                ```
+
+                ---
+
+                size = 8, align = 0x8, offset = 0x8"#]],
+        );
+    }
+
+    #[test]
+    fn hover_expression_range() {
+        check_hover_range(
+            "
+fn main() {
+    let a = 1u;
+    let b = $0a + 2u$0;
+}
+",
+            &expect![[r#"
+                ```wgsl
+                u32
+                ```"#]],
+        );
+    }
+
+    #[test]
+    fn hover_nothing_on_keyword() {
+        check_hover_no_result(
+            "
+f$0n main() {}
+",
+        );
+    }
 }
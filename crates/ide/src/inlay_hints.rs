@@ -4,12 +4,13 @@ use ast::Expression as AstExpression;
 use base_db::{EditionedFileId, FileId, FileRange, Intern as _, Lookup as _, TextRange};
 use hir::{AddressSpace, Field, HasSource as _, Semantics};
 use hir_def::{
-    InFile,
-    db::Location,
+    HasSource as _, InFile,
+    db::{Location, StructId},
     item_tree::{ItemTree, Name},
-    signature::FieldId,
+    signature::{FieldId, LocalFieldId},
 };
 use hir_ty::{
+    db::HirDatabase,
     function::FunctionDetails,
     layout::FieldLayout,
     lower::ResolvedCall,
@@ -29,20 +30,39 @@ pub struct InlayHintsConfig {
     pub enabled: bool,
     pub type_hints: bool,
     pub parameter_hints: bool,
+    pub conversion_hints: bool,
     pub struct_layout_hints: Option<StructLayoutHints>,
     pub type_verbosity: TypeVerbosity,
     pub fields_to_resolve: InlayFieldsToResolve,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum StructLayoutHints {
+    /// The offset of each field.
     Offset,
+    /// The offset, size and alignment of each field.
+    SizeAndAlignment,
+    /// The offset of each field, and the padding between the fields and at the end of the struct.
+    Padding,
+    /// The offset, size and alignment of each field, and the padding.
+    Full,
+}
+
+impl StructLayoutHints {
+    const fn shows_size_and_alignment(self) -> bool {
+        matches!(self, Self::SizeAndAlignment | Self::Full)
+    }
+
+    const fn shows_padding(self) -> bool {
+        matches!(self, Self::Padding | Self::Full)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InlayKind {
     Type,
     Parameter,
+    Conversion,
     StructLayout,
 }
 
@@ -371,58 +391,149 @@ fn get_struct_layout_hints(
 
     for r#struct in module_info.structs() {
         let r#struct = Location::new(file_id, r#struct).intern(semantics.db);
-        let fields = semantics.db.field_types(r#struct);
 
         // TODO check uniform_buffer_standard_layout extension here
         // https://github.com/wgsl-analyzer/wgsl-analyzer/issues/1358
-        let address_space = if semantics.db.struct_is_used_in_uniform(r#struct, file_id) {
+        let used_in_uniform = semantics.db.struct_is_used_in_uniform(r#struct, file_id);
+        let address_space = if used_in_uniform {
             AddressSpace::Uniform
         } else {
             AddressSpace::Storage
         };
+        let Some(layout) = StructLayout::of(semantics.db, r#struct, address_space) else {
+            continue;
+        };
 
-        hir_ty::layout::struct_member_layout(
-            &fields.0,
-            semantics.db,
-            address_space,
-            |field, _, field_layout| {
-                let FieldLayout { offset, .. } = field_layout;
-                let field = Field {
-                    id: FieldId { r#struct, field },
-                };
-
-                let source = field.source(semantics.db)?.value;
-
-                // this is only necessary, because the field syntax nodes include the whitespace to the next line...
-                let actual_last_token =
-                    iter::successors(source.syntax().last_token(), rowan::SyntaxToken::prev_token)
-                        .find(|token| !token.kind().is_trivia())?;
-                let range = TextRange::new(
-                    source.syntax().text_range().start(),
-                    actual_last_token.text_range().end(),
-                );
-
-                hints.push(InlayHint {
-                    range,
-                    position: InlayHintPosition::After,
-                    pad_left: false,
-                    pad_right: false,
-                    kind: InlayKind::StructLayout,
-                    label: match display_kind {
-                        StructLayoutHints::Offset => format!("{offset}").into(),
-                    },
-                    text_edit: None,
-                    resolve_parent: Some(range),
-                });
-
-                Some(())
-            },
-        );
+        // The range of the previous field, and the offset just past it.
+        let mut previous: Option<(TextRange, u32)> = None;
+        for &(field, field_layout) in &layout.fields {
+            let Some(range) = field_range(semantics, r#struct, field) else {
+                continue;
+            };
+            let FieldLayout {
+                offset,
+                align,
+                size,
+            } = field_layout;
+            if display_kind.shows_padding()
+                && let Some((previous_range, end)) = previous
+                && offset > end
+            {
+                hints.push(struct_layout_hint(
+                    previous_range,
+                    format!("{} bytes padding", offset - end),
+                ));
+            }
+            let label = if display_kind.shows_size_and_alignment() {
+                format!("offset {offset}, size {size}, align {align}")
+            } else {
+                format!("{offset}")
+            };
+            hints.push(struct_layout_hint(range, label));
+            previous = Some((range, offset + size));
+        }
+        if display_kind.shows_padding()
+            && let Some((last_range, end)) = previous
+            && layout.size > end
+        {
+            hints.push(struct_layout_hint(
+                last_range,
+                format!("{} bytes trailing padding", layout.size - end),
+            ));
+        }
+
+        if used_in_uniform
+            && semantics.db.struct_is_used_in_storage(r#struct, file_id)
+            && let Some(storage_layout) =
+                StructLayout::of(semantics.db, r#struct, AddressSpace::Storage)
+            && storage_layout != layout
+            && let Some(name) = r#struct
+                .lookup(semantics.db)
+                .source(semantics.db)
+                .value
+                .name()
+        {
+            hints.push(InlayHint {
+                label: InlayHintLabel::simple(
+                    "warning: uniform and storage layouts differ",
+                    Some(LazyProperty::Computed(InlayTooltip::String(format!(
+                        "size {} in uniform, {} in storage",
+                        layout.size, storage_layout.size
+                    )))),
+                    None,
+                ),
+                pad_left: true,
+                ..struct_layout_hint(name.syntax().text_range(), String::new())
+            });
+        }
     }
 
     Some(())
 }
 
+/// The layout of the fields of a struct in an address space.
+#[derive(PartialEq, Eq)]
+struct StructLayout {
+    fields: Vec<(LocalFieldId, FieldLayout)>,
+    size: u32,
+}
+
+impl StructLayout {
+    fn of(
+        db: &dyn HirDatabase,
+        r#struct: StructId,
+        address_space: AddressSpace,
+    ) -> Option<Self> {
+        let mut field_layouts = Vec::new();
+        let (_, size) = hir_ty::layout::struct_member_layout(
+            &db.field_types(r#struct).0,
+            db,
+            address_space,
+            |field, _, field_layout| field_layouts.push((field, field_layout)),
+        )?;
+        Some(Self {
+            fields: field_layouts,
+            size,
+        })
+    }
+}
+
+fn field_range(
+    semantics: &Semantics<'_>,
+    r#struct: StructId,
+    field: LocalFieldId,
+) -> Option<TextRange> {
+    let field = Field {
+        id: FieldId { r#struct, field },
+    };
+    let source = field.source(semantics.db)?.value;
+
+    // this is only necessary, because the field syntax nodes include the whitespace to the next line...
+    let actual_last_token =
+        iter::successors(source.syntax().last_token(), rowan::SyntaxToken::prev_token)
+            .find(|token| !token.kind().is_trivia())?;
+    Some(TextRange::new(
+        source.syntax().text_range().start(),
+        actual_last_token.text_range().end(),
+    ))
+}
+
+fn struct_layout_hint(
+    range: TextRange,
+    label: String,
+) -> InlayHint {
+    InlayHint {
+        range,
+        position: InlayHintPosition::After,
+        pad_left: false,
+        pad_right: false,
+        kind: InlayKind::StructLayout,
+        label: label.into(),
+        text_edit: None,
+        resolve_parent: Some(range),
+    }
+}
+
 fn get_hints(
     hints: &mut Vec<InlayHint>,
     file_id: EditionedFileId,
@@ -431,6 +542,9 @@ fn get_hints(
     node: &SyntaxNode,
 ) -> Option<()> {
     if let Some(expression) = AstExpression::cast(node.clone()) {
+        if config.conversion_hints {
+            conversion_hints(hints, file_id, semantics, node, &expression);
+        }
         match &expression {
             AstExpression::FunctionCall(function_call_expression) => {
                 if !config.parameter_hints {
@@ -527,6 +641,38 @@ fn declaration_type_hints(
     Some(())
 }
 
+/// Shows the concrete type which an abstract value is converted to, as if it was a call to the constructor of the type.
+fn conversion_hints(
+    hints: &mut Vec<InlayHint>,
+    file_id: EditionedFileId,
+    semantics: &Semantics<'_>,
+    node: &SyntaxNode,
+    expression: &AstExpression,
+) -> Option<()> {
+    let container = semantics.find_container(file_id, node)?;
+    let analyzed = semantics.analyze(container.as_def_with_body_id()?);
+    let expression_id = analyzed.expression_id(expression)?;
+    let r#type = analyzed.infer.conversion(expression_id)?;
+
+    let range = expression.syntax().text_range();
+    let label = format!(
+        "{}(",
+        pretty_type_with_verbosity(semantics.db, r#type, TypeVerbosity::Compact)
+    );
+    hints.push(InlayHint {
+        range,
+        position: InlayHintPosition::Before,
+        pad_left: false,
+        pad_right: false,
+        kind: InlayKind::Conversion,
+        label: label.into(),
+        text_edit: None,
+        resolve_parent: None,
+    });
+    hints.push(InlayHint::closing_paren_after(InlayKind::Conversion, range));
+    Some(())
+}
+
 fn function_hints(
     hints: &mut Vec<InlayHint>,
     file_id: EditionedFileId,
@@ -740,3 +886,304 @@ impl InlayFieldsToResolve {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use hir_ty::ty::pretty::TypeVerbosity;
+    use stdx::format_to;
+
+    use super::{InlayFieldsToResolve, InlayHintsConfig, StructLayoutHints};
+    use crate::fixture;
+
+    const DISABLED_CONFIG: InlayHintsConfig = InlayHintsConfig {
+        render_colons: true,
+        enabled: true,
+        type_hints: false,
+        parameter_hints: false,
+        conversion_hints: false,
+        struct_layout_hints: None,
+        type_verbosity: TypeVerbosity::Compact,
+        fields_to_resolve: InlayFieldsToResolve::empty(),
+    };
+
+    /// A struct whose uniform and storage layouts differ, for the struct layout hints.
+    const MATERIAL: &str = "
+struct Material {
+    roughness: f32,
+    weights: array<vec2<f32>, 2>,
+    color: vec3<f32>,
+}
+";
+
+    #[track_caller]
+    fn check(
+        config: &InlayHintsConfig,
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, file_id) = fixture::single_file_db(wa_fixture);
+        let mut actual = String::new();
+        for hint in analysis.inlay_hints(config, file_id, None).unwrap() {
+            format_to!(
+                actual,
+                "{:?} {:?} {}\n",
+                hint.range,
+                hint.position,
+                hint.label
+            );
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn conversion_hints() {
+        check(
+            &InlayHintsConfig {
+                conversion_hints: true,
+                ..DISABLED_CONFIG
+            },
+            "
+enable f16;
+fn scale(factor: f16) -> f16 { return factor; }
+fn main() {
+    let x = 1;
+    let y: f32 = 2;
+    let z = scale(1.5);
+}
+",
+            &expect![[r#"
+                84..85 Before i32(
+                84..85 After )
+                104..105 Before f32(
+                104..105 After )
+                125..128 Before f16(
+                125..128 After )
+            "#]],
+        );
+    }
+
+    #[test]
+    fn conversion_hints_for_binary_operands() {
+        check(
+            &InlayHintsConfig {
+                conversion_hints: true,
+                ..DISABLED_CONFIG
+            },
+            "
+fn main() {
+    let v = vec3<f32>();
+    let w = v * 2;
+    let x: u32 = 4u;
+    let y = 1 + x;
+}
+",
+            &expect![[r#"
+                53..54 Before f32(
+                53..54 After )
+                89..90 Before u32(
+                89..90 After )
+            "#]],
+        );
+    }
+
+    #[test]
+    fn conversion_hints_for_builtin_arguments() {
+        check(
+            &InlayHintsConfig {
+                conversion_hints: true,
+                ..DISABLED_CONFIG
+            },
+            "
+fn main() {
+    let x: f32 = 0.5;
+    let a = max(x, 1.0);
+    let b = clamp(x, 0, 1.0);
+}
+",
+            &expect![[r#"
+                29..32 Before f32(
+                29..32 After )
+                53..56 Before f32(
+                53..56 After )
+                80..81 Before f32(
+                80..81 After )
+                83..86 Before f32(
+                83..86 After )
+            "#]],
+        );
+    }
+
+    #[test]
+    fn conversion_hints_for_constructor_arguments() {
+        check(
+            &InlayHintsConfig {
+                conversion_hints: true,
+                ..DISABLED_CONFIG
+            },
+            "
+struct Light {
+    intensity: f32,
+}
+fn main() {
+    let light = Light(1);
+    let weights = array<f32, 2>(1, 2.0);
+}
+",
+            &expect![[r#"
+                71..72 Before f32(
+                71..72 After )
+                107..108 Before f32(
+                107..108 After )
+                110..113 Before f32(
+                110..113 After )
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_conversion_hints_without_concrete_types() {
+        check(
+            &InlayHintsConfig {
+                conversion_hints: true,
+                ..DISABLED_CONFIG
+            },
+            "
+const scale = 1 + 2.0;
+fn main() {
+    let a = 1u + 2u;
+    const b = max(1, 2.5);
+}
+",
+            &expect![[""]],
+        );
+    }
+
+    #[test]
+    fn struct_layout_offset_hints() {
+        check(
+            &InlayHintsConfig {
+                struct_layout_hints: Some(StructLayoutHints::Offset),
+                ..DISABLED_CONFIG
+            },
+            &format!("{MATERIAL}@group(0) @binding(1) var<storage> stored: Material;\n"),
+            &expect![[r#"
+                22..36 After 0
+                42..70 After 8
+                76..92 After 32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_layout_size_and_alignment_hints() {
+        check(
+            &InlayHintsConfig {
+                struct_layout_hints: Some(StructLayoutHints::SizeAndAlignment),
+                ..DISABLED_CONFIG
+            },
+            &format!("{MATERIAL}@group(0) @binding(1) var<storage> stored: Material;\n"),
+            &expect![[r#"
+                22..36 After offset 0, size 4, align 4
+                42..70 After offset 8, size 16, align 8
+                76..92 After offset 32, size 12, align 16
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_layout_full_hints() {
+        check(
+            &InlayHintsConfig {
+                struct_layout_hints: Some(StructLayoutHints::Full),
+                ..DISABLED_CONFIG
+            },
+            &format!("{MATERIAL}@group(0) @binding(1) var<storage> stored: Material;\n"),
+            &expect![[r#"
+                22..36 After offset 0, size 4, align 4
+                22..36 After 4 bytes padding
+                42..70 After offset 8, size 16, align 8
+                42..70 After 8 bytes padding
+                76..92 After offset 32, size 12, align 16
+                76..92 After 4 bytes trailing padding
+            "#]],
+        );
+    }
+
+    /// Structs which are not used in a buffer show their storage layout, without any warning.
+    #[test]
+    fn struct_layout_hints_default_to_storage_layout() {
+        check(
+            &InlayHintsConfig {
+                struct_layout_hints: Some(StructLayoutHints::Offset),
+                ..DISABLED_CONFIG
+            },
+            MATERIAL,
+            &expect![[r#"
+                22..36 After 0
+                42..70 After 8
+                76..92 After 32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_layout_padding_hints() {
+        check(
+            &InlayHintsConfig {
+                struct_layout_hints: Some(StructLayoutHints::Padding),
+                ..DISABLED_CONFIG
+            },
+            &format!("{MATERIAL}@group(0) @binding(1) var<storage> stored: Material;\n"),
+            &expect![[r#"
+                22..36 After 0
+                22..36 After 4 bytes padding
+                42..70 After 8
+                42..70 After 8 bytes padding
+                76..92 After 32
+                76..92 After 4 bytes trailing padding
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_layout_hints_warn_about_differing_layouts() {
+        check(
+            &InlayHintsConfig {
+                struct_layout_hints: Some(StructLayoutHints::Full),
+                ..DISABLED_CONFIG
+            },
+            &format!(
+                "{MATERIAL}@group(0) @binding(0) var<uniform> material: Material;
+@group(0) @binding(1) var<storage> stored: Material;
+"
+            ),
+            &expect![[r#"
+                22..36 After offset 0, size 4, align 4
+                22..36 After 12 bytes padding
+                42..70 After offset 16, size 32, align 16
+                76..92 After offset 48, size 12, align 16
+                76..92 After 4 bytes trailing padding
+                7..15 After warning: uniform and storage layouts differ
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_layout_hints_use_uniform_layout_without_warning() {
+        check(
+            &InlayHintsConfig {
+                struct_layout_hints: Some(StructLayoutHints::Full),
+                ..DISABLED_CONFIG
+            },
+            &format!("{MATERIAL}@group(0) @binding(0) var<uniform> material: Material;\n"),
+            &expect![[r#"
+                22..36 After offset 0, size 4, align 4
+                22..36 After 12 bytes padding
+                42..70 After offset 16, size 32, align 16
+                76..92 After offset 48, size 12, align 16
+                76..92 After 4 bytes trailing padding
+            "#]],
+        );
+    }
+}
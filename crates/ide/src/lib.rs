@@ -3,16 +3,28 @@
 #[cfg(test)]
 mod fixture;
 
+mod annotations;
+mod call_hierarchy;
+mod document_links;
+mod extend_selection;
+mod file_structure;
 mod folding_ranges;
 mod formatting;
+mod goto_declaration;
 mod goto_definition;
+mod goto_type_definition;
 mod helpers;
+mod highlight_related;
 mod hover;
 pub mod inlay_hints;
 mod markup;
+mod move_module;
 mod navigation_target;
+mod references;
+mod rename;
 pub mod signature_help;
 mod status;
+mod syntax_highlighting;
 mod typing;
 mod view_module_graph;
 mod view_package_graph;
@@ -36,12 +48,13 @@ use vfs::{FileId, VfsPath};
 
 use crate::signature_help::SignatureHelp;
 pub use crate::{
-    // annotations::{Annotation, AnnotationConfig, AnnotationKind, AnnotationLocation},
-    // call_hierarchy::{CallHierarchyConfig, CallItem},
+    annotations::{Annotation, AnnotationKind, Resource},
+    call_hierarchy::CallItem,
+    document_links::DocumentLink,
     // expand_macro::ExpandedMacro,
-    // file_structure::{StructureNode, StructureNodeKind},
+    file_structure::{StructureNode, StructureNodeKind},
     folding_ranges::{Fold, FoldKind},
-    // highlight_related::{HighlightRelatedConfig, HighlightedRange},
+    highlight_related::HighlightedRange,
     hover::{
         HoverAction, HoverConfig, HoverDocFormat, HoverGotoTypeData, HoverResult,
         MemoryLayoutHoverConfig, MemoryLayoutHoverRenderKind, SubstitutionTypeLength,
@@ -68,17 +81,24 @@ pub use crate::{
     // },
     // move_item::Direction,
     navigation_target::NavigationTarget,
-    // references::ReferenceSearchResult,
-    // rename::RenameError,
+    references::{Declaration, ReferenceSearchResult},
+    rename::RenameError,
     // runnables::{Runnable, RunnableKind, TestId, UpdateTest},
     // signature_help::SignatureHelp,
     // static_index::{
     //     StaticIndex, StaticIndexedFile, TokenId, TokenStaticData, VendoredLibrariesConfig,
     // },
-    // syntax_highlighting::{
-    //     HighlightConfig, HlRange,
-    //     tags::{Highlight, HlMod, HlMods, HlOperator, HlPunct, HlTag},
-    // },
+    syntax_highlighting::{
+        // HighlightConfig,
+        HlRange,
+        tags::{
+            Highlight,
+            HlMod,
+            HlMods,
+            // HlOperator, HlPunct,
+            HlTag,
+        },
+    },
     // test_explorer::{TestItem, TestItemKind},
 };
 
@@ -86,7 +106,14 @@ pub type Cancellable<T> = Result<T, Cancelled>;
 
 /// `base_db` is normally also needed in places where `ide_db` is used, so this re-export is for convenience.
 pub use base_db;
+pub use ide_assists::{
+    Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, GroupLabel, SingleResolve,
+};
 pub use ide_db::{
+    // FileId,
+    // FilePosition,
+    // FileRange,
+    RootDatabase,
     // Severity,
     // SymbolKind,
     // assists::ExprFillDefaultMode,
@@ -104,15 +131,9 @@ pub use ide_db::{
     //     LineIndex
     // },
     // prime_caches::ParallelPrimeCachesProgress,
-    // search::{
-    //     ReferenceCategory,
-    //     SearchScope
-    // },,
-    // FileId,
-    // FilePosition,
-    // FileRange,
-    RootDatabase,
-    // symbol_index::Query,
+    search::{ReferenceCategory, SearchScope},
+    source_change::SourceChange,
+    symbol_index::{FileSymbol, FileSymbolKind, Query},
     text_edit::{
         // Indel,
         TextEdit,
@@ -348,6 +369,72 @@ impl Analysis {
         self.with_db(|db| inlay_hints::inlay_hints(db, file_id, range, config))
     }
 
+    /// Returns a tree representation of symbols in the file. Useful to draw a
+    /// file outline.
+    pub fn file_structure(
+        &self,
+        file_id: FileId,
+    ) -> Cancellable<Vec<StructureNode>> {
+        self.with_db(|db| file_structure::file_structure(db, file_id))
+    }
+
+    /// Returns the links from the segments of the import paths in the file.
+    pub fn document_links(
+        &self,
+        file_id: FileId,
+    ) -> Cancellable<Vec<DocumentLink>> {
+        self.with_db(|db| document_links::document_links(db, file_id))
+    }
+
+    /// Fuzzy searches the symbols declared in the modules of the workspace.
+    pub fn symbol_search(
+        &self,
+        query: Query,
+    ) -> Cancellable<Vec<FileSymbol>> {
+        self.with_db(|db| ide_db::symbol_index::world_symbols(db, &query))
+    }
+
+    /// Computes syntax highlighting for the given file.
+    pub fn highlight(
+        &self,
+        file_id: FileId,
+    ) -> Cancellable<Vec<HlRange>> {
+        self.with_db(|db| syntax_highlighting::highlight(db, file_id))
+    }
+
+    /// Returns an edit which should be applied when opening a new line, fixing
+    /// up minor stuff like continuing the comment.
+    /// The edit will be a snippet (with `$0`).
+    pub fn on_enter(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<TextEdit>> {
+        self.with_db(|db| typing::on_enter(db, position))
+    }
+
+    /// Returns an edit which should be applied after a character was typed.
+    ///
+    /// This is useful for some on-the-fly fixups, like indenting a `}`.
+    pub fn on_char_typed(
+        &self,
+        position: FilePosition,
+        char_typed: char,
+    ) -> Cancellable<Option<SourceChange>> {
+        // Fast path to not even parse the file.
+        if !typing::TRIGGER_CHARS.contains(&char_typed) {
+            return Ok(None);
+        }
+        self.with_db(|db| typing::on_char_typed(db, position, char_typed))
+    }
+
+    /// Selects the next syntactic construct encompassing the range.
+    pub fn extend_selection(
+        &self,
+        frange: FileRange,
+    ) -> Cancellable<TextRange> {
+        self.with_db(|db| extend_selection::extend_selection(db, frange))
+    }
+
     /// Returns the set of folding ranges.
     pub fn folding_ranges(
         &self,
@@ -363,9 +450,10 @@ impl Analysis {
     pub fn diagnostics(
         &self,
         config: &DiagnosticsConfig,
+        resolve: &AssistResolveStrategy,
         file_id: FileId,
     ) -> Cancellable<Vec<Diagnostic>> {
-        self.with_db(|db| ide_diagnostics::diagnostics(db, config, file_id))
+        self.with_db(|db| ide_diagnostics::diagnostics(db, config, resolve, file_id))
     }
 
     pub fn goto_definition(
@@ -375,6 +463,146 @@ impl Analysis {
         self.with_db(|db| goto_definition::goto_definition(db, file_position))
     }
 
+    /// Returns the declaration of the identifier at `position`, stopping at WESL `import` items.
+    pub fn goto_declaration(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<RangeInfo<NavigationTarget>>> {
+        self.with_db(|db| goto_declaration::goto_declaration(db, position))
+    }
+
+    /// Returns the type definition of the expression at `position`.
+    pub fn goto_type_definition(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<RangeInfo<NavigationTarget>>> {
+        self.with_db(|db| goto_type_definition::goto_type_definition(db, position))
+    }
+
+    /// Computes assists (aka code actions aka intentions) for the given
+    /// position, preceded by the fixes of the diagnostics in the range.
+    ///
+    /// Only the assists which `resolve` asks for have their source change computed.
+    pub fn assists_with_fixes(
+        &self,
+        assist_config: &AssistConfig,
+        diagnostics_config: &DiagnosticsConfig,
+        resolve: AssistResolveStrategy,
+        frange: FileRange,
+    ) -> Cancellable<Vec<Assist>> {
+        let include_fixes = assist_config.allowed.as_ref().is_none_or(|allowed| {
+            allowed
+                .iter()
+                .any(|kind| kind.contains(AssistKind::QuickFix))
+        });
+
+        // Only the native diagnostics have fixes, so naga and tint need not run.
+        let diagnostics_config = DiagnosticsConfig {
+            naga_parsing_enabled: false,
+            naga_validation_enabled: false,
+            tint_enabled: false,
+            ..diagnostics_config.clone()
+        };
+
+        self.with_db(|db| {
+            let mut result = if diagnostics_config.enabled && include_fixes {
+                ide_diagnostics::diagnostics(db, &diagnostics_config, &resolve, frange.file_id)
+                    .into_iter()
+                    .filter_map(|diagnostic| diagnostic.fixes)
+                    .flatten()
+                    .filter(|fix| fix.target.intersect(frange.range).is_some())
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            result.extend(ide_assists::assists(db, assist_config, resolve, frange));
+            result
+        })
+    }
+
+    /// Computes the code lenses of the file.
+    pub fn annotations(
+        &self,
+        file_id: FileId,
+    ) -> Cancellable<Vec<Annotation>> {
+        self.with_db(|db| annotations::annotations(db, file_id))
+    }
+
+    /// Computes the data of a code lens which is only filled in on demand.
+    pub fn resolve_annotation(
+        &self,
+        annotation: Annotation,
+    ) -> Cancellable<Annotation> {
+        self.with_db(|db| annotations::resolve_annotation(db, annotation))
+    }
+
+    /// Computes the function at `position` for the call hierarchy.
+    pub fn call_hierarchy(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<RangeInfo<Vec<CallItem>>>> {
+        self.with_db(|db| call_hierarchy::call_hierarchy(db, position))
+    }
+
+    /// Computes the functions which call the function at `position`.
+    pub fn incoming_calls(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<Vec<CallItem>>> {
+        self.with_db(|db| call_hierarchy::incoming_calls(db, position))
+    }
+
+    /// Computes the functions which are called by the function at `position`.
+    pub fn outgoing_calls(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<Vec<CallItem>>> {
+        self.with_db(|db| call_hierarchy::outgoing_calls(db, position))
+    }
+
+    /// Finds all usages of the definition at the given position.
+    pub fn find_all_refs(
+        &self,
+        position: FilePosition,
+        search_scope: Option<&SearchScope>,
+    ) -> Cancellable<Option<ReferenceSearchResult>> {
+        self.with_db(|db| references::find_all_refs(db, position, search_scope))
+    }
+
+    /// Computes the ranges to highlight for the token at the given position.
+    pub fn highlight_related(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<Vec<HighlightedRange>>> {
+        self.with_db(|db| highlight_related::highlight_related(db, position))
+    }
+
+    /// Returns the range of the identifier that would be renamed at the given position.
+    pub fn prepare_rename(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Result<RangeInfo<()>, RenameError>> {
+        self.with_db(|db| rename::prepare_rename(db, position))
+    }
+
+    /// Renames the item at the given position, along with all of its usages.
+    pub fn rename(
+        &self,
+        position: FilePosition,
+        new_name: &str,
+    ) -> Cancellable<Result<SourceChange, RenameError>> {
+        self.with_db(|db| rename::rename(db, position, new_name))
+    }
+
+    /// Computes the changes to the imports of the workspace which keep them valid
+    /// when the given files move to their new paths.
+    pub fn will_rename_files(
+        &self,
+        renames: &[(FileId, VfsPath)],
+    ) -> Cancellable<SourceChange> {
+        self.with_db(|db| move_module::will_rename_files(db, renames))
+    }
+
     /// Computes completions at the given position.
     pub fn completions(
         &self,
@@ -386,12 +614,21 @@ impl Analysis {
         self.with_db(|db| ide_completion::completions(db, config, position, trigger_character))
     }
 
+    /// Formats the whole file, returning the formatted syntax tree.
     pub fn format(
         &self,
         file_id: FileId,
-        range: Option<TextRange>,
-    ) -> Cancellable<Option<SyntaxNode>> {
-        self.with_db(|db| formatting::format(db, file_id, range))
+    ) -> Cancellable<SyntaxNode> {
+        self.with_db(|db| formatting::format(db, file_id))
+    }
+
+    /// Formats the statements or items intersecting the range, returning the changed range of
+    /// the file and its formatted replacement.
+    pub fn format_range(
+        &self,
+        frange: FileRange,
+    ) -> Cancellable<Option<(TextRange, String)>> {
+        self.with_db(|db| formatting::format_range(db, frange))
     }
 
     /// Returns a short text describing element at position.
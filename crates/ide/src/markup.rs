@@ -41,7 +41,7 @@ impl Markup {
     where
         Displayable: fmt::Display,
     {
-        format!("```rust\n{contents}\n```").into()
+        format!("```wgsl\n{contents}\n```").into()
     }
 
     pub fn fenced_block_text<Displayable>(contents: Displayable) -> Self
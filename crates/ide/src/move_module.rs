@@ -0,0 +1,511 @@
+//! Rewrites WESL `import` paths when the files of modules are renamed or moved.
+
+use base_db::{EditionedFileId, FileId, Package, TextRange, VfsPath, all_packages, file_package};
+use hir_def::{item_tree::Name, mod_path::AbsoluteModPath, name_resolution::ModulesMap};
+use ide_db::{RootDatabase, source_change::SourceChange, text_edit::TextEdit};
+use itertools::Itertools as _;
+use rustc_hash::FxHashSet;
+use syntax::{AstNode as _, SyntaxKind, ast};
+
+use crate::document_links::keywords;
+
+/// A module which is about to move to a different path in its package.
+struct Move {
+    package: Package,
+    from: Vec<Name>,
+    to: Vec<Name>,
+}
+
+/// The module which contains an import statement.
+struct Importer {
+    package: Package,
+    old_path: Vec<Name>,
+    new_path: Vec<Name>,
+}
+
+/// How the path of an import statement starts.
+#[derive(Clone, Copy)]
+enum Start {
+    /// The first segment names a dependency.
+    Dependency,
+    /// `package::`.
+    Package,
+    /// `super::`, repeated the given number of times.
+    Super(usize),
+}
+
+/// A path of an import statement, with the `import` prefix and collections flattened out.
+struct Leaf {
+    segments: Vec<Name>,
+    alias: Option<ast::Name>,
+}
+
+// Feature: Update Imports on File Move
+//
+// When a `.wesl` or `.wgsl` file, or a folder containing them, is renamed or moved,
+// rewrites every `import` which refers to a moved module, in all packages of the workspace.
+// `super::` paths are recomputed from the new locations of both the importing and the imported module.
+pub(crate) fn will_rename_files(
+    db: &RootDatabase,
+    renames: &[(FileId, VfsPath)],
+) -> SourceChange {
+    let _p = tracing::info_span!("will_rename_files").entered();
+    let moves = renames
+        .iter()
+        .filter_map(|(file_id, new_path)| module_move(db, *file_id, new_path))
+        .collect_vec();
+
+    let mut source_change = SourceChange::default();
+    if moves.is_empty() {
+        return source_change;
+    }
+    let mut seen = FxHashSet::default();
+    for &package in &*all_packages(db) {
+        for (module_path, module) in &ModulesMap::of(db, package).modules {
+            let Some(file_id) = module.file else {
+                continue;
+            };
+            if !seen.insert(file_id) {
+                continue;
+            }
+            let old_path = module_path.segments().to_vec();
+            let importer = Importer {
+                package,
+                new_path: moved_path(&moves, package, &old_path)
+                    .unwrap_or_else(|| old_path.clone()),
+                old_path,
+            };
+            let edit = rewrite_imports(db, &moves, &importer, file_id);
+            if !edit.is_empty() {
+                source_change.insert_source_edit(file_id.file_id(db), edit);
+            }
+        }
+    }
+    source_change
+}
+
+fn module_move(
+    db: &RootDatabase,
+    file_id: FileId,
+    new_path: &VfsPath,
+) -> Option<Move> {
+    let (_, extension) = new_path.name_and_extension()?;
+    if !matches!(extension, Some("wesl" | "wgsl")) {
+        return None;
+    }
+    let package = file_package(db, file_id)?;
+    let from = AbsoluteModPath::for_file(db, package, EditionedFileId::from_file(db, file_id))?;
+    let to = AbsoluteModPath::for_path(&package.data(db).root, new_path)?;
+    (from != to).then(|| Move {
+        package,
+        from: from.segments().to_vec(),
+        to: to.segments().to_vec(),
+    })
+}
+
+/// Returns the new path of the module at `path`, if it moves.
+fn moved_path(
+    moves: &[Move],
+    package: Package,
+    path: &[Name],
+) -> Option<Vec<Name>> {
+    moves
+        .iter()
+        .find(|module_move| module_move.package == package && module_move.from == path)
+        .map(|module_move| module_move.to.clone())
+}
+
+/// Returns the new path of an imported module or item, if it moves.
+fn moved_target(
+    moves: &[Move],
+    package: Package,
+    target: &[Name],
+) -> Option<Vec<Name>> {
+    if let Some(path) = moved_path(moves, package, target) {
+        return Some(path);
+    }
+    let (item, module) = target.split_last()?;
+    let mut path = moved_path(moves, package, module)?;
+    path.push(item.clone());
+    Some(path)
+}
+
+fn rewrite_imports(
+    db: &RootDatabase,
+    moves: &[Move],
+    importer: &Importer,
+    file_id: EditionedFileId,
+) -> TextEdit {
+    let mut builder = TextEdit::builder();
+    for import in file_id
+        .parse(db)
+        .tree()
+        .syntax()
+        .descendants()
+        .filter_map(ast::ImportStatement::cast)
+    {
+        if let Some((range, text)) = rewrite_import(db, moves, importer, &import) {
+            builder.replace(range, text);
+        }
+    }
+    builder.finish()
+}
+
+/// Returns the replacement for the part of `import` which changes, if any.
+fn rewrite_import(
+    db: &RootDatabase,
+    moves: &[Move],
+    importer: &Importer,
+    import: &ast::ImportStatement,
+) -> Option<(TextRange, String)> {
+    let tree = import.item()?;
+    let (start, start_offset, keywords_end) = match import.relative() {
+        None => (Start::Dependency, tree.syntax().text_range().start(), None),
+        Some(ast::ImportRelative::ImportPackageRelative(relative)) => (
+            Start::Package,
+            relative.syntax().text_range().start(),
+            keywords(relative.syntax(), SyntaxKind::Package).last(),
+        ),
+        Some(ast::ImportRelative::ImportSuperRelative(relative)) => (
+            Start::Super(keywords(relative.syntax(), SyntaxKind::Super).count()),
+            relative.syntax().text_range().start(),
+            keywords(relative.syntax(), SyntaxKind::Super).last(),
+        ),
+    };
+
+    // The segments before the first collection, which all leaves share.
+    let mut head = Vec::new();
+    let mut current = tree.clone();
+    while let ast::ImportTree::ImportPath(path) = current.clone() {
+        head.push(path.name()?);
+        current = path.item()?;
+    }
+    let head_end = head
+        .last()
+        .map(|name| name.syntax().text_range().end())
+        .or_else(|| Some(keywords_end?.text_range().end()));
+    let head = head.into_iter().map(Name::from).collect_vec();
+    let mut leaves = Vec::new();
+    flatten(&current, &head, &mut leaves)?;
+
+    let mut changed = matches!(start, Start::Super(_)) && importer.old_path != importer.new_path;
+    let mut new_targets = Vec::with_capacity(leaves.len());
+    for leaf in &leaves {
+        // A leaf which does not resolve is kept as is.
+        let new_target = target(db, importer, start, &leaf.segments).map(|(package, target)| {
+            let new_target = moved_target(moves, package, &target);
+            changed |= new_target.is_some();
+            new_target.unwrap_or(target)
+        });
+        new_targets.push(new_target);
+    }
+    if !changed {
+        return None;
+    }
+
+    let statement_start = import.syntax().text_range().start();
+    let statement_text = import.syntax().text().to_string();
+    let existing = |range: TextRange| &statement_text[range - statement_start];
+
+    // Only rewrite the shared segments, if all leaves stay together.
+    if let Some(head_end) = head_end
+        && let Some(new_head) = new_head(&head, &leaves, &new_targets)
+    {
+        let range = TextRange::new(start_offset, head_end);
+        let text = render(start, head.first(), &importer.new_path, new_head);
+        return (text != existing(range)).then_some((range, text));
+    }
+
+    // Otherwise, split the import into one statement per leaf.
+    let prefix = existing(TextRange::new(statement_start, start_offset));
+    let start_keywords = keywords_end.map(|keywords_end| {
+        existing(TextRange::new(
+            start_offset,
+            keywords_end.text_range().end(),
+        ))
+    });
+    let text = leaves
+        .iter()
+        .zip(&new_targets)
+        .map(|(leaf, new_target)| {
+            let mut text = match new_target {
+                Some(new_target) => {
+                    render(start, leaf.segments.first(), &importer.new_path, new_target)
+                },
+                None => start_keywords
+                    .into_iter()
+                    .chain(leaf.segments.iter().map(Name::as_str))
+                    .join("::"),
+            };
+            if let Some(alias) = &leaf.alias {
+                text.push_str(" as ");
+                text.push_str(&alias.syntax().text().to_string());
+            }
+            text
+        })
+        .join(&format!(";\n{prefix}"));
+    let range = TextRange::new(start_offset, tree.syntax().text_range().end());
+    Some((range, text))
+}
+
+/// Returns the new path of the shared segments of an import, if every leaf resolves and keeps its segments after them.
+///
+/// For a dependency, the path does not include the name of the dependency.
+fn new_head<'targets>(
+    head: &[Name],
+    leaves: &[Leaf],
+    new_targets: &'targets [Option<Vec<Name>>],
+) -> Option<&'targets [Name]> {
+    let mut new_heads = leaves.iter().zip(new_targets).map(|(leaf, new_target)| {
+        let suffix = &leaf.segments[head.len()..];
+        let new_target = new_target.as_ref()?;
+        new_target
+            .ends_with(suffix)
+            .then(|| &new_target[..new_target.len() - suffix.len()])
+    });
+    let first = new_heads.next()??;
+    new_heads.all(|other| other == Some(first)).then_some(first)
+}
+
+/// Collects the paths of `tree`, each starting with `prefix`.
+fn flatten(
+    tree: &ast::ImportTree,
+    prefix: &[Name],
+    leaves: &mut Vec<Leaf>,
+) -> Option<()> {
+    match tree {
+        ast::ImportTree::ImportPath(path) => {
+            let mut prefix = prefix.to_vec();
+            prefix.push(Name::from(path.name()?));
+            flatten(&path.item()?, &prefix, leaves)?;
+        },
+        ast::ImportTree::ImportItem(item) => {
+            let mut segments = prefix.to_vec();
+            segments.push(Name::from(item.name()?));
+            leaves.push(Leaf {
+                segments,
+                alias: item.alias(),
+            });
+        },
+        ast::ImportTree::ImportCollection(collection) => {
+            for item in collection.items() {
+                flatten(&item, prefix, leaves)?;
+            }
+        },
+    }
+    Some(())
+}
+
+/// Returns the package and absolute path which `segments` refer to, before the move.
+fn target(
+    db: &RootDatabase,
+    importer: &Importer,
+    start: Start,
+    segments: &[Name],
+) -> Option<(Package, Vec<Name>)> {
+    match start {
+        Start::Dependency => {
+            let (dependency, rest) = segments.split_first()?;
+            let package = importer
+                .package
+                .data(db)
+                .dependencies
+                .iter()
+                .find(|candidate| candidate.name.as_str() == dependency.as_str())?
+                .package(db);
+            Some((package, rest.to_vec()))
+        },
+        Start::Package => Some((importer.package, segments.to_vec())),
+        Start::Super(levels) => {
+            let base = importer.old_path.len().checked_sub(levels)?;
+            let mut path = importer.old_path[..base].to_vec();
+            path.extend_from_slice(segments);
+            Some((importer.package, path))
+        },
+    }
+}
+
+/// Renders the import path of `target` from the module at `importer`, keeping the kind of `start`.
+fn render(
+    start: Start,
+    dependency: Option<&Name>,
+    importer: &[Name],
+    target: &[Name],
+) -> String {
+    let (keywords, rest): (Vec<&str>, &[Name]) = match (start, dependency) {
+        (Start::Dependency, Some(dependency)) => (vec![dependency.as_str()], target),
+        (Start::Super(_), _) if !importer.is_empty() => {
+            // `super` must go up at least one level.
+            let common = importer
+                .iter()
+                .zip(target)
+                .take_while(|(left, right)| left == right)
+                .count()
+                .min(importer.len() - 1);
+            (vec!["super"; importer.len() - common], &target[common..])
+        },
+        (Start::Dependency | Start::Package | Start::Super(_), _) => (vec!["package"], target),
+    };
+    keywords
+        .into_iter()
+        .chain(rest.iter().map(Name::as_str))
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use stdx::format_to;
+    use test_fixture::ChangeFixture;
+    use vfs::VfsPath;
+
+    use crate::AnalysisHost;
+
+    /// Moves the files at the given indices of the fixture, and lists the edited files.
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        moves: &[(usize, &str)],
+        expect: &Expect,
+    ) {
+        let mut host = AnalysisHost::default();
+        let fixture = ChangeFixture::parse(wa_fixture);
+        host.apply_change(fixture.change);
+        let analysis = host.analysis();
+        let renames: Vec<_> = moves
+            .iter()
+            .map(|&(index, path)| {
+                (
+                    fixture.files[index],
+                    VfsPath::new_virtual_path(path.to_owned()),
+                )
+            })
+            .collect();
+        let source_change = analysis.will_rename_files(&renames).unwrap();
+
+        let mut actual = String::new();
+        for &file_id in &fixture.files {
+            let Some(edit) = source_change.get_source_edit(file_id) else {
+                continue;
+            };
+            let mut text = analysis.file_text(file_id).unwrap().to_string();
+            edit.apply(&mut text);
+            format_to!(actual, "{file_id:?}\n{}\n", text.trim_end());
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn moves_imported_module() {
+        check(
+            "
+//- /main.wesl package:app root:/ edition:2026_pre
+import package::lighting::brdf::ggx;
+import package::lighting::{shade, brdf::{ggx as specular, lambert}};
+
+//- /lighting.wesl
+fn shade() -> f32 { return 1.0; }
+
+//- /lighting/brdf.wesl
+fn ggx() -> f32 { return 1.0; }
+fn lambert() -> f32 { return 1.0; }
+
+//- /lighting/sky.wesl
+import super::brdf::lambert;
+import super::shade;
+",
+            &[(2, "/shading/brdf.wesl")],
+            &expect![[r#"
+                FileId(1)
+                import package::shading::brdf::ggx;
+                import package::lighting::shade;
+                import package::shading::brdf::ggx as specular;
+                import package::shading::brdf::lambert;
+                FileId(4)
+                import super::super::shading::brdf::lambert;
+                import super::shade;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn moves_importing_module() {
+        check(
+            "
+//- /lighting/sky.wesl package:app root:/ edition:2026_pre
+import super::brdf::lambert;
+import package::lighting::shade;
+
+//- /lighting.wesl
+fn shade() -> f32 { return 1.0; }
+
+//- /lighting/brdf.wesl
+fn lambert() -> f32 { return 1.0; }
+",
+            &[(0, "/sky.wesl")],
+            &expect![[r#"
+                FileId(1)
+                import super::lighting::brdf::lambert;
+                import package::lighting::shade;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn moves_module_of_dependency() {
+        check(
+            "
+//- /main.wesl package:app dependencies:lighting edition:2026_pre
+import lighting::brdf::{ggx, lambert};
+import other::brdf::ggx;
+
+//- /lighting/package.wesl package:lighting root:/lighting edition:2026_pre
+
+//- /lighting/brdf.wesl
+fn ggx() -> f32 { return 1.0; }
+fn lambert() -> f32 { return 1.0; }
+",
+            &[(2, "/lighting/models/brdf.wesl")],
+            &expect![[r#"
+                FileId(1)
+                import lighting::models::brdf::{ggx, lambert};
+            "#]],
+        );
+    }
+
+    #[test]
+    fn keeps_unresolved_leaves() {
+        check(
+            "
+//- /main.wesl package:app dependencies:lighting edition:2026_pre
+import {lighting::brdf::ggx, unknown::x};
+
+//- /lighting/package.wesl package:lighting root:/lighting edition:2026_pre
+
+//- /lighting/brdf.wesl
+fn ggx() -> f32 { return 1.0; }
+",
+            &[(2, "/lighting/models/brdf.wesl")],
+            &expect![[r#"
+                FileId(1)
+                import lighting::models::brdf::ggx;
+                import unknown::x;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn ignores_moves_to_other_file_types() {
+        check(
+            "
+//- /main.wesl package:app root:/ edition:2026_pre
+import package::brdf::ggx;
+
+//- /brdf.wesl
+fn ggx() -> f32 { return 1.0; }
+",
+            &[(1, "/brdf.txt")],
+            &expect![[""]],
+        );
+    }
+}
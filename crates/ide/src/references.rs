@@ -0,0 +1,354 @@
+//! This module implements a reference search.
+//! First, the element at the cursor position must be either an `ast::Name`
+//! or `ast::Path`. If it is an `ast::Path`, it gets resolved to its definition.
+//! Then, the definition is used to find all usages, following WESL imports into
+//! other modules and dependent packages.
+
+use base_db::{EditionedFileId, FilePosition, SourceDatabase as _, TextRange};
+use hir::{Semantics, definition::Definition};
+use ide_db::{
+    FxHashMap, RootDatabase,
+    search::{FindUsages, ReferenceCategory, SearchScope},
+};
+use syntax::{AstNode as _, SyntaxKind};
+use vfs::FileId;
+
+use crate::{NavigationTarget, goto_definition::TryToNavigationTarget as _, helpers};
+
+#[derive(Debug, Clone)]
+pub struct ReferenceSearchResult {
+    /// Information about the declaration site of the searched item.
+    ///
+    /// This is `None` for builtins, which have no declaration in the source code.
+    pub declaration: Option<Declaration>,
+    /// All references found, grouped by file.
+    ///
+    /// Each reference carries its category, if one could be determined.
+    pub references: FxHashMap<FileId, Vec<(TextRange, Option<ReferenceCategory>)>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Declaration {
+    pub navigation_target: NavigationTarget,
+}
+
+// Feature: Find All References
+//
+// Shows all references of the item at the cursor location.
+// This includes usages in other modules and packages which bring the item into scope
+// with a WESL `import` statement.
+pub(crate) fn find_all_refs(
+    db: &RootDatabase,
+    position: FilePosition,
+    search_scope: Option<&SearchScope>,
+) -> Option<ReferenceSearchResult> {
+    let _p = tracing::info_span!("find_all_refs").entered();
+    let semantics = &Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, position.file_id);
+    let file = file_id.parse(db).tree();
+    let token = file.syntax().token_at_offset(position.offset);
+
+    #[expect(
+        clippy::wildcard_enum_match_arm,
+        reason = "infeasible to list all cases"
+    )]
+    let token = helpers::pick_best_token(token, |token| match token {
+        SyntaxKind::Identifier => 2,
+        kind if kind.is_trivia() => 0,
+        _ => 1,
+    })?;
+
+    let definition = Definition::from_token(semantics, file_id, &token)?;
+    let mut usages = FindUsages::new(&definition, semantics);
+    if let Some(scope) = search_scope {
+        usages = usages.in_scope(scope);
+    }
+    let references = usages
+        .all()
+        .into_iter()
+        .map(|(file_id, references)| {
+            let references = references
+                .into_iter()
+                .map(|reference| (reference.range, reference.category))
+                .collect();
+            (file_id, references)
+        })
+        .collect();
+    let declaration = definition
+        .try_to_navigation_target(db)
+        .map(|navigation_target| Declaration { navigation_target });
+
+    Some(ReferenceSearchResult {
+        declaration,
+        references,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use itertools::Itertools as _;
+    use stdx::format_to;
+
+    use crate::fixture;
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let references = analysis.find_all_refs(position, None).unwrap().unwrap();
+
+        let mut actual = String::new();
+        if let Some(declaration) = &references.declaration {
+            let navigation_target = &declaration.navigation_target;
+            format_to!(
+                actual,
+                "declaration: {:?} {:?}\n",
+                navigation_target.file_id,
+                navigation_target.focus_or_full_range()
+            );
+        }
+        for (file_id, references) in references
+            .references
+            .iter()
+            .sorted_by_key(|(file_id, _)| **file_id)
+        {
+            for (range, category) in references {
+                format_to!(actual, "{file_id:?} {range:?}");
+                if let Some(category) = category {
+                    format_to!(actual, " {category:?}");
+                }
+                actual.push('\n');
+            }
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn local_references() {
+        check(
+            "
+fn main() {
+    var a$0 = 1;
+    a = a + 1;
+    a += 2;
+    let b = a;
+}
+",
+            &expect![[r#"
+                declaration: FileId(1) 20..21
+                FileId(1) 31..32 Write
+                FileId(1) 35..36 Read
+                FileId(1) 46..47 Write
+                FileId(1) 66..67 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn local_references_are_scoped_to_function() {
+        check(
+            "
+fn foo() {
+    let a = 1;
+    let b = a;
+}
+fn main() {
+    let a$0 = 2;
+    let c = a;
+}
+",
+            &expect![[r#"
+                declaration: FileId(1) 63..64
+                FileId(1) 82..83 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn parameter_references() {
+        check(
+            "
+fn foo(value$0: f32) -> f32 {
+    return value * 2.0;
+}
+",
+            &expect![[r#"
+                declaration: FileId(1) 7..12
+                FileId(1) 39..44 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn function_references_from_usage() {
+        check(
+            "
+fn foo() -> f32 { return 1.0; }
+fn main() {
+    let a = foo$0();
+    let b = foo() + foo();
+}
+",
+            &expect![[r#"
+                declaration: FileId(1) 3..6
+                FileId(1) 56..59 Read
+                FileId(1) 75..78 Read
+                FileId(1) 83..86 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_references_in_types() {
+        check(
+            "
+struct Light$0 { intensity: f32 }
+var<private> light: Light;
+fn get(light: Light) -> Light {
+    return Light(1.0);
+}
+",
+            &expect![[r#"
+                declaration: FileId(1) 7..12
+                FileId(1) 52..57
+                FileId(1) 73..78
+                FileId(1) 83..88
+                FileId(1) 102..107 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn field_references() {
+        check(
+            "
+struct Light { intensity$0: f32 }
+fn main() {
+    var light: Light;
+    light.intensity = 2.0;
+    let a = light.intensity;
+}
+",
+            &expect![[r#"
+                declaration: FileId(1) 15..24
+                FileId(1) 76..85 Write
+                FileId(1) 111..120 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn global_references() {
+        check(
+            "
+const SCALE$0 = 2.0;
+override OFFSET = SCALE;
+fn main() {
+    let a = SCALE;
+}
+",
+            &expect![[r#"
+                declaration: FileId(1) 6..11
+                FileId(1) 37..42 Read
+                FileId(1) 68..73 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn references_across_modules() {
+        check(
+            "
+//- /package.wesl edition:2026_pre
+import package::lighting::brdf;
+fn main() {
+    let a = brdf();
+    let b = package::lighting::brdf();
+}
+
+//- /lighting.wesl
+fn brdf$0() -> f32 { return 1.0; }
+fn other() -> f32 { return brdf(); }
+",
+            &expect![[r#"
+                declaration: FileId(2) 3..7
+                FileId(1) 26..30 Import
+                FileId(1) 56..60 Read
+                FileId(1) 95..99 Read
+                FileId(2) 60..64 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn references_through_import_alias() {
+        check(
+            "
+//- /package.wesl edition:2026_pre
+import package::lighting::brdf as shade;
+fn main() {
+    let a = shade();
+}
+
+//- /lighting.wesl
+fn brdf$0() -> f32 { return 1.0; }
+",
+            &expect![[r#"
+                declaration: FileId(2) 3..7
+                FileId(1) 26..30 Import
+                FileId(1) 65..70 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn references_from_import_statement() {
+        check(
+            "
+//- /package.wesl edition:2026_pre
+import package::lighting::brdf$0;
+fn main() {
+    let a = brdf();
+}
+
+//- /lighting.wesl
+fn brdf() -> f32 { return 1.0; }
+",
+            &expect![[r#"
+                declaration: FileId(2) 3..7
+                FileId(1) 26..30 Import
+                FileId(1) 56..60 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn references_in_dependent_package() {
+        check(
+            "
+//- /main.wesl package:app dependencies:lighting edition:2026_pre
+import lighting::brdf;
+fn main() {
+    let a = brdf();
+}
+
+//- /package.wesl package:lighting edition:2026_pre
+fn brdf$0() -> f32 { return 1.0; }
+
+//- /unrelated.wesl package:unrelated edition:2026_pre
+fn brdf() -> f32 { return 2.0; }
+fn main() {
+    let a = brdf();
+}
+",
+            &expect![[r#"
+                declaration: FileId(3) 3..7
+                FileId(1) 17..21 Import
+                FileId(1) 47..51 Read
+            "#]],
+        );
+    }
+}
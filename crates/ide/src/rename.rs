@@ -0,0 +1,477 @@
+//! Renaming functionality.
+//!
+//! This is mostly front-end for [`ide_db::search`], with the addition of
+//! validation of the new name. Usages in other modules and packages are updated
+//! as well, including the items of WESL `import` statements. Import aliases
+//! are kept as they are, since they introduce a new name of their own.
+
+use std::fmt;
+
+use base_db::{EditionedFileId, FilePosition, RangeInfo, SourceDatabase as _, TextRange};
+use hir::{Semantics, definition::Definition};
+use ide_db::{
+    FxHashMap, RootDatabase,
+    search::{FindUsages, SearchScope},
+    source_change::SourceChange,
+    text_edit::TextEdit,
+};
+use syntax::{AstNode as _, Edition, SyntaxKind, SyntaxToken, ast};
+
+use crate::{goto_definition::TryToNavigationTarget as _, helpers};
+
+#[derive(Debug)]
+pub struct RenameError(pub String);
+
+impl fmt::Display for RenameError {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+type RenameResult<T> = Result<T, RenameError>;
+
+/// What the cursor is on.
+struct RenameTarget {
+    token: SyntaxToken,
+    definition: Definition,
+    /// Set if the cursor is on the alias of an `import` item.
+    /// Renaming an alias only affects the importing file.
+    alias: Option<ast::Name>,
+}
+
+// Feature: Rename
+//
+// Renames the item below the cursor and all of its references.
+// Items which are imported into other modules are renamed there as well.
+pub(crate) fn prepare_rename(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> RenameResult<RangeInfo<()>> {
+    let semantics = Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, position.file_id);
+    let target = find_target(&semantics, file_id, position)?;
+    Ok(RangeInfo::new(target.token.text_range(), ()))
+}
+
+pub(crate) fn rename(
+    db: &RootDatabase,
+    position: FilePosition,
+    new_name: &str,
+) -> RenameResult<SourceChange> {
+    let semantics = Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, position.file_id);
+    let target = find_target(&semantics, file_id, position)?;
+    let old_name = target.token.text().to_owned();
+
+    let mut ranges: FxHashMap<EditionedFileId, Vec<TextRange>> = FxHashMap::default();
+    let usages = FindUsages::new(&target.definition, &semantics);
+    let single_file = SearchScope::single_file(file_id);
+    let usages = if target.alias.is_some() {
+        usages.in_scope(&single_file)
+    } else {
+        usages
+    };
+    usages.search(&mut |file_id, reference| {
+        // References which go through an import alias use a different name and stay untouched.
+        let text = db.file_text(file_id.file_id(db)).text(db);
+        if text.get(reference.range) == Some(old_name.as_str()) {
+            ranges.entry(file_id).or_default().push(reference.range);
+        }
+        false
+    });
+
+    let declaration = if let Some(alias) = &target.alias {
+        Some((file_id, alias.syntax().text_range()))
+    } else {
+        target
+            .definition
+            .try_to_navigation_target(db)
+            .map(|navigation_target| {
+                (
+                    EditionedFileId::from_file(db, navigation_target.file_id),
+                    navigation_target.focus_or_full_range(),
+                )
+            })
+    };
+    if let Some((file_id, range)) = declaration {
+        ranges.entry(file_id).or_default().push(range);
+    }
+
+    for file_id in ranges.keys() {
+        check_identifier(new_name, file_id.edition(db))?;
+    }
+
+    let mut source_change = SourceChange::default();
+    for (file_id, mut ranges) in ranges {
+        ranges.sort_by_key(|range| range.start());
+        ranges.dedup();
+        let mut builder = TextEdit::builder();
+        for range in ranges {
+            builder.replace(range, new_name.to_owned());
+        }
+        source_change.insert_source_edit(file_id.file_id(db), builder.finish());
+    }
+    Ok(source_change)
+}
+
+fn find_target(
+    semantics: &Semantics<'_>,
+    file_id: EditionedFileId,
+    position: FilePosition,
+) -> RenameResult<RenameTarget> {
+    let file = semantics.parse(file_id);
+    let token = file.syntax().token_at_offset(position.offset);
+    let token = helpers::pick_best_token(token, |kind| usize::from(kind == SyntaxKind::Identifier))
+        .filter(|token| token.kind() == SyntaxKind::Identifier)
+        .ok_or_else(|| RenameError("No references found at position".to_owned()))?;
+
+    let parent = token.parent();
+    let is_module_path = if let Some(path) = parent.clone().and_then(ast::Path::cast) {
+        path.segments().last().as_ref() != Some(&token)
+    } else {
+        parent
+            .as_ref()
+            .and_then(|parent| parent.parent())
+            .is_some_and(|grandparent| ast::ImportPath::can_cast(grandparent.kind()))
+    };
+    if is_module_path {
+        return Err(RenameError("Cannot rename a module path".to_owned()));
+    }
+
+    let definition = Definition::from_token(semantics, file_id, &token)
+        .ok_or_else(|| RenameError("No references found at position".to_owned()))?;
+    if let Definition::BuiltinFunction(name)
+    | Definition::BuiltinType(name)
+    | Definition::BuiltinTypeGenerator(name)
+    | Definition::BuiltinEnumerant(name)
+    | Definition::BuiltinDeclaration(name) = &definition
+    {
+        return Err(RenameError(format!(
+            "Cannot rename builtin `{}`",
+            name.as_str()
+        )));
+    }
+
+    let alias = parent.and_then(ast::Name::cast).filter(|name| {
+        name.syntax()
+            .parent()
+            .and_then(ast::ImportItem::cast)
+            .and_then(|item| item.alias())
+            .as_ref()
+            == Some(name)
+    });
+    Ok(RenameTarget {
+        token,
+        definition,
+        alias,
+    })
+}
+
+/// Checks that `new_name` can be used as an identifier in the given edition.
+fn check_identifier(
+    new_name: &str,
+    edition: Edition,
+) -> RenameResult<()> {
+    let not_an_identifier = || RenameError(format!("Invalid name `{new_name}`: not an identifier"));
+    let kind = syntax::single_token(new_name, edition).ok_or_else(not_an_identifier)?;
+    if kind == SyntaxKind::Identifier {
+        if hir::is_invalid_identifier(new_name) {
+            return Err(RenameError(format!(
+                "Invalid name `{new_name}`: identifiers must not start with `__`"
+            )));
+        }
+        Ok(())
+    } else if kind == SyntaxKind::Reserved {
+        Err(RenameError(format!(
+            "Invalid name `{new_name}`: it is a reserved word in WGSL"
+        )))
+    } else if kind.is_keyword() {
+        Err(RenameError(format!(
+            "Invalid name `{new_name}`: it is a keyword"
+        )))
+    } else {
+        Err(not_an_identifier())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use ide_db::text_edit::TextEdit;
+    use itertools::Itertools as _;
+    use stdx::format_to;
+
+    use crate::fixture;
+
+    #[track_caller]
+    fn check(
+        new_name: &str,
+        wa_fixture_before: &str,
+        wa_fixture_after: &str,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture_before);
+        let source_change = analysis
+            .rename(position, new_name)
+            .unwrap()
+            .unwrap_or_else(|error| panic!("rename to `{new_name}` failed: {error}"));
+        let mut text = analysis.file_text(position.file_id).unwrap().to_string();
+        source_change
+            .get_source_edit(position.file_id)
+            .unwrap_or(&TextEdit::default())
+            .apply(&mut text);
+        assert_eq!(text, stdx::trim_indent(wa_fixture_after));
+    }
+
+    #[track_caller]
+    fn check_expect(
+        new_name: &str,
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let source_change = analysis
+            .rename(position, new_name)
+            .unwrap()
+            .unwrap_or_else(|error| panic!("rename to `{new_name}` failed: {error}"));
+        let mut actual = String::new();
+        for (file_id, edit) in source_change
+            .source_file_edits
+            .iter()
+            .sorted_by_key(|(file_id, _)| **file_id)
+        {
+            for insert_delete in edit {
+                format_to!(
+                    actual,
+                    "{file_id:?} {:?} {}\n",
+                    insert_delete.delete,
+                    insert_delete.insert
+                );
+            }
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[track_caller]
+    fn check_error(
+        new_name: &str,
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let error = analysis
+            .rename(position, new_name)
+            .unwrap()
+            .expect_err("rename should fail");
+        expect.assert_eq(&error.to_string());
+    }
+
+    #[track_caller]
+    #[expect(clippy::use_debug, reason = "useful in tests")]
+    fn check_prepare(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(wa_fixture);
+        let actual = match analysis.prepare_rename(position).unwrap() {
+            Ok(range_info) => format!("{:?}", range_info.range),
+            Err(error) => error.to_string(),
+        };
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn rename_local() {
+        check(
+            "b",
+            "
+fn main() {
+    var a$0 = 1;
+    a = a + 1;
+}
+",
+            "
+fn main() {
+    var b = 1;
+    b = b + 1;
+}
+",
+        );
+    }
+
+    #[test]
+    fn rename_parameter_from_usage() {
+        check(
+            "scale",
+            "
+fn foo(value: f32) -> f32 {
+    return value$0 * 2.0;
+}
+",
+            "
+fn foo(scale: f32) -> f32 {
+    return scale * 2.0;
+}
+",
+        );
+    }
+
+    #[test]
+    fn rename_function() {
+        check(
+            "bar",
+            "
+fn foo$0() -> f32 { return 1.0; }
+fn main() {
+    let a = foo() + foo();
+}
+",
+            "
+fn bar() -> f32 { return 1.0; }
+fn main() {
+    let a = bar() + bar();
+}
+",
+        );
+    }
+
+    #[test]
+    fn rename_struct_and_field() {
+        check(
+            "Lamp",
+            "
+struct Light$0 { intensity: f32 }
+fn get(light: Light) -> Light {
+    return Light(light.intensity);
+}
+",
+            "
+struct Lamp { intensity: f32 }
+fn get(light: Lamp) -> Lamp {
+    return Lamp(light.intensity);
+}
+",
+        );
+        check(
+            "brightness",
+            "
+struct Light { intensity: f32 }
+fn get(light: Light) -> f32 {
+    return light.intensity$0;
+}
+",
+            "
+struct Light { brightness: f32 }
+fn get(light: Light) -> f32 {
+    return light.brightness;
+}
+",
+        );
+    }
+
+    #[test]
+    fn rename_updates_imports_in_other_modules() {
+        check_expect(
+            "specular",
+            "
+//- /package.wesl edition:2026_pre
+import package::lighting::{brdf, brdf as shade};
+fn main() {
+    let a = brdf() + shade();
+    let b = package::lighting::brdf();
+}
+
+//- /lighting.wesl
+fn brdf$0() -> f32 { return 1.0; }
+",
+            &expect![[r#"
+                FileId(1) 27..31 specular
+                FileId(1) 33..37 specular
+                FileId(1) 73..77 specular
+                FileId(1) 122..126 specular
+                FileId(2) 3..7 specular
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rename_import_alias_is_local() {
+        check_expect(
+            "diffuse",
+            "
+//- /package.wesl edition:2026_pre
+import package::lighting::brdf as shade$0;
+fn main() {
+    let a = shade();
+}
+
+//- /lighting.wesl
+fn brdf() -> f32 { return 1.0; }
+",
+            &expect![[r#"
+                FileId(1) 34..39 diffuse
+                FileId(1) 65..70 diffuse
+            "#]],
+        );
+    }
+
+    #[test]
+    fn rename_rejects_invalid_names() {
+        let fixture = "
+fn main() {
+    let a$0 = 1;
+}
+";
+        check_error(
+            "class",
+            fixture,
+            &expect!["Invalid name `class`: it is a reserved word in WGSL"],
+        );
+        check_error(
+            "loop",
+            fixture,
+            &expect!["Invalid name `loop`: it is a keyword"],
+        );
+        check_error(
+            "__a",
+            fixture,
+            &expect!["Invalid name `__a`: identifiers must not start with `__`"],
+        );
+        check_error(
+            "a b",
+            fixture,
+            &expect!["Invalid name `a b`: not an identifier"],
+        );
+    }
+
+    #[test]
+    fn prepare_rename() {
+        check_prepare(
+            "
+fn main() {
+    let a = 1;
+    let b = a$0;
+}
+",
+            &expect!["39..40"],
+        );
+        check_prepare(
+            "
+fn main() {
+    let a = abs$0(1.0);
+}
+",
+            &expect!["Cannot rename builtin `abs`"],
+        );
+        check_prepare(
+            "
+fn main() {
+    let a = 1.0 +$0 2.0;
+}
+",
+            &expect!["No references found at position"],
+        );
+    }
+}
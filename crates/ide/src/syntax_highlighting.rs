@@ -0,0 +1,350 @@
+pub(crate) mod tags;
+
+use std::iter;
+
+use base_db::{EditionedFileId, TextRange};
+use hir::{
+    AccessMode, AddressSpace, HasSource as _, HirDatabase, ModuleDef, Semantics,
+    definition::Definition,
+};
+use hir_ty::ty::{Pointer, Reference, Type, TypeKind};
+use ide_db::RootDatabase;
+use syntax::{AstNode as _, SyntaxKind, SyntaxToken, ast};
+use vfs::FileId;
+
+use self::tags::{Highlight, HlMod, HlTag};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HlRange {
+    pub range: TextRange,
+    pub highlight: Highlight,
+}
+
+// Feature: Semantic Syntax Highlighting
+//
+// wgsl-analyzer highlights the code semantically.
+// For example, user functions are highlighted differently from builtin functions,
+// and `var`s are highlighted differently from `let`s.
+//
+// Variables and pointers carry their address space as a modifier,
+// so a `storage` buffer can be told apart from a function-local variable.
+// Storage buffers with `read_write` access additionally have the `readWrite` modifier,
+// and names declared in other modules have the `imported` modifier.
+pub(crate) fn highlight(
+    db: &RootDatabase,
+    file_id: FileId,
+) -> Vec<HlRange> {
+    let _p = tracing::info_span!("highlight").entered();
+    let semantics = Semantics::new(db);
+    let file_id = EditionedFileId::from_file(db, file_id);
+    let root = file_id.parse(db).syntax();
+
+    root.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter_map(|token| {
+            let highlight = highlight_token(&semantics, file_id, &token)?;
+            Some(HlRange {
+                range: token.text_range(),
+                highlight,
+            })
+        })
+        .collect()
+}
+
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "only some tokens are highlighted"
+)]
+fn highlight_token(
+    semantics: &Semantics<'_>,
+    file_id: EditionedFileId,
+    token: &SyntaxToken,
+) -> Option<Highlight> {
+    let highlight = match token.kind() {
+        SyntaxKind::LineEndingComment | SyntaxKind::BlockComment => HlTag::Comment.into(),
+        kind if kind.is_trivia() => return None,
+        SyntaxKind::IntLiteral | SyntaxKind::FloatLiteral => HlTag::NumericLiteral.into(),
+        SyntaxKind::True | SyntaxKind::False => HlTag::BoolLiteral.into(),
+        SyntaxKind::AttributeOperator => HlTag::Attribute.into(),
+        _ if is_attribute_name(token) => HlTag::Attribute.into(),
+        SyntaxKind::Identifier => return highlight_identifier(semantics, file_id, token),
+        kind if is_keyword(kind) => HlTag::Keyword.into(),
+        _ if is_attribute_enumerant(token) => HlTag::EnumMember | HlMod::DefaultLibrary,
+        _ => return None,
+    };
+    Some(highlight)
+}
+
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "only names and paths refer to definitions"
+)]
+fn highlight_identifier(
+    semantics: &Semantics<'_>,
+    file_id: EditionedFileId,
+    token: &SyntaxToken,
+) -> Option<Highlight> {
+    let parent = token.parent()?;
+    match parent.kind() {
+        SyntaxKind::Name => {
+            let name = ast::Name::cast(parent)?;
+            let name_parent = name.syntax().parent()?;
+            if name_parent.kind() == SyntaxKind::ImportPath {
+                return Some(HlTag::Module.into());
+            }
+            let definition = Definition::from_name(semantics, file_id, &name)?;
+            let mut highlight = highlight_definition(semantics.db, file_id, &definition)?;
+            if name_parent.kind() != SyntaxKind::ImportItem {
+                highlight |= HlMod::Declaration;
+            }
+            Some(highlight)
+        },
+        SyntaxKind::Path => {
+            let mut segments = parent
+                .children_with_tokens()
+                .filter_map(|element| element.into_token())
+                .filter(|segment| segment.kind() == SyntaxKind::Identifier);
+            if segments.by_ref().any(|segment| segment == *token) && segments.next().is_some() {
+                // Every segment but the last one names a module.
+                return Some(HlTag::Module.into());
+            }
+            let definition = Definition::from_node(semantics, file_id, &parent)?;
+            highlight_definition(semantics.db, file_id, &definition)
+        },
+        SyntaxKind::FieldExpression => {
+            match Definition::from_node(semantics, file_id, &parent) {
+                Some(definition) => highlight_definition(semantics.db, file_id, &definition),
+                // Vector swizzles, like `color.rgb`
+                None => Some(HlTag::Field.into()),
+            }
+        },
+        _ if is_attribute_enumerant(token) => Some(HlTag::EnumMember | HlMod::DefaultLibrary),
+        _ => None,
+    }
+}
+
+fn highlight_definition(
+    db: &dyn HirDatabase,
+    file_id: EditionedFileId,
+    definition: &Definition,
+) -> Option<Highlight> {
+    let highlight = match definition {
+        Definition::Local(local) => {
+            let declaration = local.source(db)?.value.syntax().parent()?;
+            let tag = match declaration.kind() {
+                SyntaxKind::Parameter => HlTag::Parameter,
+                SyntaxKind::VariableDeclaration => HlTag::Variable,
+                SyntaxKind::ConstantDeclaration => HlTag::Constant,
+                _ => HlTag::Local,
+            };
+            with_memory_modifiers(db, tag.into(), local.ty(db))
+        },
+        Definition::Field(_) => HlTag::Field.into(),
+        Definition::ModuleDef(module_definition) => {
+            let mut highlight = match module_definition {
+                ModuleDef::Function(_) => HlTag::Function.into(),
+                ModuleDef::GlobalVariable(variable) => {
+                    with_memory_modifiers(db, HlTag::Variable.into(), variable.ty(db))
+                },
+                ModuleDef::GlobalConstant(_) | ModuleDef::Override(_) => HlTag::Constant.into(),
+                ModuleDef::Struct(_) => HlTag::Struct.into(),
+                ModuleDef::TypeAlias(_) => HlTag::TypeAlias.into(),
+                ModuleDef::GlobalAssertStatement(_) => return None,
+            };
+            if module_definition.file_id(db) != file_id {
+                highlight |= HlMod::Imported;
+            }
+            highlight
+        },
+        Definition::BuiltinFunction(_) => HlTag::Function | HlMod::DefaultLibrary,
+        Definition::BuiltinType(_) | Definition::BuiltinTypeGenerator(_) => {
+            HlTag::BuiltinType | HlMod::DefaultLibrary
+        },
+        Definition::BuiltinEnumerant(_) => HlTag::EnumMember | HlMod::DefaultLibrary,
+        Definition::BuiltinDeclaration(_) => HlTag::Constant | HlMod::DefaultLibrary,
+    };
+    Some(highlight)
+}
+
+/// Adds the address space and access mode of references and pointers.
+fn with_memory_modifiers(
+    db: &dyn HirDatabase,
+    mut highlight: Highlight,
+    r#type: Type,
+) -> Highlight {
+    let (address_space, access_mode) = match r#type.kind(db) {
+        TypeKind::Reference(Reference {
+            address_space,
+            access_mode,
+            ..
+        })
+        | TypeKind::Pointer(Pointer {
+            address_space,
+            access_mode,
+            ..
+        }) => (address_space, access_mode),
+        TypeKind::Error
+        | TypeKind::Scalar(_)
+        | TypeKind::Atomic(_)
+        | TypeKind::Vector(_)
+        | TypeKind::Matrix(_)
+        | TypeKind::Struct(_)
+        | TypeKind::BuiltinStruct(_)
+        | TypeKind::Array(_)
+        | TypeKind::Texture(_)
+        | TypeKind::Sampler(_) => return highlight,
+    };
+    match address_space {
+        AddressSpace::Private => highlight |= HlMod::Private,
+        AddressSpace::Workgroup => highlight |= HlMod::Workgroup,
+        AddressSpace::Uniform => highlight |= HlMod::Uniform,
+        AddressSpace::Storage => {
+            highlight |= HlMod::Storage;
+            if access_mode == AccessMode::ReadWrite {
+                highlight |= HlMod::ReadWrite;
+            }
+        },
+        AddressSpace::Function
+        | AddressSpace::Handle
+        | AddressSpace::Immediate
+        | AddressSpace::TaskPayload => {},
+    }
+    highlight
+}
+
+/// Whether the token is the name of an attribute, like `vertex` in `@vertex`.
+fn is_attribute_name(token: &SyntaxToken) -> bool {
+    iter::successors(token.prev_token(), SyntaxToken::prev_token)
+        .find(|previous| !previous.kind().is_trivia())
+        .is_some_and(|previous| previous.kind() == SyntaxKind::AttributeOperator)
+}
+
+/// Whether the token is a predeclared enumerant in an attribute, like `position` in `@builtin(position)`.
+fn is_attribute_enumerant(token: &SyntaxToken) -> bool {
+    token.parent().is_some_and(|parent| {
+        matches!(
+            parent.kind(),
+            SyntaxKind::BuiltinValueName
+                | SyntaxKind::InterpolateTypeName
+                | SyntaxKind::InterpolateSamplingName
+                | SyntaxKind::EarlyDepthTestMode
+        )
+    })
+}
+
+fn is_keyword(kind: SyntaxKind) -> bool {
+    kind.is_keyword()
+        || matches!(
+            kind,
+            SyntaxKind::Import
+                | SyntaxKind::Package
+                | SyntaxKind::Super
+                | SyntaxKind::As
+                | SyntaxKind::Elif
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use stdx::format_to;
+
+    use super::highlight;
+    use crate::fixture;
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, file_id) = fixture::single_file_db(wa_fixture);
+        let text = analysis.file_text(file_id).unwrap();
+        let highlights = analysis.with_db(|db| highlight(db, file_id)).unwrap();
+
+        let mut actual = String::new();
+        for range in highlights {
+            format_to!(actual, "{} {}\n", &text[range.range], range.highlight);
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn highlight_variables_and_address_spaces() {
+        check(
+            "
+struct Particle { position: vec3f }
+@group(0) @binding(0) var<storage, read_write> particles: array<Particle>;
+@group(0) @binding(1) var<uniform> count: u32;
+var<workgroup> shared_data: array<f32, 64>;
+fn update(index: u32, target: ptr<private, f32>) {
+    let particle = particles[index];
+    var speed = length(particle.position.xy);
+    const SCALE = 2.0;
+    particles[index].position = particle.position * SCALE + speed;
+}
+",
+            &expect![[r#"
+                struct keyword
+                Particle struct.declaration
+                position field.declaration
+                vec3f builtin_type.default_library
+                @ attribute
+                group attribute
+                0 numeric_literal
+                @ attribute
+                binding attribute
+                0 numeric_literal
+                var keyword
+                storage enum_member.default_library
+                read_write enum_member.default_library
+                particles variable.declaration.storage.read_write
+                array builtin_type.default_library
+                Particle struct
+                @ attribute
+                group attribute
+                0 numeric_literal
+                @ attribute
+                binding attribute
+                1 numeric_literal
+                var keyword
+                uniform enum_member.default_library
+                count variable.declaration.uniform
+                u32 builtin_type.default_library
+                var keyword
+                workgroup enum_member.default_library
+                shared_data variable.declaration.workgroup
+                array builtin_type.default_library
+                f32 builtin_type.default_library
+                64 numeric_literal
+                fn keyword
+                update function.declaration
+                index parameter.declaration
+                u32 builtin_type.default_library
+                target parameter.declaration.private
+                ptr builtin_type.default_library
+                private enum_member.default_library
+                f32 builtin_type.default_library
+                let keyword
+                particle local.declaration
+                particles variable.storage.read_write
+                index parameter
+                var keyword
+                speed variable.declaration
+                length function.default_library
+                particle local
+                position field
+                xy field
+                const keyword
+                SCALE constant.declaration
+                2.0 numeric_literal
+                particles variable.storage.read_write
+                index parameter
+                position field
+                particle local
+                position field
+                SCALE constant
+                speed variable
+            "#]],
+        );
+    }
+}
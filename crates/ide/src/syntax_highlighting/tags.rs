@@ -0,0 +1,225 @@
+//! Defines token tags we use for syntax highlighting.
+//! A tag is not unlike a CSS class.
+
+use std::{fmt, ops};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Highlight {
+    pub tag: HlTag,
+    pub mods: HlMods,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HlMods(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HlTag {
+    Attribute,
+    BoolLiteral,
+    /// Predeclared types, like `f32` or `array`.
+    BuiltinType,
+    Comment,
+    /// `const` and `override` declarations.
+    Constant,
+    EnumMember,
+    Field,
+    Function,
+    Keyword,
+    /// `let` declarations.
+    Local,
+    /// Module path segments, like `lighting` in `import package::lighting::brdf;`.
+    Module,
+    NumericLiteral,
+    Parameter,
+    Struct,
+    TypeAlias,
+    /// `var` declarations, both at module scope and in functions.
+    Variable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum HlMod {
+    /// The name of the item being declared, like `foo` in `fn foo()`.
+    Declaration = 0,
+    /// Predeclared functions, types, enumerants and constants.
+    DefaultLibrary,
+    /// Items declared in another module and brought into scope by a WESL import
+    /// or a qualified path.
+    Imported,
+    /// Variables and pointers in the `private` address space.
+    Private,
+    /// Variables and pointers in the `workgroup` address space.
+    Workgroup,
+    /// Variables and pointers in the `uniform` address space.
+    Uniform,
+    /// Variables and pointers in the `storage` address space.
+    Storage,
+    /// Storage buffers with `read_write` access.
+    ReadWrite,
+}
+
+impl HlTag {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Attribute => "attribute",
+            Self::BoolLiteral => "bool_literal",
+            Self::BuiltinType => "builtin_type",
+            Self::Comment => "comment",
+            Self::Constant => "constant",
+            Self::EnumMember => "enum_member",
+            Self::Field => "field",
+            Self::Function => "function",
+            Self::Keyword => "keyword",
+            Self::Local => "local",
+            Self::Module => "module",
+            Self::NumericLiteral => "numeric_literal",
+            Self::Parameter => "parameter",
+            Self::Struct => "struct",
+            Self::TypeAlias => "type_alias",
+            Self::Variable => "variable",
+        }
+    }
+}
+
+impl fmt::Display for HlTag {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), formatter)
+    }
+}
+
+impl HlMod {
+    pub const ALL: &'static [Self; 8] = &[
+        Self::Declaration,
+        Self::DefaultLibrary,
+        Self::Imported,
+        Self::Private,
+        Self::Workgroup,
+        Self::Uniform,
+        Self::Storage,
+        Self::ReadWrite,
+    ];
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Declaration => "declaration",
+            Self::DefaultLibrary => "default_library",
+            Self::Imported => "imported",
+            Self::Private => "private",
+            Self::Workgroup => "workgroup",
+            Self::Uniform => "uniform",
+            Self::Storage => "storage",
+            Self::ReadWrite => "read_write",
+        }
+    }
+
+    #[expect(clippy::as_conversions, reason = "repr(u8)")]
+    const fn mask(self) -> u32 {
+        1 << (self as u32)
+    }
+}
+
+impl fmt::Display for HlMod {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), formatter)
+    }
+}
+
+impl fmt::Display for Highlight {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        self.tag.fmt(formatter)?;
+        for modifier in self.mods.iter() {
+            formatter.write_str(".")?;
+            modifier.fmt(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<HlTag> for Highlight {
+    fn from(tag: HlTag) -> Self {
+        Self::new(tag)
+    }
+}
+
+impl Highlight {
+    #[must_use]
+    pub const fn new(tag: HlTag) -> Self {
+        Self {
+            tag,
+            mods: HlMods(0),
+        }
+    }
+}
+
+impl ops::BitOr<HlMod> for HlTag {
+    type Output = Highlight;
+
+    fn bitor(
+        self,
+        modifier: HlMod,
+    ) -> Highlight {
+        Highlight::new(self) | modifier
+    }
+}
+
+impl ops::BitOrAssign<HlMod> for HlMods {
+    fn bitor_assign(
+        &mut self,
+        modifier: HlMod,
+    ) {
+        self.0 |= modifier.mask();
+    }
+}
+
+impl ops::BitOrAssign<HlMod> for Highlight {
+    fn bitor_assign(
+        &mut self,
+        modifier: HlMod,
+    ) {
+        self.mods |= modifier;
+    }
+}
+
+impl ops::BitOr<HlMod> for Highlight {
+    type Output = Self;
+
+    fn bitor(
+        mut self,
+        modifier: HlMod,
+    ) -> Self {
+        self |= modifier;
+        self
+    }
+}
+
+impl HlMods {
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[must_use]
+    pub const fn contains(
+        self,
+        modifier: HlMod,
+    ) -> bool {
+        self.0 & modifier.mask() == modifier.mask()
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = HlMod> {
+        HlMod::ALL
+            .iter()
+            .copied()
+            .filter(move |modifier| self.contains(*modifier))
+    }
+}
@@ -1,2 +1,590 @@
+//! This module handles auto-magic editing actions applied together with users
+//! edits. For example, if the user typed
+//!
+//! ```text
+//!     foo
+//!         .bar()
+//!         .baz()
+//!     |   // <- cursor is here
+//! ```
+//!
+//! and types `.` next, we want to indent the dot.
+//!
+//! Language server executes such typing assists synchronously. That is, they
+//! block user's typing and should be pretty fast for this reason!
+
+use base_db::{EditionedFileId, FilePosition, SourceDatabase as _, TextRange, TextSize};
+use ide_db::{source_change::SourceChange, text_edit::TextEdit};
+use rowan::NodeOrToken;
+use syntax::{
+    AstToken as _, Direction, Edition, SyntaxKind, SyntaxNode, SyntaxToken, algorithms, ast,
+};
+use wgsl_formatter::FormattingOptions;
+
+use crate::RootDatabase;
+
 // Do not forget to add new trigger characters to `server_capabilities` in `capabilities.rs`.
-pub(crate) const TRIGGER_CHARS: &[char] = &['.', '=', '<', '>', '{', '(', '|', '+'];
+pub(crate) const TRIGGER_CHARS: &[char] = &['.', '=', '<', '>', '{', '(', '|', '+', '}'];
+
+// Feature: On Typing Assists
+//
+// Some features trigger on typing certain characters:
+//
+// - typing `}` re-indents the line to match the line of the opening `{`
+// - typing `.` at the start of a line indents a method-style `.`-chain one level deeper than
+//   its receiver
+// - typing `<` after a type name such as `array` or `vec4`, or after `var`, inserts the
+//   closing `>` if the lexer would then disambiguate the `<` as the start of a template list
+pub(crate) fn on_char_typed(
+    db: &RootDatabase,
+    position: FilePosition,
+    char_typed: char,
+) -> Option<SourceChange> {
+    let text = db.file_text(position.file_id).text(db);
+    if !stdx::always!(text[usize::from(position.offset)..].starts_with(char_typed)) {
+        return None;
+    }
+    let file_id = EditionedFileId::from_file(db, position.file_id);
+    let file = file_id.parse(db).tree();
+    let (edit, is_snippet) = match char_typed {
+        '}' => (
+            on_closing_brace_typed(file.syntax(), text, position.offset)?,
+            false,
+        ),
+        '.' => (on_dot_typed(file.syntax(), text, position.offset)?, false),
+        '<' => (
+            on_left_angle_typed(file.syntax(), text, file_id.edition(db), position.offset)?,
+            true,
+        ),
+        _ => return None,
+    };
+    Some(SourceChange {
+        is_snippet,
+        ..SourceChange::from_text_edit(position.file_id, edit)
+    })
+}
+
+/// Re-indents a `}` which starts its line to the indentation of the line of the matching `{`.
+fn on_closing_brace_typed(
+    file: &SyntaxNode,
+    text: &str,
+    offset: TextSize,
+) -> Option<TextEdit> {
+    let brace = file.token_at_offset(offset).right_biased()?;
+    if brace.kind() != SyntaxKind::BraceRight {
+        return None;
+    }
+    let leading_whitespace = leading_whitespace_before(text, offset)?;
+    let open = brace
+        .siblings_with_tokens(Direction::Prev)
+        .filter_map(NodeOrToken::into_token)
+        .find(|token| token.kind() == SyntaxKind::BraceLeft)?;
+    reindent(
+        text,
+        leading_whitespace,
+        line_indent(text, open.text_range().start()),
+    )
+}
+
+/// Indents a `.` which starts its line one level deeper than the receiver of the `.`-chain.
+fn on_dot_typed(
+    file: &SyntaxNode,
+    text: &str,
+    offset: TextSize,
+) -> Option<TextEdit> {
+    let dot = file.token_at_offset(offset).right_biased()?;
+    if dot.kind() != SyntaxKind::Period {
+        return None;
+    }
+    let leading_whitespace = leading_whitespace_before(text, offset)?;
+    let receiver = receiver_before(&dot)?;
+    let indent = format!(
+        "{}{}",
+        line_indent(text, receiver.text_range().start()),
+        FormattingOptions::default().indent_symbol
+    );
+    reindent(text, leading_whitespace, &indent)
+}
+
+/// The outermost postfix expression which ends right before the `.`, across the line break.
+fn receiver_before(dot: &SyntaxToken) -> Option<SyntaxNode> {
+    let previous = algorithms::skip_trivia_token(dot.prev_token()?, Direction::Prev)?;
+    let end = previous.text_range().end();
+    previous
+        .parent_ancestors()
+        .take_while(|node| node.text_range().end() == end)
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                SyntaxKind::FieldExpression
+                    | SyntaxKind::IndexExpression
+                    | SyntaxKind::FunctionCall
+                    | SyntaxKind::IdentExpression
+                    | SyntaxKind::ParenthesisExpression
+            )
+        })
+        .last()
+}
+
+/// Inserts the closing `>` of a template list, leaving the cursor between the brackets.
+///
+/// Whether a `<` starts a template list is decided by the lexer, which only does so if it
+/// finds the matching `>`. So the `>` is inserted only if it turns the `<` into a
+/// [`SyntaxKind::TemplateStart`], and only directly after a name, to stay out of the way of
+/// comparisons like `a < b`.
+fn on_left_angle_typed(
+    file: &SyntaxNode,
+    text: &str,
+    edition: Edition,
+    offset: TextSize,
+) -> Option<TextEdit> {
+    let angle = file.token_at_offset(offset).right_biased()?;
+    if angle.kind() == SyntaxKind::TemplateStart {
+        return None;
+    }
+    let previous = angle.prev_token()?;
+    if !matches!(previous.kind(), SyntaxKind::Identifier | SyntaxKind::Var) {
+        return None;
+    }
+
+    let after_angle = usize::from(offset) + 1;
+    let closed = format!("{}>{}", &text[..after_angle], &text[after_angle..]);
+    let closed_angle = syntax::parse(&closed, edition)
+        .syntax()
+        .token_at_offset(offset)
+        .right_biased()?;
+    (closed_angle.kind() == SyntaxKind::TemplateStart)
+        .then(|| TextEdit::replace(angle.text_range(), "<$0>".to_owned()))
+}
+
+// Feature: On Enter
+//
+// Pressing Enter
+//
+// - inside a `//` comment continues the comment on the next line
+// - inside a `/* */` comment continues it with a leading ` * `, closing it if it is unterminated
+// - right after a `{` puts the cursor on an indented line of its own, inserting the closing
+//   `}` if the block is not closed yet
+//
+// This feature requires the client to send the `experimental/onEnter` request instead of
+// inserting the line break itself.
+pub(crate) fn on_enter(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<TextEdit> {
+    let text = db.file_text(position.file_id).text(db);
+    let file = EditionedFileId::from_file(db, position.file_id)
+        .parse(db)
+        .tree();
+    let token = file
+        .syntax()
+        .token_at_offset(position.offset)
+        .left_biased()?;
+
+    if let Some(comment) = ast::Comment::cast(token.clone()) {
+        return on_enter_in_comment(&comment, text, position.offset);
+    }
+    if token.kind() == SyntaxKind::Error && token.text().starts_with("/*") {
+        return on_enter_in_unterminated_block_comment(&token, text, position.offset);
+    }
+    on_enter_after_brace(&token, text, position.offset)
+}
+
+fn on_enter_in_comment(
+    comment: &ast::Comment,
+    text: &str,
+    offset: TextSize,
+) -> Option<TextEdit> {
+    let prefix = comment.prefix();
+    let comment_range = comment.syntax().text_range();
+    if offset < comment_range.start() + TextSize::of(prefix) {
+        return None;
+    }
+    let indent = line_indent(text, comment_range.start());
+
+    if comment.kind().shape.is_block() {
+        if offset > comment_range.end() - TextSize::of("*/") {
+            return None;
+        }
+        return Some(TextEdit::insert(offset, format!("\n{indent} * $0")));
+    }
+
+    let mut delete = TextRange::empty(offset);
+    // Continuing a plain comment at the end of its line is more often annoying than not,
+    // unless the comment goes on on the next line.
+    if prefix == "//" && offset == comment_range.end() {
+        let trimmed = comment.text().trim_end();
+        if trimmed.len() < comment.text().len() {
+            delete = TextRange::new(comment_range.start() + TextSize::of(trimmed), offset);
+        } else if !followed_by_comment(comment) {
+            return None;
+        }
+    }
+    Some(TextEdit::replace(delete, format!("\n{indent}{prefix} $0")))
+}
+
+fn on_enter_in_unterminated_block_comment(
+    comment: &SyntaxToken,
+    text: &str,
+    offset: TextSize,
+) -> Option<TextEdit> {
+    if offset < comment.text_range().start() + TextSize::of("/*") {
+        return None;
+    }
+    let indent = line_indent(text, comment.text_range().start());
+    Some(TextEdit::insert(
+        offset,
+        format!("\n{indent} * $0\n{indent} */"),
+    ))
+}
+
+fn followed_by_comment(comment: &ast::Comment) -> bool {
+    let Some(whitespace) = comment
+        .syntax()
+        .next_token()
+        .and_then(ast::Whitespace::cast)
+    else {
+        return false;
+    };
+    if whitespace.spans_multiple_lines() {
+        return false;
+    }
+    whitespace
+        .syntax()
+        .next_token()
+        .and_then(ast::Comment::cast)
+        .is_some()
+}
+
+/// Splits `{$0}` over three lines, or closes the block of a `{` which ends its line.
+fn on_enter_after_brace(
+    token: &SyntaxToken,
+    text: &str,
+    offset: TextSize,
+) -> Option<TextEdit> {
+    let brace = if token.kind().is_whitespace() {
+        token.prev_token()?
+    } else {
+        token.clone()
+    };
+    if brace.kind() != SyntaxKind::BraceLeft || !is_blank(text, brace.text_range().end(), offset) {
+        return None;
+    }
+    let indent = line_indent(text, brace.text_range().start());
+    let unit = FormattingOptions::default().indent_symbol;
+
+    let next = algorithms::skip_whitespace_token(brace.next_token()?, Direction::Next);
+    if let Some(close) = next.filter(|close| close.kind() == SyntaxKind::BraceRight) {
+        if close.parent() != brace.parent() || !is_blank(text, offset, close.text_range().start()) {
+            return None;
+        }
+        return Some(TextEdit::replace(
+            TextRange::new(offset, close.text_range().start()),
+            format!("\n{indent}{unit}$0\n{indent}"),
+        ));
+    }
+
+    let is_closed = brace
+        .siblings_with_tokens(Direction::Next)
+        .any(|element| element.kind() == SyntaxKind::BraceRight);
+    let rest_of_line = text[usize::from(offset)..]
+        .lines()
+        .next()
+        .unwrap_or_default();
+    if is_closed || !rest_of_line.trim().is_empty() {
+        return None;
+    }
+    Some(TextEdit::insert(
+        offset,
+        format!("\n{indent}{unit}$0\n{indent}}}"),
+    ))
+}
+
+/// Whether the text between the offsets is only spaces and tabs.
+fn is_blank(
+    text: &str,
+    start: TextSize,
+    end: TextSize,
+) -> bool {
+    text[usize::from(start)..usize::from(end)]
+        .chars()
+        .all(|character| matches!(character, ' ' | '\t'))
+}
+
+/// The range of the spaces and tabs before `offset`, if they are all that precedes it on its line.
+fn leading_whitespace_before(
+    text: &str,
+    offset: TextSize,
+) -> Option<TextRange> {
+    let line_start = line_start(text, offset);
+    if line_start == TextSize::from(0) || !is_blank(text, line_start, offset) {
+        return None;
+    }
+    Some(TextRange::new(line_start, offset))
+}
+
+/// Replaces the whitespace with `indent`, unless it already is `indent`.
+fn reindent(
+    text: &str,
+    whitespace: TextRange,
+    indent: &str,
+) -> Option<TextEdit> {
+    (&text[whitespace] != indent).then(|| TextEdit::replace(whitespace, indent.to_owned()))
+}
+
+fn line_start(
+    text: &str,
+    offset: TextSize,
+) -> TextSize {
+    text[..usize::from(offset)]
+        .rfind('\n')
+        .map_or(TextSize::from(0), |index| TextSize::of(&text[..=index]))
+}
+
+/// The leading spaces and tabs of the line containing `offset`.
+fn line_indent(
+    text: &str,
+    offset: TextSize,
+) -> &str {
+    let line = &text[usize::from(line_start(text, offset))..];
+    let indent_length = line.len() - line.trim_start_matches([' ', '\t']).len();
+    &line[..indent_length]
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+
+    use crate::fixture;
+
+    /// Types `char_typed` at the `$0` of the fixture and applies the typing assist.
+    #[track_caller]
+    fn type_char(
+        char_typed: char,
+        before: &str,
+    ) -> Option<String> {
+        let (analysis, position) =
+            fixture::position(&before.replace("$0", &format!("$0{char_typed}")));
+        let source_change = analysis.on_char_typed(position, char_typed).unwrap()?;
+        let mut text = analysis.file_text(position.file_id).unwrap().to_string();
+        source_change
+            .get_source_edit(position.file_id)
+            .unwrap()
+            .apply(&mut text);
+        Some(text)
+    }
+
+    #[track_caller]
+    fn check_type_char(
+        char_typed: char,
+        before: &str,
+        expect: &Expect,
+    ) {
+        let after = type_char(char_typed, before).expect("typing assist should apply");
+        expect.assert_eq(&after);
+    }
+
+    #[track_caller]
+    fn check_type_char_noop(
+        char_typed: char,
+        before: &str,
+    ) {
+        assert_eq!(type_char(char_typed, before), None);
+    }
+
+    #[track_caller]
+    fn check_on_enter(
+        before: &str,
+        expect: &Expect,
+    ) {
+        let (analysis, position) = fixture::position(before);
+        let edit = analysis
+            .on_enter(position)
+            .unwrap()
+            .expect("on enter should apply");
+        let mut text = analysis.file_text(position.file_id).unwrap().to_string();
+        edit.apply(&mut text);
+        expect.assert_eq(&text);
+    }
+
+    #[track_caller]
+    fn check_on_enter_noop(before: &str) {
+        let (analysis, position) = fixture::position(before);
+        assert_eq!(analysis.on_enter(position).unwrap(), None);
+    }
+
+    #[test]
+    fn indents_closing_brace() {
+        check_type_char(
+            '}',
+            "
+fn main() {
+    if true {
+        discard;
+            $0
+}
+",
+            &expect![[r#"
+                fn main() {
+                    if true {
+                        discard;
+                    }
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn does_not_indent_closing_brace_after_code() {
+        check_type_char_noop(
+            '}',
+            "
+fn main() {
+    if true { discard; $0
+}
+",
+        );
+    }
+
+    #[test]
+    fn indents_dot_chain() {
+        check_type_char(
+            '.',
+            "
+fn main() {
+    let color = light
+    $0rgb;
+}
+",
+            &expect![[r#"
+                fn main() {
+                    let color = light
+                        .rgb;
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn indents_dot_chain_like_previous_link() {
+        check_type_char(
+            '.',
+            "
+fn main() {
+    let color = lights[0]
+        .color
+$0rgb;
+}
+",
+            &expect![[r#"
+                fn main() {
+                    let color = lights[0]
+                        .color
+                        .rgb;
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn closes_template_list() {
+        check_type_char(
+            '<',
+            "var<private> lights: array$0;",
+            &expect!["var<private> lights: array<$0>;"],
+        );
+    }
+
+    #[test]
+    fn does_not_close_comparison() {
+        check_type_char_noop('<', "fn main() { let x = 1 $0 2; }");
+    }
+
+    #[test]
+    fn does_not_close_closed_template_list() {
+        check_type_char_noop('<', "var<private> lights: array$0f32>;");
+    }
+
+    #[test]
+    fn continues_line_comment() {
+        check_on_enter(
+            "
+// Computes the$0 lighting
+fn main() {}
+",
+            &expect![[r#"
+                // Computes the
+                // $0 lighting
+                fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn does_not_continue_line_comment_at_end_of_line() {
+        check_on_enter_noop(
+            "
+// Computes the lighting$0
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn continues_block_comment() {
+        check_on_enter(
+            "
+/* Computes the$0 lighting */
+fn main() {}
+",
+            &expect![[r#"
+                /* Computes the
+                 * $0 lighting */
+                fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn closes_unterminated_block_comment() {
+        check_on_enter(
+            "
+fn main() {}
+/*$0",
+            &expect![[r#"
+                fn main() {}
+                /*
+                 * $0
+                 */"#]],
+        );
+    }
+
+    #[test]
+    fn splits_braces() {
+        check_on_enter(
+            "
+fn main() {
+    if true {$0}
+}
+",
+            &expect![[r#"
+                fn main() {
+                    if true {
+                        $0
+                    }
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn closes_unclosed_brace() {
+        check_on_enter(
+            "fn main() {$0",
+            &expect![[r#"
+                fn main() {
+                    $0
+                }"#]],
+        );
+    }
+}
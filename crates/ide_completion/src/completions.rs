@@ -2,8 +2,13 @@ use ide_db::RootDatabase;
 
 use crate::item::{Builder, CompletionItem};
 
+pub(crate) mod attribute;
 pub(crate) mod dot;
 pub(crate) mod expression;
+pub(crate) mod flyimport;
+pub(crate) mod import_path;
+pub(crate) mod keyword;
+pub(crate) mod postfix;
 
 /// Represents an in-progress set of completions being built.
 #[derive(Debug, Default)]
@@ -0,0 +1,497 @@
+//! Completes attribute names and the arguments of `@builtin(...)` and `@interpolate(...)`.
+
+use hir::ShaderStage;
+use syntax::{ExtensionsConfig, SyntaxKind};
+
+use super::Completions;
+use crate::{
+    context::{
+        AttributeTarget, BuiltinDirection, BuiltinValueContext, CompletionContext,
+        ImmediateLocation,
+    },
+    item::{BuiltInKind, CompletionItem, CompletionItemKind},
+};
+
+/// An attribute and, if it takes arguments, the snippet for them.
+type AttributeSpec = (&'static str, Option<&'static str>);
+
+const FUNCTION_ATTRIBUTES: &[AttributeSpec] = &[
+    ("vertex", None),
+    ("fragment", None),
+    ("compute", None),
+    ("workgroup_size", Some("($0)")),
+    ("must_use", None),
+    ("diagnostic", Some("(${1:off}, $0)")),
+];
+
+const IO_ATTRIBUTES: &[AttributeSpec] = &[
+    ("location", Some("($0)")),
+    ("builtin", Some("($0)")),
+    ("interpolate", Some("($0)")),
+    ("invariant", None),
+];
+
+const STRUCT_MEMBER_ATTRIBUTES: &[AttributeSpec] =
+    &[("align", Some("($0)")), ("size", Some("($0)"))];
+
+const GLOBAL_VARIABLE_ATTRIBUTES: &[AttributeSpec] =
+    &[("group", Some("($0)")), ("binding", Some("($0)"))];
+
+const OVERRIDE_ATTRIBUTES: &[AttributeSpec] = &[("id", Some("($0)"))];
+
+const STATEMENT_ATTRIBUTES: &[AttributeSpec] = &[("diagnostic", Some("(${1:off}, $0)"))];
+
+/// A built-in value, its type, and the stages and directions it can be used in.
+///
+/// Source: <https://www.w3.org/TR/WGSL/#built-in-values>
+const BUILTIN_VALUES: &[(&str, &str, &[(ShaderStage, BuiltinDirection)])] = &[
+    (
+        "vertex_index",
+        "u32",
+        &[(ShaderStage::Vertex, BuiltinDirection::Input)],
+    ),
+    (
+        "instance_index",
+        "u32",
+        &[(ShaderStage::Vertex, BuiltinDirection::Input)],
+    ),
+    (
+        "draw_index",
+        "u32",
+        &[(ShaderStage::Vertex, BuiltinDirection::Input)],
+    ),
+    (
+        "clip_distances",
+        "array<f32, N>",
+        &[(ShaderStage::Vertex, BuiltinDirection::Output)],
+    ),
+    (
+        "position",
+        "vec4<f32>",
+        &[
+            (ShaderStage::Vertex, BuiltinDirection::Output),
+            (ShaderStage::Fragment, BuiltinDirection::Input),
+        ],
+    ),
+    (
+        "front_facing",
+        "bool",
+        &[(ShaderStage::Fragment, BuiltinDirection::Input)],
+    ),
+    (
+        "frag_depth",
+        "f32",
+        &[(ShaderStage::Fragment, BuiltinDirection::Output)],
+    ),
+    (
+        "primitive_index",
+        "u32",
+        &[(ShaderStage::Fragment, BuiltinDirection::Input)],
+    ),
+    (
+        "sample_index",
+        "u32",
+        &[(ShaderStage::Fragment, BuiltinDirection::Input)],
+    ),
+    (
+        "sample_mask",
+        "u32",
+        &[
+            (ShaderStage::Fragment, BuiltinDirection::Input),
+            (ShaderStage::Fragment, BuiltinDirection::Output),
+        ],
+    ),
+    (
+        "local_invocation_id",
+        "vec3<u32>",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "local_invocation_index",
+        "u32",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "global_invocation_id",
+        "vec3<u32>",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "global_invocation_index",
+        "u32",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "workgroup_id",
+        "vec3<u32>",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "workgroup_index",
+        "u32",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "num_workgroups",
+        "vec3<u32>",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "subgroup_invocation_id",
+        "u32",
+        &[
+            (ShaderStage::Compute, BuiltinDirection::Input),
+            (ShaderStage::Fragment, BuiltinDirection::Input),
+        ],
+    ),
+    (
+        "subgroup_size",
+        "u32",
+        &[
+            (ShaderStage::Compute, BuiltinDirection::Input),
+            (ShaderStage::Fragment, BuiltinDirection::Input),
+        ],
+    ),
+    (
+        "subgroup_id",
+        "u32",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+    (
+        "num_subgroups",
+        "u32",
+        &[(ShaderStage::Compute, BuiltinDirection::Input)],
+    ),
+];
+
+pub(crate) fn complete_attributes(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+) {
+    let _p = tracing::info_span!("complete_attributes").entered();
+    match &context.completion_location {
+        Some(ImmediateLocation::Attribute(target)) => {
+            complete_attribute_names(accumulator, context, *target);
+        },
+        Some(ImmediateLocation::BuiltinValue(builtin)) => {
+            complete_builtin_values(accumulator, context, *builtin);
+        },
+        Some(ImmediateLocation::InterpolationType) => {
+            let extensions = context.file.extensions();
+            let mut types = vec!["perspective", "linear", "flat"];
+            if extensions.per_vertex {
+                types.push("per_vertex");
+            }
+            add_enumerants(accumulator, context, &types);
+        },
+        Some(ImmediateLocation::InterpolationSampling { interpolation_type }) => {
+            let samplings: &[&str] = match interpolation_type.text() {
+                "perspective" | "linear" => &["center", "centroid", "sample"],
+                "flat" => &["first", "either"],
+                _ => &[],
+            };
+            add_enumerants(accumulator, context, samplings);
+        },
+        Some(
+            ImmediateLocation::ItemList
+            | ImmediateLocation::StatementList(_)
+            | ImmediateLocation::SwitchBody
+            | ImmediateLocation::InsideStatement
+            | ImmediateLocation::FieldAccess { .. }
+            | ImmediateLocation::ImportPath { .. },
+        )
+        | None => {},
+    }
+}
+
+fn complete_attribute_names(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    target: AttributeTarget,
+) {
+    let extensions = context.file.extensions();
+    let attributes: Vec<AttributeSpec> = match target {
+        AttributeTarget::Function => FUNCTION_ATTRIBUTES.to_vec(),
+        AttributeTarget::Parameter | AttributeTarget::ReturnType => IO_ATTRIBUTES.to_vec(),
+        AttributeTarget::StructMember => {
+            let mut attributes = [IO_ATTRIBUTES, STRUCT_MEMBER_ATTRIBUTES].concat();
+            if extensions.dual_source_blending {
+                attributes.push(("blend_src", Some("($0)")));
+            }
+            attributes
+        },
+        AttributeTarget::GlobalVariable => GLOBAL_VARIABLE_ATTRIBUTES.to_vec(),
+        AttributeTarget::Override => OVERRIDE_ATTRIBUTES.to_vec(),
+        AttributeTarget::Statement => STATEMENT_ATTRIBUTES.to_vec(),
+        AttributeTarget::Item => [
+            FUNCTION_ATTRIBUTES,
+            GLOBAL_VARIABLE_ATTRIBUTES,
+            OVERRIDE_ATTRIBUTES,
+        ]
+        .concat(),
+    };
+
+    let has_arguments = context
+        .token
+        .next_token()
+        .is_some_and(|next| next.kind() == SyntaxKind::ParenthesisLeft);
+    for (name, arguments) in attributes {
+        let mut item =
+            CompletionItem::new(CompletionItemKind::Attribute, context.source_range(), name);
+        if let Some(capability) = context.config.snippet_capability
+            && let Some(arguments) = arguments
+            && !has_arguments
+        {
+            item.insert_snippet(capability, format!("{name}{arguments}"));
+        }
+        item.add_to(accumulator, context.db);
+    }
+}
+
+fn complete_builtin_values(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    builtin: BuiltinValueContext,
+) {
+    let extensions = context.file.extensions();
+    for (name, r#type, usages) in BUILTIN_VALUES {
+        if !is_builtin_value_enabled(name, &extensions) {
+            continue;
+        }
+        let is_usable = usages.iter().any(|(stage, direction)| {
+            builtin.stage.is_none_or(|expected| expected == *stage)
+                && builtin
+                    .direction
+                    .is_none_or(|expected| expected == *direction)
+        });
+        if !is_usable {
+            continue;
+        }
+        let mut item = CompletionItem::new(
+            CompletionItemKind::Builtin(BuiltInKind::Enumerant),
+            context.source_range(),
+            *name,
+        );
+        item.set_detail(Some(*r#type));
+        item.add_to(accumulator, context.db);
+    }
+}
+
+/// Whether the extension introducing a built-in value is enabled.
+fn is_builtin_value_enabled(
+    name: &str,
+    extensions: &ExtensionsConfig,
+) -> bool {
+    match name {
+        "clip_distances" => extensions.clip_distances,
+        "primitive_index" => extensions.primitive_index,
+        "draw_index" => extensions.draw_index,
+        "subgroup_invocation_id" | "subgroup_size" => extensions.subgroups,
+        "subgroup_id" | "num_subgroups" => extensions.subgroups && extensions.subgroup_id,
+        "global_invocation_index" | "workgroup_index" => extensions.linear_indexing,
+        _ => true,
+    }
+}
+
+fn add_enumerants(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    names: &[&str],
+) {
+    for name in names {
+        CompletionItem::new(
+            CompletionItemKind::Builtin(BuiltInKind::Enumerant),
+            context.source_range(),
+            *name,
+        )
+        .add_to(accumulator, context.db);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+
+    use crate::tests::{check_edit, completion_list};
+
+    #[expect(clippy::needless_pass_by_value, reason = "Matches expect! macro")]
+    fn check(
+        wa_fixture: &str,
+        expect: Expect,
+    ) {
+        expect.assert_eq(&completion_list(wa_fixture));
+    }
+
+    #[test]
+    fn function_attributes() {
+        check(
+            "
+            @$0
+            fn main() {}
+            ",
+            expect![[r#"
+                attribute compute
+                attribute diagnostic
+                attribute fragment
+                attribute must_use
+                attribute vertex
+                attribute workgroup_size
+            "#]],
+        );
+    }
+
+    #[test]
+    fn parameter_attributes() {
+        check(
+            "
+            @fragment
+            fn main(@$0 position: vec4<f32>) {}
+            ",
+            expect![[r#"
+                attribute builtin
+                attribute interpolate
+                attribute invariant
+                attribute location
+            "#]],
+        );
+    }
+
+    #[test]
+    fn global_variable_attributes() {
+        check(
+            "
+            @$0
+            var<uniform> camera: mat4x4<f32>;
+            ",
+            expect![[r#"
+                attribute binding
+                attribute group
+            "#]],
+        );
+    }
+
+    #[test]
+    fn override_attributes() {
+        check(
+            "
+            @i$0
+            override scale: f32;
+            ",
+            expect![[r#"
+                attribute id
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_member_attributes_with_extension() {
+        check(
+            "
+            enable dual_source_blending;
+            struct Output {
+                @$0 color: vec4<f32>,
+            }
+            ",
+            expect![[r#"
+                attribute align
+                attribute blend_src
+                attribute builtin
+                attribute interpolate
+                attribute invariant
+                attribute location
+                attribute size
+            "#]],
+        );
+    }
+
+    #[test]
+    fn vertex_input_builtins() {
+        check(
+            "
+            @vertex
+            fn main(@builtin($0) index: u32) -> @builtin(position) vec4<f32> {}
+            ",
+            expect![[r#"
+                builtin enumerant instance_index u32
+                builtin enumerant vertex_index   u32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn vertex_output_builtins_with_extension() {
+        check(
+            "
+            enable clip_distances;
+            @vertex
+            fn main() -> @builtin($0) vec4<f32> {}
+            ",
+            expect![[r#"
+                builtin enumerant clip_distances array<f32, N>
+                builtin enumerant position           vec4<f32>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn fragment_input_builtins() {
+        check(
+            "
+            enable primitive_index;
+            @fragment
+            fn main(@builtin(f$0) facing: bool) {}
+            ",
+            expect![[r#"
+                builtin enumerant front_facing   bool
+                builtin enumerant position  vec4<f32>
+                builtin enumerant primitive_index u32
+                builtin enumerant sample_index    u32
+                builtin enumerant sample_mask     u32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn interpolation_types() {
+        check(
+            "
+            struct Output {
+                @location(0) @interpolate($0) color: vec4<f32>,
+            }
+            ",
+            expect![[r#"
+                builtin enumerant flat
+                builtin enumerant linear
+                builtin enumerant perspective
+            "#]],
+        );
+    }
+
+    #[test]
+    fn interpolation_sampling() {
+        check(
+            "
+            struct Output {
+                @location(0) @interpolate(flat, $0) color: vec4<f32>,
+            }
+            ",
+            expect![[r#"
+                builtin enumerant either
+                builtin enumerant first
+            "#]],
+        );
+    }
+
+    #[test]
+    fn attribute_snippet() {
+        check_edit(
+            "builtin",
+            "
+fn main(@b$0 index: u32) {}
+",
+            "
+fn main(@builtin($0) index: u32) {}
+",
+        );
+    }
+}
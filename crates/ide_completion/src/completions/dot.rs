@@ -1,12 +1,14 @@
 use std::iter;
 
+use base_db::Intern as _;
+use hir::HirDatabase as _;
 use hir_def::signature::StructSignature;
-use hir_ty::ty::TypeKind;
+use hir_ty::ty::{Type, TypeKind, VecSize, VectorType};
 
 use super::Completions;
 use crate::{
     context::{CompletionContext, ImmediateLocation},
-    item::{CompletionItem, CompletionItemKind},
+    item::{CompletionItem, CompletionItemKind, CompletionRelevance},
 };
 
 pub(crate) fn complete_dot(
@@ -82,17 +84,12 @@ fn struct_completions(
     context: &CompletionContext<'_>,
     r#struct: hir_def::db::StructId,
 ) {
-    let field_completion_item = |name| {
-        CompletionItem::new(CompletionItemKind::Field, context.source_range(), name)
-            .build(context.db)
-    };
-
+    let field_types = context.db.field_types(r#struct);
     let r#struct = StructSignature::of(context.db, r#struct);
     let items = r#struct
         .fields()
         .iter()
-        .map(|(_, field)| field.name.as_str())
-        .map(field_completion_item);
+        .map(|(id, field)| field_completion_item(context, field.name.as_str(), field_types.0[id]));
     accumulator.add_all(items);
 }
 
@@ -101,19 +98,26 @@ fn builtin_struct_completions(
     context: &CompletionContext<'_>,
     builtin_struct: &hir_ty::ty::BuiltinStruct,
 ) {
-    let field_completion_item = |name| {
-        CompletionItem::new(CompletionItemKind::Field, context.source_range(), name)
-            .build(context.db)
-    };
-
     let items = builtin_struct
         .fields
         .iter()
-        .map(|(name, _)| name.as_str())
-        .map(field_completion_item);
+        .map(|(name, r#type)| field_completion_item(context, name, *r#type));
     accumulator.add_all(items);
 }
 
+fn field_completion_item(
+    context: &CompletionContext<'_>,
+    name: &str,
+    r#type: Type,
+) -> CompletionItem {
+    let mut item = CompletionItem::new(CompletionItemKind::Field, context.source_range(), name);
+    item.set_relevance(CompletionRelevance {
+        type_match: context.type_match(r#type),
+        ..CompletionRelevance::default()
+    });
+    item.build(context.db)
+}
+
 fn vector_completions(
     accumulator: &mut Completions,
     context: &CompletionContext<'_>,
@@ -134,14 +138,38 @@ fn vector_completions(
         );
         let possible_swizzles = possible_swizzles(size, &field_text);
         let suggestions = possible_swizzles.map(|label| {
-            let binding =
+            let r#type = swizzle_type(context, vector_type, label.len());
+            let mut binding =
                 CompletionItem::new(CompletionItemKind::Field, context.source_range(), label);
+            binding.set_relevance(CompletionRelevance {
+                type_match: r#type.and_then(|r#type| context.type_match(r#type)),
+                ..CompletionRelevance::default()
+            });
             binding.build(context.db)
         });
         accumulator.add_all(suggestions);
     }
 }
 
+/// The type of a swizzle with `length` components, like `f32` for `.x` of a `vec3<f32>`.
+fn swizzle_type(
+    context: &CompletionContext<'_>,
+    vector_type: &VectorType,
+    length: usize,
+) -> Option<Type> {
+    if length == 1 {
+        return Some(vector_type.component_type);
+    }
+    let size = VecSize::try_from(u8::try_from(length).ok()?).ok()?;
+    Some(
+        TypeKind::Vector(VectorType {
+            size,
+            component_type: vector_type.component_type,
+        })
+        .intern(context.db),
+    )
+}
+
 /// Tells whether swizzle completions are valid.
 fn is_swizzleable(field_text: &str) -> bool {
     if !(0..=MAX_VECTOR_SIZE).contains(&field_text.len()) {
@@ -1,23 +1,29 @@
 use std::fmt::format;
 
-use base_db::Lookup as _;
+use base_db::{Intern as _, Lookup as _};
 use hir::HirDatabase as _;
 use hir_def::{
     db::{DefinitionWithBodyId, ModuleDefinitionId},
     item_tree::Name,
-    resolver::ScopeDef,
+    resolver::{BuiltInKind, ScopeDef},
 };
 use hir_ty::{
-    infer::InferenceResult,
-    ty::pretty::{
-        TypeVerbosity, pretty_fn_with_verbosity, pretty_type, pretty_type_with_verbosity,
+    infer::{InferenceResult, builtin_function_returns},
+    ty::{
+        TypeKind,
+        pretty::{
+            TypeVerbosity, pretty_fn_with_verbosity, pretty_type, pretty_type_with_verbosity,
+        },
     },
 };
+use itertools::Itertools as _;
+use syntax::SyntaxKind;
 
 use super::Completions;
 use crate::{
+    CallableSnippets,
     context::{CompletionContext, ImmediateLocation},
-    item::{CompletionItem, CompletionItemKind, CompletionRelevance},
+    item::{CompletionItem, CompletionItemKind, CompletionRelevance, CompletionRelevanceTypeMatch},
 };
 
 pub(crate) fn complete_names_in_scope(
@@ -25,7 +31,7 @@ pub(crate) fn complete_names_in_scope(
     context: &CompletionContext<'_>,
 ) -> Option<()> {
     match context.completion_location {
-        Some(ImmediateLocation::InsideStatement) => {},
+        Some(ImmediateLocation::InsideStatement | ImmediateLocation::StatementList(_)) => {},
         _ => return None,
     }
 
@@ -92,7 +98,7 @@ pub(crate) fn complete_names_in_scope(
         let mut completion = CompletionItem::new(kind, context.source_range(), name.as_str());
         completion.set_relevance(CompletionRelevance {
             exact_name_match: false,
-            type_match: None,
+            type_match: type_match(context, &item),
             is_local: matches!(item, ScopeDef::Local(_)),
             is_name_already_imported: false,
             requires_import: false,
@@ -103,11 +109,113 @@ pub(crate) fn complete_names_in_scope(
             is_builtin: false,
         });
         completion.set_detail(detail);
+        if let Some(capability) = context.config.snippet_capability
+            && let Some(snippet) = call_snippet(context, name, &item)
+        {
+            completion.insert_snippet(capability, snippet);
+        }
         completion.add_to(accumulator, context.db);
     });
     None
 }
 
+/// How well the value of `item`, or the result of calling it, fits the expected type.
+fn type_match(
+    context: &CompletionContext<'_>,
+    item: &ScopeDef,
+) -> Option<CompletionRelevanceTypeMatch> {
+    let expected = context.expected_type?;
+    let db = context.db;
+    let r#type = match item {
+        ScopeDef::Local(local) => {
+            let definition = context
+                .container
+                .and_then(hir::ChildContainer::as_def_with_body_id)?;
+            InferenceResult::of(db, definition)[*local]
+        },
+        ScopeDef::ModuleDefinition(ModuleDefinitionId::Function(id)) => {
+            db.function_type(*id).lookup(db).return_type?
+        },
+        ScopeDef::ModuleDefinition(ModuleDefinitionId::GlobalVariable(id)) => {
+            InferenceResult::of(db, DefinitionWithBodyId::GlobalVariable(*id)).return_type()
+        },
+        ScopeDef::ModuleDefinition(ModuleDefinitionId::GlobalConstant(id)) => {
+            InferenceResult::of(db, DefinitionWithBodyId::GlobalConstant(*id)).return_type()
+        },
+        ScopeDef::ModuleDefinition(ModuleDefinitionId::Override(id)) => {
+            InferenceResult::of(db, DefinitionWithBodyId::Override(*id)).return_type()
+        },
+        ScopeDef::ModuleDefinition(ModuleDefinitionId::Struct(id)) => {
+            TypeKind::Struct(*id).intern(db)
+        },
+        ScopeDef::BuiltIn(BuiltInKind::Function(name)) => {
+            return builtin_function_returns(db, name.as_str(), expected)
+                .then_some(CompletionRelevanceTypeMatch::Exact);
+        },
+        ScopeDef::ModuleDefinition(
+            ModuleDefinitionId::TypeAlias(_) | ModuleDefinitionId::GlobalAssertStatement(_),
+        )
+        | ScopeDef::BuiltIn(_)
+        | ScopeDef::Module => return None,
+    };
+    context.type_match(r#type)
+}
+
+/// The snippet calling a completed function, according to [`CompletionConfig::callable`].
+///
+/// [`CompletionConfig::callable`]: crate::CompletionConfig::callable
+fn call_snippet(
+    context: &CompletionContext<'_>,
+    name: &Name,
+    item: &ScopeDef,
+) -> Option<String> {
+    let callable = context.config.callable.as_ref()?;
+    let has_arguments = context
+        .token
+        .next_token()
+        .is_some_and(|token| token.kind() == SyntaxKind::ParenthesisLeft);
+    if has_arguments {
+        return None;
+    }
+    let parameters = match item {
+        ScopeDef::ModuleDefinition(ModuleDefinitionId::Function(id)) => {
+            let details = context.db.function_type(*id).lookup(context.db);
+            details
+                .parameter_names()
+                .map(ToOwned::to_owned)
+                .collect::<Vec<_>>()
+        },
+        // Builtin functions are overloaded, so their parameters are not known here.
+        ScopeDef::BuiltIn(hir_def::resolver::BuiltInKind::Function(_)) => {
+            return Some(format!("{}($0)", name.as_str()));
+        },
+        ScopeDef::BuiltIn(_)
+        | ScopeDef::ModuleDefinition(_)
+        | ScopeDef::Local(_)
+        | ScopeDef::Module => {
+            return None;
+        },
+    };
+    let snippet = match callable {
+        CallableSnippets::FillArguments if !parameters.is_empty() => {
+            let placeholders = parameters
+                .iter()
+                .enumerate()
+                .map(|(index, parameter)| format!("${{{}:{parameter}}}", index + 1))
+                .join(", ");
+            format!("{}({placeholders})$0", name.as_str())
+        },
+        CallableSnippets::FillArguments | CallableSnippets::AddParentheses => {
+            if parameters.is_empty() {
+                format!("{}()$0", name.as_str())
+            } else {
+                format!("{}($0)", name.as_str())
+            }
+        },
+    };
+    Some(snippet)
+}
+
 fn render_detail(
     context: &CompletionContext<'_>,
     name: &Name,
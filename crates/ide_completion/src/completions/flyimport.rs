@@ -0,0 +1,239 @@
+//! Completes declarations of other modules which are not imported yet.
+//!
+//! Accepting such a completion also imports the declaration, see [`ide_db::imports`].
+
+use ide_db::{
+    FxHashSet,
+    imports::{import_candidates, insert_import},
+    symbol_index::{FileSymbolKind, Query},
+    text_edit::TextEdit,
+};
+
+use super::Completions;
+use crate::{
+    context::{CompletionContext, ImmediateLocation},
+    item::{CompletionItem, CompletionItemKind, CompletionRelevance},
+    patterns::is_word,
+};
+
+pub(crate) fn import_on_the_fly(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+) -> Option<()> {
+    let _p = tracing::info_span!("import_on_the_fly").entered();
+    if !context.config.enable_imports_on_the_fly {
+        return None;
+    }
+    match context.completion_location {
+        Some(ImmediateLocation::InsideStatement | ImmediateLocation::StatementList(_)) => {},
+        _ => return None,
+    }
+    let db = context.db;
+    if !context.file_id.edition(db).at_least_wesl_0_0_1() || !is_word(&context.token) {
+        return None;
+    }
+
+    let mut names_in_scope = FxHashSet::default();
+    context.resolver.process_all_names(|name, _| {
+        names_in_scope.insert(name.as_str().to_owned());
+    });
+
+    let mut query = Query::new(context.token.text().to_owned());
+    if let Some(limit) = context.config.limit {
+        query.limit(limit);
+    }
+    for candidate in import_candidates(db, context.file_id, &query) {
+        let name = candidate.symbol.name.as_str();
+        if names_in_scope.contains(name) {
+            continue;
+        }
+        let Some(import_edit) = insert_import(&context.file, &candidate.path) else {
+            continue;
+        };
+        let mut text_edit = TextEdit::replace(context.source_range(), name.to_owned());
+        if text_edit.union(import_edit).is_err() {
+            continue;
+        }
+
+        let mut item = CompletionItem::new(
+            completion_kind(candidate.symbol.kind),
+            context.source_range(),
+            name,
+        );
+        item.add_import(candidate.import_path())
+            .text_edit(text_edit)
+            .set_relevance(CompletionRelevance {
+                requires_import: true,
+                ..CompletionRelevance::default()
+            });
+        item.add_to(accumulator, db);
+    }
+    Some(())
+}
+
+const fn completion_kind(kind: FileSymbolKind) -> CompletionItemKind {
+    match kind {
+        FileSymbolKind::Function => CompletionItemKind::Function,
+        FileSymbolKind::Struct => CompletionItemKind::Struct,
+        FileSymbolKind::GlobalVariable => CompletionItemKind::Variable,
+        FileSymbolKind::GlobalConstant | FileSymbolKind::Override => CompletionItemKind::Constant,
+        FileSymbolKind::TypeAlias => CompletionItemKind::TypeAlias,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use itertools::Itertools as _;
+
+    use crate::{
+        CompletionConfig,
+        tests::{TEST_CONFIG, check_edit, get_all_items},
+    };
+
+    /// Lists the completions which add an import.
+    #[track_caller]
+    fn check_with_config(
+        config: &CompletionConfig,
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let actual = get_all_items(config, wa_fixture, None)
+            .into_iter()
+            .filter(|item| !item.import_to_add.is_empty())
+            .map(|item| {
+                format!(
+                    "{} {} {}\n",
+                    item.kind.tag(),
+                    item.label.primary,
+                    item.label.detail_left.unwrap_or_default()
+                )
+            })
+            .sorted()
+            .collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        check_with_config(&TEST_CONFIG, wa_fixture, expect);
+    }
+
+    #[test]
+    fn completes_items_of_package_and_dependencies() {
+        check(
+            "
+            //- /main.wesl package:app dependencies:shadows edition:2026_pre
+            fn main() {
+                sample_sh$0
+            }
+            //- /lighting/shadow.wesl edition:2026_pre
+            fn sample_shadow() -> f32 { return 1.0; }
+            //- /package.wesl package:shadows library edition:2026_pre
+            fn sample_shadow_cascade() -> f32 { return 1.0; }
+            ",
+            &expect![[r#"
+                function sample_shadow (import package::lighting::shadow::sample_shadow)
+                function sample_shadow_cascade (import shadows::sample_shadow_cascade)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn skips_imported_items() {
+        check(
+            "
+            //- /main.wesl edition:2026_pre
+            import package::lighting::shade_area;
+            fn main() {
+                shade_ar$0
+            }
+            //- /lighting.wesl edition:2026_pre
+            fn shade_area() {}
+            ",
+            &expect![[""]],
+        );
+    }
+
+    #[test]
+    fn no_imports_in_wgsl() {
+        check(
+            "
+            //- /main.wgsl
+            fn main() {
+                shade_ar$0
+            }
+            //- /lighting.wgsl
+            fn shade_area() {}
+            ",
+            &expect![[""]],
+        );
+    }
+
+    #[test]
+    fn no_imports_when_disabled() {
+        check_with_config(
+            &CompletionConfig {
+                enable_imports_on_the_fly: false,
+                ..TEST_CONFIG
+            },
+            "
+            //- /main.wesl edition:2026_pre
+            fn main() {
+                shade_ar$0
+            }
+            //- /lighting.wesl edition:2026_pre
+            fn shade_area() {}
+            ",
+            &expect![[""]],
+        );
+    }
+
+    #[test]
+    fn adds_import_statement() {
+        check_edit(
+            "shade_area",
+            "
+            //- /main.wesl edition:2026_pre
+            fn main() {
+                shade_ar$0
+            }
+            //- /lighting.wesl edition:2026_pre
+            fn shade_area() {}
+            ",
+            "
+            import package::lighting::shade_area;
+
+            fn main() {
+                shade_area
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn merges_into_existing_import() {
+        check_edit(
+            "Light",
+            "
+            //- /main.wesl edition:2026_pre
+            import package::lighting::shade;
+            fn main() {
+                let light = Lig$0
+            }
+            //- /lighting.wesl edition:2026_pre
+            struct Light { intensity: f32 }
+            fn shade() {}
+            ",
+            "
+            import package::lighting::{shade, Light};
+            fn main() {
+                let light = Light
+            }
+            ",
+        );
+    }
+}
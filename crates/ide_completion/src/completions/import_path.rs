@@ -0,0 +1,126 @@
+//! Completes the segments of WESL import paths.
+
+use base_db::{Package, file_package};
+use hir_def::{
+    item_tree::{ItemTree, ModuleItemId, Name},
+    mod_path::{AbsoluteModPath, PathKind},
+    name_resolution::{ModulesMap, resolve_module},
+};
+
+use super::Completions;
+use crate::{
+    context::{CompletionContext, ImmediateLocation},
+    item::{CompletionItem, CompletionItemKind},
+};
+
+pub(crate) fn complete_import_path(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+) -> Option<()> {
+    let _p = tracing::info_span!("complete_import_path").entered();
+    let Some(ImmediateLocation::ImportPath { kind, qualifier }) = &context.completion_location
+    else {
+        return None;
+    };
+    let db = context.db;
+    let package = file_package(db, context.file_id.file_id(db))?;
+
+    let (package, module_path) = match (kind, qualifier.as_slice()) {
+        (PathKind::Plain, []) => {
+            complete_path_starts(accumulator, context, package);
+            return Some(());
+        },
+        (PathKind::Plain, [dependency_name, rest @ ..]) => {
+            let dependency = package
+                .data(db)
+                .dependencies
+                .iter()
+                .find(|dependency| dependency.name.as_str() == dependency_name.as_str())?;
+            (dependency.package(db), rest.to_vec())
+        },
+        (PathKind::Package, _) => (package, qualifier.clone()),
+        (PathKind::Super(levels), _) => {
+            let mut module_path = AbsoluteModPath::for_file(db, package, context.file_id)?;
+            for _ in 0..*levels {
+                module_path.pop_segment()?;
+            }
+            let mut segments = module_path.segments().to_vec();
+            segments.extend(qualifier.iter().cloned());
+            (package, segments)
+        },
+    };
+
+    complete_child_modules(accumulator, context, package, &module_path);
+    if let Some(module) = resolve_module(db, package, &module_path) {
+        complete_module_items(accumulator, context, &ItemTree::of(db, module));
+    }
+    Some(())
+}
+
+/// Completes the first segment of a path, which is a dependency or a relative path keyword.
+fn complete_path_starts(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    package: Package,
+) {
+    for dependency in &package.data(context.db).dependencies {
+        CompletionItem::new(
+            CompletionItemKind::Module,
+            context.source_range(),
+            dependency.name.as_str(),
+        )
+        .add_to(accumulator, context.db);
+    }
+    for keyword in ["package", "super"] {
+        let mut item =
+            CompletionItem::new(CompletionItemKind::Keyword, context.source_range(), keyword);
+        item.insert_text(format!("{keyword}::"));
+        item.add_to(accumulator, context.db);
+    }
+}
+
+/// Completes the modules directly inside of the module at `module_path`.
+fn complete_child_modules(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    package: Package,
+    module_path: &[Name],
+) {
+    let modules_map = ModulesMap::of(context.db, package);
+    for path in modules_map.modules.keys() {
+        if let [parent @ .., child] = path.segments()
+            && parent == module_path
+        {
+            CompletionItem::new(
+                CompletionItemKind::Module,
+                context.source_range(),
+                child.as_str(),
+            )
+            .add_to(accumulator, context.db);
+        }
+    }
+}
+
+/// Completes the importable declarations of a module.
+fn complete_module_items(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    item_tree: &ItemTree,
+) {
+    for item in item_tree.top_level_items() {
+        let (kind, name) = match *item {
+            ModuleItemId::Struct(id) => (CompletionItemKind::Struct, &item_tree[id].name),
+            ModuleItemId::TypeAlias(id) => (CompletionItemKind::TypeAlias, &item_tree[id].name),
+            ModuleItemId::GlobalVariable(id) => (CompletionItemKind::Variable, &item_tree[id].name),
+            ModuleItemId::GlobalConstant(id) => (CompletionItemKind::Constant, &item_tree[id].name),
+            ModuleItemId::Override(id) => (CompletionItemKind::Constant, &item_tree[id].name),
+            ModuleItemId::Function(id) => (CompletionItemKind::Function, &item_tree[id].name),
+            ModuleItemId::GlobalAssertStatement(_) | ModuleItemId::ImportStatement(_) => continue,
+        };
+        if name == &Name::missing() {
+            continue;
+        }
+        CompletionItem::new(kind, context.source_range(), name.as_str())
+            .add_to(accumulator, context.db);
+    }
+}
@@ -0,0 +1,391 @@
+//! Completes keywords and the declarations and statements they start.
+
+use syntax::AstNode as _;
+
+use super::Completions;
+use crate::{
+    context::{BlockKind, CompletionContext, ImmediateLocation, StatementContext},
+    item::{CompletionItem, CompletionItemKind},
+};
+
+pub(crate) fn complete_keywords(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+) {
+    let _p = tracing::info_span!("complete_keywords").entered();
+    match &context.completion_location {
+        Some(ImmediateLocation::ItemList) => complete_item_keywords(accumulator, context),
+        Some(ImmediateLocation::StatementList(statement)) => {
+            complete_statement_keywords(accumulator, context, *statement);
+        },
+        Some(ImmediateLocation::SwitchBody) => {
+            add_keyword(accumulator, context, "case", "case $1: {\n\t$0\n}");
+            add_keyword(accumulator, context, "default", "default: {\n\t$0\n}");
+        },
+        Some(
+            ImmediateLocation::InsideStatement
+            | ImmediateLocation::FieldAccess { .. }
+            | ImmediateLocation::Attribute(_)
+            | ImmediateLocation::BuiltinValue(_)
+            | ImmediateLocation::InterpolationType
+            | ImmediateLocation::InterpolationSampling { .. }
+            | ImmediateLocation::ImportPath { .. },
+        )
+        | None => {},
+    }
+}
+
+fn complete_item_keywords(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+) {
+    // Directives have to come before any declaration.
+    let directives_allowed = context
+        .file
+        .syntax()
+        .children()
+        .take_while(|item| item.text_range().end() <= context.source_range().start())
+        .all(|item| item.kind().is_directive());
+    if directives_allowed {
+        add_keyword(accumulator, context, "enable", "enable $0;");
+        add_keyword(accumulator, context, "requires", "requires $0;");
+        add_keyword(
+            accumulator,
+            context,
+            "diagnostic",
+            "diagnostic(${1:off}, $0);",
+        );
+    }
+    add_keyword(accumulator, context, "fn", "fn ${1:name}($2) {\n\t$0\n}");
+    add_keyword(
+        accumulator,
+        context,
+        "struct",
+        "struct ${1:Name} {\n\t$0\n}",
+    );
+    add_keyword(
+        accumulator,
+        context,
+        "var<...>",
+        "var<${1:private}> ${2:name}: $0;",
+    );
+    add_keyword(accumulator, context, "override", "override ${1:name}: $0;");
+    add_keyword(accumulator, context, "const", "const ${1:name} = $0;");
+    add_keyword(accumulator, context, "alias", "alias ${1:Name} = $0;");
+    add_keyword(accumulator, context, "const_assert", "const_assert $0;");
+}
+
+fn complete_statement_keywords(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    statement: StatementContext,
+) {
+    add_keyword(accumulator, context, "let", "let ${1:name} = $0;");
+    add_keyword(accumulator, context, "var", "var ${1:name} = $0;");
+    add_keyword(accumulator, context, "const", "const ${1:name} = $0;");
+    add_keyword(accumulator, context, "if", "if $1 {\n\t$0\n}");
+    add_keyword(
+        accumulator,
+        context,
+        "switch",
+        "switch $1 {\n\tcase $2: {\n\t\t$0\n\t}\n\tdefault: {}\n}",
+    );
+    add_keyword(accumulator, context, "loop", "loop {\n\t$0\n}");
+    add_keyword(
+        accumulator,
+        context,
+        "for",
+        "for (var ${1:i} = 0; ${1:i} < $2; ${1:i}++) {\n\t$0\n}",
+    );
+    add_keyword(accumulator, context, "while", "while $1 {\n\t$0\n}");
+    if !statement.in_continuing {
+        add_keyword(accumulator, context, "return", "return$0;");
+    }
+    add_keyword(accumulator, context, "discard", "discard;");
+    add_keyword(accumulator, context, "const_assert", "const_assert $0;");
+    if statement.in_breakable {
+        add_keyword(accumulator, context, "break", "break;");
+    }
+    if statement.in_loop {
+        add_keyword(accumulator, context, "continue", "continue;");
+    }
+    match statement.block_kind {
+        BlockKind::LoopBody => {
+            add_keyword(accumulator, context, "continuing", "continuing {\n\t$0\n}");
+        },
+        BlockKind::Continuing => {
+            add_keyword(accumulator, context, "break if", "break if $0;");
+        },
+        BlockKind::Other => {},
+    }
+}
+
+/// Adds a keyword, expanding to `snippet` if the client supports snippets.
+///
+/// The label may describe the expansion, like `var<...>`, but only the keyword is inserted
+/// without snippets.
+fn add_keyword(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+    label: &str,
+    snippet: &str,
+) {
+    let keyword = label.strip_suffix("<...>").unwrap_or(label);
+    let mut item = CompletionItem::new(CompletionItemKind::Keyword, context.source_range(), label);
+    item.lookup_by(keyword);
+    match context.config.snippet_capability {
+        Some(capability) => item.insert_snippet(capability, snippet),
+        None => item.insert_text(keyword),
+    };
+    item.add_to(accumulator, context.db);
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+
+    use crate::{
+        CompletionConfig, CompletionItemKind,
+        tests::{TEST_CONFIG, check_edit, check_edit_with_config, do_completion},
+    };
+
+    #[expect(clippy::needless_pass_by_value, reason = "Matches expect! macro")]
+    fn check(
+        wa_fixture: &str,
+        expect: Expect,
+    ) {
+        let actual: String = do_completion(wa_fixture, CompletionItemKind::Keyword)
+            .into_iter()
+            .map(|item| format!("kw {}\n", item.label.primary))
+            .collect();
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn item_keywords_before_declarations() {
+        check(
+            "
+            enable f16;
+            $0
+            ",
+            expect![[r#"
+                kw alias
+                kw const
+                kw const_assert
+                kw diagnostic
+                kw enable
+                kw fn
+                kw override
+                kw requires
+                kw struct
+                kw var<...>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_directives_after_declarations() {
+        check(
+            "
+            const a = 1;
+            $0
+            ",
+            expect![[r#"
+                kw alias
+                kw const
+                kw const_assert
+                kw fn
+                kw override
+                kw struct
+                kw var<...>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn statement_keywords() {
+        check(
+            "
+            fn main() {
+                let a = 1;
+                $0
+            }
+            ",
+            expect![[r#"
+                kw const
+                kw const_assert
+                kw discard
+                kw for
+                kw if
+                kw let
+                kw loop
+                kw return
+                kw switch
+                kw var
+                kw while
+            "#]],
+        );
+    }
+
+    #[test]
+    fn loop_keywords() {
+        check(
+            "
+            fn main() {
+                loop {
+                    $0
+                }
+            }
+            ",
+            expect![[r#"
+                kw break
+                kw const
+                kw const_assert
+                kw continue
+                kw continuing
+                kw discard
+                kw for
+                kw if
+                kw let
+                kw loop
+                kw return
+                kw switch
+                kw var
+                kw while
+            "#]],
+        );
+    }
+
+    #[test]
+    fn continuing_keywords() {
+        check(
+            "
+            fn main() {
+                loop {
+                    continuing {
+                        $0
+                    }
+                }
+            }
+            ",
+            expect![[r#"
+                kw break if
+                kw const
+                kw const_assert
+                kw discard
+                kw for
+                kw if
+                kw let
+                kw loop
+                kw switch
+                kw var
+                kw while
+            "#]],
+        );
+    }
+
+    #[test]
+    fn loop_in_continuing_keywords() {
+        check(
+            "
+            fn main() {
+                loop {
+                    continuing {
+                        loop {
+                            $0
+                        }
+                    }
+                }
+            }
+            ",
+            expect![[r#"
+                kw break
+                kw const
+                kw const_assert
+                kw continue
+                kw continuing
+                kw discard
+                kw for
+                kw if
+                kw let
+                kw loop
+                kw switch
+                kw var
+                kw while
+            "#]],
+        );
+    }
+
+    #[test]
+    fn switch_body_keywords() {
+        check(
+            "
+            fn main() {
+                switch 1 {
+                    case 1: {}
+                    $0
+                }
+            }
+            ",
+            expect![[r#"
+                kw case
+                kw default
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_keywords_in_expression() {
+        check(
+            "
+            fn main() {
+                let a = $0;
+            }
+            ",
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn keyword_snippet() {
+        check_edit(
+            "loop",
+            "
+fn main() {
+    lo$0
+}
+",
+            "\nfn main() {\n    loop {\n\t$0\n}\n}\n",
+        );
+    }
+
+    #[test]
+    fn item_keyword_snippet() {
+        check_edit(
+            "var",
+            "
+$0
+",
+            "
+var<${1:private}> ${2:name}: $0;
+",
+        );
+    }
+
+    #[test]
+    fn keyword_without_snippets() {
+        check_edit_with_config(
+            CompletionConfig {
+                snippet_capability: None,
+                ..TEST_CONFIG
+            },
+            "var",
+            "
+$0
+",
+            "
+var
+",
+        );
+    }
+}
@@ -0,0 +1,455 @@
+//! Postfix completions, like `value.let` which becomes `let $0 = value;`.
+//!
+//! Each completion replaces the receiver of the field access together with the typed name, and is
+//! only offered when the inferred type of the receiver fits.
+
+use base_db::TextRange;
+use hir_ty::ty::{ScalarType, TypeKind, VectorType};
+use ide_db::{SnippetCapability, text_edit::TextEdit};
+use syntax::{AstNode as _, SyntaxKind, ast};
+
+use super::Completions;
+use crate::{
+    context::{CompletionContext, ImmediateLocation},
+    item::{CompletionItem, CompletionItemKind},
+};
+
+pub(crate) fn complete_postfix(
+    accumulator: &mut Completions,
+    context: &CompletionContext<'_>,
+) -> Option<()> {
+    let _p = tracing::info_span!("complete_postfix").entered();
+    if !context.config.enable_postfix_completions {
+        return None;
+    }
+    let Some(ImmediateLocation::FieldAccess { expression }) = &context.completion_location else {
+        return None;
+    };
+    let capability = context.config.snippet_capability?;
+    let receiver = expression.expression()?;
+    let receiver_type = context
+        .semantics
+        .analyze(
+            context
+                .container
+                .and_then(hir::ChildContainer::as_def_with_body_id)?,
+        )
+        .type_of_expression(&receiver)?
+        .kind(context.db);
+    let receiver_type = receiver_type.unref(context.db);
+    if receiver_type.is_error() {
+        return None;
+    }
+
+    let postfix = Postfix {
+        context,
+        capability,
+        receiver: receiver.syntax().text().to_string(),
+        indentation: indentation(&receiver),
+        range: TextRange::new(
+            receiver.syntax().text_range().start(),
+            context.source_range().end(),
+        ),
+    };
+
+    if is_statement(expression) {
+        if receiver_type.is_constructable()
+            || matches!(
+                *receiver_type,
+                TypeKind::BuiltinStruct(_) | TypeKind::Pointer(_)
+            )
+        {
+            postfix.add(accumulator, "let", "let _ = expr;", "let $0 = {receiver};");
+        }
+        if receiver_type.is_constructable() || matches!(*receiver_type, TypeKind::BuiltinStruct(_))
+        {
+            postfix.add(accumulator, "var", "var _ = expr;", "var $0 = {receiver};");
+        }
+        // Only const-expressions can initialize a `const`. Literals and abstract values always
+        // are, while telling whether a concrete value is one would need const evaluation.
+        if receiver_type.is_constructable()
+            && (matches!(receiver, ast::Expression::Literal(_))
+                || receiver_type.is_abstract(context.db))
+        {
+            postfix.add(
+                accumulator,
+                "const",
+                "const _ = expr;",
+                "const $0 = {receiver};",
+            );
+        }
+        if matches!(*receiver_type, TypeKind::Scalar(ScalarType::Bool)) {
+            postfix.add(
+                accumulator,
+                "if",
+                "if expr {}",
+                "if {receiver} {\n    $0\n}",
+            );
+        }
+    }
+
+    match &*receiver_type {
+        TypeKind::Scalar(scalar) => {
+            if *scalar == ScalarType::Bool {
+                add_select(accumulator, &postfix);
+            }
+            if is_float(*scalar) {
+                postfix.add(
+                    accumulator,
+                    "saturate",
+                    "saturate(expr)",
+                    "saturate({receiver})",
+                );
+            }
+            for (conversion, detail, snippet) in [
+                (ScalarType::F32, "f32(expr)", "f32({receiver})"),
+                (ScalarType::U32, "u32(expr)", "u32({receiver})"),
+                (ScalarType::I32, "i32(expr)", "i32({receiver})"),
+            ] {
+                if conversion != *scalar {
+                    postfix.add(accumulator, conversion.name(), detail, snippet);
+                }
+            }
+        },
+        TypeKind::Vector(VectorType { component_type, .. }) => {
+            match component_type.kind(context.db) {
+                TypeKind::Scalar(ScalarType::Bool) => add_select(accumulator, &postfix),
+                TypeKind::Scalar(scalar) if is_float(scalar) => {
+                    postfix.add(
+                        accumulator,
+                        "normalize",
+                        "normalize(expr)",
+                        "normalize({receiver})",
+                    );
+                    postfix.add(accumulator, "length", "length(expr)", "length({receiver})");
+                    postfix.add(
+                        accumulator,
+                        "saturate",
+                        "saturate(expr)",
+                        "saturate({receiver})",
+                    );
+                },
+                TypeKind::Error
+                | TypeKind::Scalar(_)
+                | TypeKind::Atomic(_)
+                | TypeKind::Vector(_)
+                | TypeKind::Matrix(_)
+                | TypeKind::Struct(_)
+                | TypeKind::BuiltinStruct(_)
+                | TypeKind::Array(_)
+                | TypeKind::Texture(_)
+                | TypeKind::Sampler(_)
+                | TypeKind::Reference(_)
+                | TypeKind::Pointer(_) => {},
+            }
+        },
+        TypeKind::Pointer(_) => postfix.add(accumulator, "deref", "*expr", "*{receiver}"),
+        TypeKind::Error
+        | TypeKind::Atomic(_)
+        | TypeKind::Matrix(_)
+        | TypeKind::Struct(_)
+        | TypeKind::BuiltinStruct(_)
+        | TypeKind::Array(_)
+        | TypeKind::Texture(_)
+        | TypeKind::Sampler(_)
+        | TypeKind::Reference(_) => {},
+    }
+    Some(())
+}
+
+/// The parts shared by the postfix completions of one receiver.
+struct Postfix<'context, 'db> {
+    context: &'context CompletionContext<'db>,
+    capability: SnippetCapability,
+    receiver: String,
+    /// The whitespace at the start of the line of the receiver, which continues each line of a snippet.
+    indentation: String,
+    /// The receiver, the `.` and the typed name, which the completion replaces.
+    range: TextRange,
+}
+
+impl Postfix<'_, '_> {
+    /// Adds the completion `label`, whose `snippet` contains the receiver in place of `{receiver}`.
+    fn add(
+        &self,
+        accumulator: &mut Completions,
+        label: &str,
+        detail: &str,
+        snippet: &str,
+    ) {
+        let snippet = snippet
+            .replace("{receiver}", &self.receiver)
+            .replace('\n', &format!("\n{}", self.indentation));
+        let mut item = CompletionItem::new(
+            CompletionItemKind::Snippet,
+            self.context.source_range(),
+            label,
+        );
+        item.detail(detail)
+            .snippet_edit(self.capability, TextEdit::replace(self.range, snippet));
+        item.add_to(accumulator, self.context.db);
+    }
+}
+
+fn add_select(
+    accumulator: &mut Completions,
+    postfix: &Postfix<'_, '_>,
+) {
+    postfix.add(
+        accumulator,
+        "select",
+        "select(f, t, expr)",
+        "select(${1:if_false}, ${2:if_true}, {receiver})$0",
+    );
+}
+
+const fn is_float(scalar: ScalarType) -> bool {
+    matches!(
+        scalar,
+        ScalarType::AbstractFloat | ScalarType::F32 | ScalarType::F16
+    )
+}
+
+fn indentation(receiver: &ast::Expression) -> String {
+    receiver
+        .syntax()
+        .first_token()
+        .and_then(|token| token.prev_token())
+        .filter(|token| token.kind() == SyntaxKind::Blankspace)
+        .and_then(|token| {
+            token
+                .text()
+                .rsplit_once('\n')
+                .map(|(_, indentation)| indentation.to_owned())
+        })
+        .unwrap_or_default()
+}
+
+/// Whether the field access makes up a whole statement, like `value.let` on its own line.
+///
+/// Such an expression is parsed as the left side of an assignment without an `=`.
+fn is_statement(expression: &ast::FieldExpression) -> bool {
+    expression
+        .syntax()
+        .parent()
+        .and_then(ast::AssignmentStatement::cast)
+        .is_some_and(|statement| statement.equal_token().is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+
+    use crate::{
+        CompletionConfig, CompletionItemKind,
+        tests::{TEST_CONFIG, check_edit, do_completion_with_config},
+    };
+
+    #[track_caller]
+    fn check_with_config(
+        config: &CompletionConfig,
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        let actual = do_completion_with_config(config, wa_fixture, CompletionItemKind::Snippet)
+            .into_iter()
+            .map(|item| {
+                format!(
+                    "{} {}\n",
+                    item.label.primary,
+                    item.label.detail_right.unwrap_or_default()
+                )
+            })
+            .collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    #[track_caller]
+    fn check(
+        wa_fixture: &str,
+        expect: &Expect,
+    ) {
+        check_with_config(&TEST_CONFIG, wa_fixture, expect);
+    }
+
+    #[test]
+    fn postfix_on_bool_statement() {
+        check(
+            "
+            fn main() {
+                let enabled = true;
+                enabled.$0
+            }
+            ",
+            &expect![[r#"
+                f32 f32(expr)
+                i32 i32(expr)
+                if if expr {}
+                let let _ = expr;
+                select select(f, t, expr)
+                u32 u32(expr)
+                var var _ = expr;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn postfix_const_on_abstract_value() {
+        check(
+            "
+            const scale = 2.0;
+            fn main() {
+                scale.$0
+            }
+            ",
+            &expect![[r#"
+                const const _ = expr;
+                f32 f32(expr)
+                i32 i32(expr)
+                let let _ = expr;
+                saturate saturate(expr)
+                u32 u32(expr)
+                var var _ = expr;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn postfix_on_float_vector() {
+        check(
+            "
+            fn main() {
+                var direction = vec3<f32>(1.0, 2.0, 3.0);
+                let x = direction.$0;
+            }
+            ",
+            &expect![[r#"
+                length length(expr)
+                normalize normalize(expr)
+                saturate saturate(expr)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn postfix_on_u32() {
+        check(
+            "
+            fn main() {
+                let index = 1u;
+                let x = index.$0;
+            }
+            ",
+            &expect![[r#"
+                f32 f32(expr)
+                i32 i32(expr)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn postfix_on_pointer() {
+        check(
+            "
+            fn main() {
+                var value = 1.0;
+                let pointer = &value;
+                let x = pointer.$0;
+            }
+            ",
+            &expect![[r#"
+                deref *expr
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_postfix_on_struct_expression() {
+        check(
+            "
+            struct Light { intensity: f32 }
+            fn main() {
+                let light = Light(1.0);
+                let x = light.$0;
+            }
+            ",
+            &expect![[""]],
+        );
+    }
+
+    #[test]
+    fn no_postfix_when_disabled() {
+        check_with_config(
+            &CompletionConfig {
+                enable_postfix_completions: false,
+                ..TEST_CONFIG
+            },
+            "
+            fn main() {
+                let enabled = true;
+                enabled.$0
+            }
+            ",
+            &expect![[""]],
+        );
+    }
+
+    #[test]
+    fn postfix_let() {
+        check_edit(
+            "let",
+            "
+            fn main() {
+                let direction = normalize(vec3(1.0));
+                direction.$0
+            }
+            ",
+            "
+            fn main() {
+                let direction = normalize(vec3(1.0));
+                let $0 = direction;
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn postfix_if() {
+        check_edit(
+            "if",
+            "
+            fn main() {
+                let enabled = true;
+                enabled.i$0
+            }
+            ",
+            "
+            fn main() {
+                let enabled = true;
+                if enabled {
+                    $0
+                }
+            }
+            ",
+        );
+    }
+
+    #[test]
+    fn postfix_conversion() {
+        check_edit(
+            "f32",
+            "
+            fn main() {
+                let index = 1u;
+                let x = index.$0;
+            }
+            ",
+            "
+            fn main() {
+                let index = 1u;
+                let x = f32(index);
+            }
+            ",
+        );
+    }
+}
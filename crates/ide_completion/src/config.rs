@@ -5,10 +5,7 @@
 //! completions if we are allowed to.
 
 // use hir::ImportPathConfig;
-use ide_db::{
-    SnippetCapability,
-    // imports::insert_import::InsertImportConfig
-};
+use ide_db::SnippetCapability;
 
 use crate::{
     // snippet::Snippet,
@@ -17,10 +14,10 @@ use crate::{
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CompletionConfig {
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/913
-    // pub enable_postfix_completions: bool,
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/914
-    // pub enable_imports_on_the_fly: bool,
+    /// Whether to complete snippets after a `.`, like `value.let`.
+    pub enable_postfix_completions: bool,
+    /// Whether to complete declarations of other modules, adding an import for them.
+    pub enable_imports_on_the_fly: bool,
     // pub enable_private_editable: bool,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/915
     // pub enable_term_search: bool,
@@ -28,12 +25,9 @@ pub struct CompletionConfig {
     // pub term_search_fuel: u64,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/916
     // pub full_function_signatures: bool,
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/917
-    // pub callable: Option<CallableSnippets>,
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/920
-    // pub snippet_capability: Option<SnippetCapability>,
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/914
-    // pub insert_import: InsertImportConfig,
+    /// How to complete function calls. Only used when snippets are supported.
+    pub callable: Option<CallableSnippets>,
+    pub snippet_capability: Option<SnippetCapability>,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/922
     // pub prefer_prelude: bool,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/921
@@ -1,10 +1,24 @@
-use base_db::{EditionedFileId, FilePosition, TextRange};
-use hir::{ChildContainer, Semantics, nearest_scope};
-use hir_def::{item_scope::ItemScope, resolver::Resolver};
+use base_db::{EditionedFileId, FilePosition, Intern as _, Lookup as _, TextRange, TextSize};
+use hir::{ChildContainer, Semantics, ShaderStage, SourceAnalyzer, nearest_scope};
+use hir_def::{
+    db::DefinitionWithBodyId, item_scope::ItemScope, item_tree::Name, mod_path::PathKind,
+    resolver::Resolver,
+};
+use hir_ty::{
+    lower::ResolvedCall,
+    ty::{ScalarType, Type, TypeKind},
+};
 use ide_db::RootDatabase;
-use syntax::{AstNode as _, SyntaxKind, SyntaxToken, ast};
+use syntax::{
+    AstNode as _, HasName as _, SyntaxKind, SyntaxToken, ast, ast::operators::BinaryOperation,
+    match_ast,
+};
 
-use crate::{config::CompletionConfig, patterns::determine_location};
+use crate::{
+    config::CompletionConfig,
+    item::CompletionRelevanceTypeMatch,
+    patterns::{determine_location, is_word},
+};
 
 /// `CompletionContext` is created early during completion to figure out, where
 /// exactly is the cursor, syntax-wise.
@@ -12,12 +26,16 @@ pub(crate) struct CompletionContext<'db> {
     pub(crate) semantics: Semantics<'db>,
     pub(crate) file_id: EditionedFileId,
     pub(crate) db: &'db RootDatabase,
+    pub(crate) config: &'db CompletionConfig,
     pub(crate) position: FilePosition,
     pub(crate) token: SyntaxToken,
     pub(crate) file: ast::SourceFile,
     pub(crate) container: Option<ChildContainer>,
     pub(crate) completion_location: Option<ImmediateLocation>,
     pub(crate) resolver: Resolver<'db>,
+    /// The type which the expression being completed should have, like the declared type of the
+    /// variable it initializes. Never a reference or an error type.
+    pub(crate) expected_type: Option<Type>,
 }
 
 impl<'db> CompletionContext<'db> {
@@ -48,41 +66,253 @@ impl<'db> CompletionContext<'db> {
 
         let nearest_scope = token.parent().and_then(|node| nearest_scope(&node));
 
+        let analyzer = container
+            .and_then(ChildContainer::as_def_with_body_id)
+            .map(|definition| semantics.analyze(definition));
         if let Some(scope) = nearest_scope
-            && let Some(definition) = container
-            && let Some(definition) = definition.as_def_with_body_id()
+            && let Some(analyzer) = &analyzer
         {
-            resolver = semantics.analyze(definition).resolver_for(scope);
+            resolver = analyzer.resolver_for(scope);
         }
+        let expected_type = analyzer
+            .as_ref()
+            .and_then(|analyzer| expected_type(analyzer, &token, position.offset))
+            .map(|r#type| r#type.kind(db).unref(db).into_owned())
+            .filter(|kind| !kind.is_error())
+            .map(|kind| kind.intern(db));
 
         let context = Self {
             semantics,
             file_id,
             db,
+            config,
             position,
             token,
             file,
             container,
             completion_location,
             resolver,
+            expected_type,
         };
         Some(context)
     }
 
     pub(crate) fn source_range(&self) -> base_db::TextRange {
-        let kind = self.token.kind();
-        if kind == SyntaxKind::Identifier || kind.is_keyword() {
+        if is_word(&self.token) {
             self.token.text_range()
         } else {
             TextRange::empty(self.position.offset)
         }
     }
+
+    /// How well a completion of type `r#type` fits the [expected type](Self::expected_type).
+    pub(crate) fn type_match(
+        &self,
+        r#type: Type,
+    ) -> Option<CompletionRelevanceTypeMatch> {
+        let expected = self.expected_type?;
+        let kind = r#type.kind(self.db);
+        let kind = kind.unref(self.db);
+        if kind.is_error() {
+            None
+        } else if *kind == expected.kind(self.db) {
+            Some(CompletionRelevanceTypeMatch::Exact)
+        } else if kind.is_convertible_to(&expected.kind(self.db), self.db) {
+            Some(CompletionRelevanceTypeMatch::CouldUnify)
+        } else {
+            None
+        }
+    }
+}
+
+/// Finds the type expected at `offset` from the expression, statement or call around it.
+fn expected_type(
+    analyzer: &SourceAnalyzer<'_>,
+    token: &SyntaxToken,
+    offset: TextSize,
+) -> Option<Type> {
+    let db = analyzer.db;
+    let is_before_offset =
+        |token: Option<SyntaxToken>| token.is_some_and(|token| token.text_range().end() <= offset);
+    let bool_type = || TypeKind::Scalar(ScalarType::Bool).intern(db);
+
+    for node in token.parent_ancestors() {
+        match_ast! {
+            match node {
+                ast::InfixExpression(infix) => {
+                    if matches!(infix.op_kind(), Some(BinaryOperation::Logical(_))) {
+                        return Some(bool_type());
+                    }
+                    let left_side = infix.left_side()?;
+                    let other_side = if left_side.syntax().text_range().contains_inclusive(offset) {
+                        infix.right_side()?
+                    } else {
+                        left_side
+                    };
+                    return analyzer.type_of_expression(&other_side);
+                },
+                // Neither the indexed value nor the index has the type of the whole expression.
+                ast::IndexExpression(_index) => return None,
+                ast::FieldExpression(expression) => {
+                    if expression
+                        .expression()
+                        .is_some_and(|inner| inner.syntax().text_range().contains_inclusive(offset))
+                    {
+                        return None;
+                    }
+                },
+                ast::Arguments(arguments) => {
+                    let index = arguments
+                        .syntax()
+                        .children_with_tokens()
+                        .filter(|element| {
+                            element.kind() == SyntaxKind::Comma && element.text_range().end() <= offset
+                        })
+                        .count();
+                    let call = ast::FunctionCall::cast(arguments.syntax().parent()?)?;
+                    let call = analyzer.expression_id(&ast::Expression::FunctionCall(call))?;
+                    let Some(ResolvedCall::Function(function)) = analyzer.infer.call_resolution(call)
+                    else {
+                        return None;
+                    };
+                    return function.lookup(db).parameters().nth(index);
+                },
+                ast::AssignmentStatement(statement) => {
+                    if !is_before_offset(statement.equal_token()) {
+                        return None;
+                    }
+                    return analyzer.type_of_expression(&statement.left_side()?);
+                },
+                ast::CompoundAssignmentStatement(statement) => {
+                    if !is_before_offset(statement.operator_token()) {
+                        return None;
+                    }
+                    return analyzer.type_of_expression(&statement.left_side()?);
+                },
+                ast::VariableDeclaration(declaration) => {
+                    if declaration.r#type().is_none() || !is_before_offset(declaration.equal_token()) {
+                        return None;
+                    }
+                    return declared_type(analyzer, &declaration.name()?);
+                },
+                ast::LetDeclaration(declaration) => {
+                    if declaration.r#type().is_none() || !is_before_offset(declaration.equal_token()) {
+                        return None;
+                    }
+                    return declared_type(analyzer, &declaration.name()?);
+                },
+                ast::ConstantDeclaration(declaration) => {
+                    if declaration.r#type().is_none() || !is_before_offset(declaration.equal_token()) {
+                        return None;
+                    }
+                    return declared_type(analyzer, &declaration.name()?);
+                },
+                ast::ReturnStatement(_statement) => return Some(analyzer.infer.return_type()),
+                // The blocks of these are compound statements, so this is their condition.
+                ast::IfClause(_clause) => return Some(bool_type()),
+                ast::ElseIfClause(_clause) => return Some(bool_type()),
+                ast::WhileStatement(_statement) => return Some(bool_type()),
+                ast::BreakIfStatement(_statement) => return Some(bool_type()),
+                _ => {
+                    if ast::Statement::can_cast(node.kind())
+                        || ast::CompoundStatement::can_cast(node.kind())
+                    {
+                        return None;
+                    }
+                },
+            }
+        }
+    }
+    None
+}
+
+/// The type of a declaration with a type annotation, either in a function or at module level.
+fn declared_type(
+    analyzer: &SourceAnalyzer<'_>,
+    name: &ast::Name,
+) -> Option<Type> {
+    if matches!(analyzer.owner, DefinitionWithBodyId::Function(_)) {
+        analyzer.type_of_binding(name)
+    } else {
+        Some(analyzer.infer.return_type())
+    }
 }
 
 #[derive(Debug)]
 pub(crate) enum ImmediateLocation {
+    /// At the start of a module-level declaration or directive.
     ItemList,
-    StatementList,
+    /// At the start of a statement in a function body.
+    StatementList(StatementContext),
+    /// At the start of a clause of a `switch` statement.
+    SwitchBody,
     InsideStatement,
-    FieldAccess { expression: ast::FieldExpression },
+    FieldAccess {
+        expression: ast::FieldExpression,
+    },
+    /// The name of an attribute, right after the `@`.
+    Attribute(AttributeTarget),
+    /// The argument of `@builtin(...)`.
+    BuiltinValue(BuiltinValueContext),
+    /// The first argument of `@interpolate(...)`.
+    InterpolationType,
+    /// The second argument of `@interpolate(...)`, after the given interpolation type.
+    InterpolationSampling {
+        interpolation_type: SyntaxToken,
+    },
+    /// A segment of a WESL import path, after the segments of `qualifier`.
+    ImportPath {
+        kind: PathKind,
+        qualifier: Vec<Name>,
+    },
+}
+
+/// What the attribute being completed applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttributeTarget {
+    Function,
+    Parameter,
+    ReturnType,
+    StructMember,
+    GlobalVariable,
+    Override,
+    Statement,
+    /// A module-level declaration that has not been written yet.
+    Item,
+}
+
+/// Where the value of a `@builtin(...)` attribute flows.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BuiltinValueContext {
+    /// The stage of the entry point, if the attribute is on one of its parameters or its return type.
+    pub(crate) stage: Option<ShaderStage>,
+    /// `None` for struct members, which can be used as either.
+    pub(crate) direction: Option<BuiltinDirection>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BuiltinDirection {
+    Input,
+    Output,
+}
+
+/// The control flow constructs around the statement being completed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StatementContext {
+    pub(crate) block_kind: BlockKind,
+    /// Whether the statement is inside a loop or a switch clause, where `break` is allowed.
+    pub(crate) in_breakable: bool,
+    /// Whether the statement is inside a loop, where `continue` is allowed.
+    pub(crate) in_loop: bool,
+    /// Whether the statement is inside a `continuing` statement, where `return` is not allowed.
+    pub(crate) in_continuing: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BlockKind {
+    /// The body of a `loop`, which may end with a `continuing` statement.
+    LoopBody,
+    /// The body of a `continuing` statement, which may end with a `break if` statement.
+    Continuing,
+    Other,
 }
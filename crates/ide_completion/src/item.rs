@@ -5,7 +5,7 @@ use ide_db::{RootDatabase, SnippetCapability, text_edit::TextEdit};
 use itertools::Itertools as _;
 use smallvec::SmallVec;
 use smol_str::{SmolStr, format_smolstr};
-use stdx::never;
+use stdx::{format_to, never};
 
 /// [`CompletionItem`] describes a single completion entity which expands to 1 or more entries in the
 /// editor pop-up.
@@ -94,6 +94,7 @@ pub enum CompletionItemKind {
     Struct,
     Module,
     TypeAlias,
+    Attribute,
     Builtin(BuiltInKind),
 }
 
@@ -122,6 +123,7 @@ impl CompletionItemKind {
             Self::Struct => "struct",
             Self::Module => "module",
             Self::TypeAlias => "type alias",
+            Self::Attribute => "attribute",
             Self::Builtin(kind) => match kind {
                 BuiltInKind::Alias => "builtin alias",
                 BuiltInKind::Constructor => "builtin constructor",
@@ -367,7 +369,7 @@ impl CompletionItem {
             deprecated: false,
             trigger_call_info: false,
             // ref_match: None,
-            // imports_to_add: Default::default(),
+            imports_to_add: SmallVec::new(),
             relevance: CompletionRelevance::default(),
             // edition,
         }
@@ -450,7 +452,7 @@ impl CompletionItem {
 #[derive(Clone)]
 pub(crate) struct Builder {
     source_range: TextRange,
-    // imports_to_add: SmallVec<[LocatedImport; 1]>,
+    imports_to_add: SmallVec<[String; 1]>,
     trait_name: Option<SmolStr>,
     doc_aliases: Vec<SmolStr>,
     label: SmolStr,
@@ -556,16 +558,15 @@ impl Builder {
                 lookup = format_smolstr!("{lookup}{lookup_doc_aliases}");
             }
         }
-        // if let [import_edit] = &*self.imports_to_add {
-        //     // snippets can have multiple imports, but normal completions only have up to one
-        //     let detail_left = detail_left.get_or_insert_with(String::new);
-        //     format_to!(
-        //         detail_left,
-        //         "{}(use {})",
-        //         if detail_left.is_empty() { "" } else { " " },
-        //         import_edit.import_path.display(db, self.edition)
-        //     );
-        // } else if let Some(trait_name) = self.trait_name {
+        if let [import_path] = &*self.imports_to_add {
+            let detail_left = detail_left.get_or_insert_with(String::new);
+            format_to!(
+                detail_left,
+                "{}(import {import_path})",
+                if detail_left.is_empty() { "" } else { " " },
+            );
+        }
+        // else if let Some(trait_name) = self.trait_name {
         //     let detail_left = detail_left.get_or_insert_with(String::new);
         //     format_to!(
         //         detail_left,
@@ -579,12 +580,6 @@ impl Builder {
             None => TextEdit::replace(self.source_range, insert_text),
         };
 
-        // let import_to_add = self
-        //     .imports_to_add
-        //     .into_iter()
-        //     .map(|import| import.import_path.display(db, self.edition).to_string())
-        //     .collect();
-
         CompletionItem {
             label: CompletionItemLabel {
                 primary: label,
@@ -602,7 +597,7 @@ impl Builder {
             trigger_call_info: self.trigger_call_info,
             relevance: self.relevance,
             // ref_match: self.ref_match,
-            import_to_add: SmallVec::default(),
+            import_to_add: self.imports_to_add,
         }
     }
 
@@ -667,6 +662,17 @@ impl Builder {
         self.insert_text(snippet)
     }
 
+    /// Records that accepting the item imports `import_path`.
+    ///
+    /// The edit adding the import itself is part of [`Builder::text_edit`].
+    pub(crate) fn add_import(
+        &mut self,
+        import_path: String,
+    ) -> &mut Self {
+        self.imports_to_add.push(import_path);
+        self
+    }
+
     pub(crate) fn text_edit(
         &mut self,
         edit: TextEdit,
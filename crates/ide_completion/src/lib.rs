@@ -67,10 +67,15 @@ pub fn completions(
     let (context) = &CompletionContext::new(db, position, config, trigger_character)?;
     let mut completions = Completions::default();
 
+    completions::attribute::complete_attributes(&mut completions, context);
     completions::dot::complete_dot(&mut completions, context);
+    completions::postfix::complete_postfix(&mut completions, context);
     // TODO: make completions context-sensitive
     // https://github.com/wgsl-analyzer/wgsl-analyzer/issues/1321
     completions::expression::complete_names_in_scope(&mut completions, context);
+    completions::keyword::complete_keywords(&mut completions, context);
+    completions::import_path::complete_import_path(&mut completions, context);
+    completions::flyimport::import_on_the_fly(&mut completions, context);
 
     Some(completions.into())
 }
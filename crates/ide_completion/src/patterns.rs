@@ -1,22 +1,35 @@
 use base_db::TextSize;
-use hir::Semantics;
-use syntax::{AstNode as _, SyntaxKind, SyntaxNode, SyntaxToken, ast};
+use hir::{Semantics, ShaderStage};
+use hir_def::{item_tree::Name, mod_path::PathKind};
+use syntax::{
+    AstNode as _, Direction, HasAttributes as _, SyntaxKind, SyntaxNode, SyntaxToken, algorithms,
+    ast,
+};
 
-use crate::context::ImmediateLocation;
+use crate::context::{
+    AttributeTarget, BlockKind, BuiltinDirection, BuiltinValueContext, ImmediateLocation,
+    StatementContext,
+};
 
 pub(crate) fn determine_location(
     _sema: &Semantics<'_>,
     _file: &SyntaxNode,
-    _offset: TextSize,
+    offset: TextSize,
     token: &SyntaxToken,
 ) -> Option<ImmediateLocation> {
     let node = token.parent()?;
-    let parent = node.parent()?;
+    let parent = node.parent();
 
     if let Some(expression) = ast::FieldExpression::cast(node.clone()) {
         Some(ImmediateLocation::FieldAccess { expression })
-    } else if let Some(expression) = ast::FieldExpression::cast(parent) {
+    } else if let Some(expression) = parent.and_then(ast::FieldExpression::cast) {
         Some(ImmediateLocation::FieldAccess { expression })
+    } else if let Some(location) = determine_import_location(token) {
+        Some(location)
+    } else if let Some(location) = determine_attribute_location(token) {
+        Some(location)
+    } else if let Some(location) = determine_start_location(token, offset) {
+        Some(location)
     } else if node.kind() == SyntaxKind::SourceFile {
         Some(ImmediateLocation::ItemList)
     } else if node.ancestors().find_map(ast::Statement::cast).is_some() {
@@ -25,3 +38,309 @@ pub(crate) fn determine_location(
         None
     }
 }
+
+/// Whether `token` looks like an identifier or a keyword, which completions replace.
+///
+/// This includes the contextual keywords of attributes, like `vertex` or `perspective`.
+pub(crate) fn is_word(token: &SyntaxToken) -> bool {
+    let mut characters = token.text().chars();
+    characters
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && characters.all(|character| character.is_alphanumeric() || character == '_')
+}
+
+/// The token before the word being typed, or before the cursor.
+fn previous_token(token: &SyntaxToken) -> Option<SyntaxToken> {
+    if is_word(token) {
+        token.prev_token()
+    } else {
+        Some(token.clone())
+    }
+    .and_then(|previous| algorithms::skip_trivia_token(previous, Direction::Prev))
+}
+
+fn previous_non_trivia(token: &SyntaxToken) -> Option<SyntaxToken> {
+    algorithms::skip_trivia_token(token.prev_token()?, Direction::Prev)
+}
+
+/// Detects whether `token` is a segment of a WESL import path, and collects the segments before it.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn determine_import_location(token: &SyntaxToken) -> Option<ImmediateLocation> {
+    let mut kind = PathKind::Plain;
+    let mut qualifier = Vec::new();
+    let mut current = previous_token(token)?;
+    loop {
+        match current.kind() {
+            SyntaxKind::Import => break,
+            SyntaxKind::ColonColon => {
+                let segment = previous_non_trivia(&current)?;
+                current = match segment.kind() {
+                    SyntaxKind::Identifier => {
+                        qualifier.push(Name::from(segment.text()));
+                        previous_non_trivia(&segment)?
+                    },
+                    SyntaxKind::Package => {
+                        kind = PathKind::Package;
+                        previous_non_trivia(&segment)?
+                    },
+                    SyntaxKind::Super => {
+                        let mut levels: u8 = 1;
+                        let mut first = segment;
+                        while let Some(separator) = previous_non_trivia(&first)
+                            && separator.kind() == SyntaxKind::ColonColon
+                            && let Some(previous) = previous_non_trivia(&separator)
+                            && previous.kind() == SyntaxKind::Super
+                        {
+                            levels = levels.saturating_add(1);
+                            first = previous;
+                        }
+                        kind = PathKind::Super(levels);
+                        previous_non_trivia(&first)?
+                    },
+                    _ => return None,
+                };
+            },
+            // The prefix of an import collection comes before its `{`.
+            SyntaxKind::BraceLeft => current = previous_non_trivia(&current)?,
+            SyntaxKind::Comma => current = collection_start(&current)?,
+            _ => return None,
+        }
+    }
+    qualifier.reverse();
+    Some(ImmediateLocation::ImportPath { kind, qualifier })
+}
+
+/// Finds the `{` of the import collection containing `comma`.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn collection_start(comma: &SyntaxToken) -> Option<SyntaxToken> {
+    let mut depth: usize = 0;
+    let mut current = previous_non_trivia(comma)?;
+    loop {
+        match current.kind() {
+            SyntaxKind::BraceRight => depth += 1,
+            SyntaxKind::BraceLeft if depth == 0 => return Some(current),
+            SyntaxKind::BraceLeft => depth -= 1,
+            SyntaxKind::Import
+            | SyntaxKind::Semicolon
+            | SyntaxKind::ParenthesisLeft
+            | SyntaxKind::ParenthesisRight => return None,
+            _ => {},
+        }
+        current = previous_non_trivia(&current)?;
+    }
+}
+
+/// Detects whether `token` is the name or an argument of an attribute.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn determine_attribute_location(token: &SyntaxToken) -> Option<ImmediateLocation> {
+    if matches!(
+        token.kind(),
+        SyntaxKind::LineEndingComment | SyntaxKind::BlockComment
+    ) {
+        return None;
+    }
+    let previous = previous_token(token)?;
+    match previous.kind() {
+        SyntaxKind::AttributeOperator => {
+            Some(ImmediateLocation::Attribute(attribute_target(&previous)))
+        },
+        SyntaxKind::ParenthesisLeft | SyntaxKind::Comma => attribute_argument(&previous),
+        _ => None,
+    }
+}
+
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn attribute_argument(delimiter: &SyntaxToken) -> Option<ImmediateLocation> {
+    let (parenthesis, first_argument) = if delimiter.kind() == SyntaxKind::Comma {
+        let argument = previous_non_trivia(delimiter)?;
+        (previous_non_trivia(&argument)?, Some(argument))
+    } else {
+        (delimiter.clone(), None)
+    };
+    if parenthesis.kind() != SyntaxKind::ParenthesisLeft {
+        return None;
+    }
+    let name = previous_non_trivia(&parenthesis)?;
+    match (name.kind(), first_argument) {
+        (SyntaxKind::Builtin, None) => Some(ImmediateLocation::BuiltinValue(
+            builtin_value_context(&name),
+        )),
+        (SyntaxKind::Interpolate, None) => Some(ImmediateLocation::InterpolationType),
+        (SyntaxKind::Interpolate, Some(interpolation_type)) if is_word(&interpolation_type) => {
+            Some(ImmediateLocation::InterpolationSampling { interpolation_type })
+        },
+        _ => None,
+    }
+}
+
+/// Finds what an attribute starting with the `@` token applies to.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn attribute_target(at: &SyntaxToken) -> AttributeTarget {
+    if previous_non_trivia(at).is_some_and(|previous| previous.kind() == SyntaxKind::Arrow) {
+        return AttributeTarget::ReturnType;
+    }
+    for node in at.parent_ancestors() {
+        match node.kind() {
+            SyntaxKind::FunctionParameters => return AttributeTarget::Parameter,
+            SyntaxKind::ReturnType => return AttributeTarget::ReturnType,
+            SyntaxKind::StructBody => return AttributeTarget::StructMember,
+            SyntaxKind::CompoundStatement | SyntaxKind::SwitchBody => {
+                return AttributeTarget::Statement;
+            },
+            SyntaxKind::AttributeList => {
+                // Module-level attributes come right before their declaration.
+                return match node.next_sibling().map(|sibling| sibling.kind()) {
+                    Some(SyntaxKind::FunctionDeclaration) => AttributeTarget::Function,
+                    Some(SyntaxKind::VariableDeclaration) => AttributeTarget::GlobalVariable,
+                    Some(SyntaxKind::OverrideDeclaration) => AttributeTarget::Override,
+                    _ => AttributeTarget::Item,
+                };
+            },
+            _ => {},
+        }
+    }
+    AttributeTarget::Item
+}
+
+/// Finds the entry point and the direction of the value of a `@builtin(...)` attribute.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn builtin_value_context(name: &SyntaxToken) -> BuiltinValueContext {
+    let mut direction = None;
+    let mut stage = None;
+    for node in name.parent_ancestors() {
+        match node.kind() {
+            SyntaxKind::FunctionParameters => direction = Some(BuiltinDirection::Input),
+            SyntaxKind::ReturnType => direction = Some(BuiltinDirection::Output),
+            SyntaxKind::FunctionDeclaration => {
+                stage = ast::FunctionDeclaration::cast(node)
+                    .and_then(|function| function.attributes())
+                    .and_then(|mut attributes| {
+                        attributes.find_map(|attribute| match attribute {
+                            ast::Attribute::VertexAttribute(_) => Some(ShaderStage::Vertex),
+                            ast::Attribute::FragmentAttribute(_) => Some(ShaderStage::Fragment),
+                            ast::Attribute::ComputeAttribute(_) => Some(ShaderStage::Compute),
+                            _ => None,
+                        })
+                    });
+                break;
+            },
+            _ => {},
+        }
+    }
+    BuiltinValueContext { stage, direction }
+}
+
+/// Detects whether `token` is at the start of an item, a statement or a switch clause.
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn determine_start_location(
+    token: &SyntaxToken,
+    offset: TextSize,
+) -> Option<ImmediateLocation> {
+    if matches!(
+        token.kind(),
+        SyntaxKind::LineEndingComment | SyntaxKind::BlockComment
+    ) {
+        return None;
+    }
+    if let Some(previous) = &previous_token(token)
+        && !is_start_delimiter(previous)
+    {
+        return None;
+    }
+
+    let container = token.parent_ancestors().find(|node| {
+        matches!(
+            node.kind(),
+            SyntaxKind::SourceFile | SyntaxKind::CompoundStatement | SyntaxKind::SwitchBody
+        ) && !is_closed_before(node, offset)
+    })?;
+    match container.kind() {
+        SyntaxKind::SourceFile => Some(ImmediateLocation::ItemList),
+        SyntaxKind::SwitchBody => Some(ImmediateLocation::SwitchBody),
+        _ => Some(ImmediateLocation::StatementList(statement_context(
+            &container,
+        ))),
+    }
+}
+
+/// Whether the closing brace of `node` is before the cursor.
+fn is_closed_before(
+    node: &SyntaxNode,
+    offset: TextSize,
+) -> bool {
+    node.last_token().is_some_and(|token| {
+        token.kind() == SyntaxKind::BraceRight && token.text_range().end() <= offset
+    })
+}
+
+/// Whether an item or statement can begin right after `token`.
+fn is_start_delimiter(token: &SyntaxToken) -> bool {
+    matches!(
+        token.kind(),
+        SyntaxKind::Semicolon | SyntaxKind::BraceLeft | SyntaxKind::BraceRight
+    ) || token
+        .parent_ancestors()
+        .filter(|node| ast::Attribute::can_cast(node.kind()))
+        .any(|attribute| attribute.text_range().end() == token.text_range().end())
+}
+
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "infeasible to list all cases"
+)]
+fn statement_context(block: &SyntaxNode) -> StatementContext {
+    let block_kind = match block.parent().map(|parent| parent.kind()) {
+        Some(SyntaxKind::LoopStatement) => BlockKind::LoopBody,
+        Some(SyntaxKind::ContinuingStatement) => BlockKind::Continuing,
+        _ => BlockKind::Other,
+    };
+    let enclosing = block
+        .ancestors()
+        .take_while(|node| node.kind() != SyntaxKind::FunctionDeclaration);
+    let mut in_breakable = false;
+    let mut in_loop = false;
+    let mut in_continuing = false;
+    for node in enclosing {
+        match node.kind() {
+            SyntaxKind::LoopStatement | SyntaxKind::ForStatement | SyntaxKind::WhileStatement => {
+                in_breakable = true;
+                in_loop = true;
+            },
+            SyntaxKind::SwitchBodyCase => in_breakable = true,
+            // Only loops and switches nested in a `continuing` statement can be left early.
+            SyntaxKind::ContinuingStatement => {
+                in_continuing = true;
+                break;
+            },
+            _ => {},
+        }
+    }
+    StatementContext {
+        block_kind,
+        in_breakable,
+        in_loop,
+        in_continuing,
+    }
+}
@@ -10,6 +10,7 @@
 
 mod expression;
 mod module_items;
+mod relevance;
 mod wesl;
 
 use base_db::{EditionedFileId, FilePosition, SourceDatabase, change};
@@ -31,28 +32,16 @@ use crate::{
 const BASE_ITEMS_FIXTURE: &str = "";
 
 pub(crate) const TEST_CONFIG: CompletionConfig = CompletionConfig {
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/913
-    // enable_postfix_completions: true,
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/914
-    // enable_imports_on_the_fly: true,
+    enable_postfix_completions: true,
+    enable_imports_on_the_fly: true,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/915
     // enable_term_search: true,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/915
     // term_search_fuel: 200,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/916
     // full_function_signatures: false,
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/917
-    // callable: Some(CallableSnippets::FillArguments),
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/920
-    // snippet_cap: SnippetCap::new(true),
-    // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/914
-    // insert_import: InsertImportConfig {
-    //     granularity: ImportGranularity::Crate,
-    //     prefix_kind: PrefixKind::Plain,
-    //     enforce_granularity: true,
-    //     group: true,
-    //     skip_glob_imports: true,
-    // },
+    callable: Some(CallableSnippets::FillArguments),
+    snippet_capability: SnippetCapability::new(true),
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/922
     // prefer_prelude: true,
     // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/921
@@ -250,6 +239,40 @@ pub(crate) fn check_with_trigger_character(
     expect.assert_eq(&actual);
 }
 
+#[track_caller]
+pub(crate) fn check_edit(
+    what: &str,
+    wa_fixture_before: &str,
+    wa_fixture_after: &str,
+) {
+    check_edit_with_config(TEST_CONFIG, what, wa_fixture_before, wa_fixture_after);
+}
+
+/// Applies the completion looked up by `what` and compares the resulting text.
+#[track_caller]
+pub(crate) fn check_edit_with_config(
+    config: CompletionConfig,
+    what: &str,
+    wa_fixture_before: &str,
+    wa_fixture_after: &str,
+) {
+    let wa_fixture_after = trim_indent(wa_fixture_after);
+    let (db, position) = position(wa_fixture_before);
+    HirDatabase::zalsa_register_downcaster(&db);
+    let completions: Vec<CompletionItem> =
+        crate::completions(&db, &config, position, None).unwrap_or_default();
+    let Ok(completion) = completions
+        .iter()
+        .filter(|completion_item| completion_item.lookup() == what)
+        .exactly_one()
+    else {
+        panic!("expected exactly one completion for {what:?}, got {completions:?}");
+    };
+    let mut actual = db.file_text(position.file_id).text(&db).to_string();
+    completion.text_edit.apply(&mut actual);
+    assert_eq_text!(&wa_fixture_after, &actual);
+}
+
 pub(crate) fn get_all_items(
     config: &CompletionConfig,
     code: &str,
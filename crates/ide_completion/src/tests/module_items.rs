@@ -551,7 +551,6 @@ fn complete_variable() {
 }
 
 #[test]
-// TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/314
 fn complete_keyword() {
     check(
         "
@@ -610,6 +609,8 @@ fn complete_keyword() {
             builtin type generator bool
             builtin function ceil
             builtin function clamp
+            keyword const
+            keyword const_assert
             builtin function cos
             builtin function cosh
             builtin function countLeadingZeros
@@ -618,6 +619,7 @@ fn complete_keyword() {
             builtin function cross
             builtin function degrees
             builtin function determinant
+            keyword discard
             builtin function distance
             builtin function dot
             builtin function dot4I8Packed
@@ -642,6 +644,7 @@ fn complete_keyword() {
             builtin function firstTrailingBit
             builtin function floor
             builtin function fma
+            keyword for
             builtin function fract
             builtin function frexp
             builtin enumerant function
@@ -654,13 +657,16 @@ fn complete_keyword() {
             builtin type generator i32
             builtin constructor i64
             builtin type generator i64
+            keyword if
             builtin enumerant immediate
             builtin function insertBits
             builtin function inverseSqrt
             builtin function ldexp
             builtin function length
+            keyword let
             builtin function log
             builtin function log2
+            keyword loop
             builtin constructor mat2x2
             builtin type mat2x2
             builtin alias mat2x2f
@@ -763,6 +769,7 @@ fn complete_keyword() {
             builtin enumerant read_write
             builtin function reflect
             builtin function refract
+            keyword return
             builtin function reverseBits
             builtin enumerant rg11b10float
             builtin enumerant rg16float
@@ -825,6 +832,7 @@ fn complete_keyword() {
             builtin function subgroupShuffleUp
             builtin function subgroupShuffleXor
             builtin function subgroupXor
+            keyword switch
             builtin function tan
             builtin function tanh
             builtin enumerant task_payload
@@ -879,6 +887,7 @@ fn complete_keyword() {
             builtin function unpack4x8unorm
             builtin function unpack4xI8
             builtin function unpack4xU8
+            keyword var
             builtin constructor vec2
             builtin type vec2
             builtin alias vec2f
@@ -910,6 +919,7 @@ fn complete_keyword() {
             builtin alias vec4u
             builtin constructor vec4u
             builtin enumerant vertex_return
+            keyword while
             builtin enumerant workgroup
             builtin function workgroupBarrier
             builtin function workgroupUniformLoad
@@ -978,6 +988,8 @@ fn complete_snippet() {
             builtin type generator bool
             builtin function ceil
             builtin function clamp
+            keyword const
+            keyword const_assert
             builtin function cos
             builtin function cosh
             builtin function countLeadingZeros
@@ -986,6 +998,7 @@ fn complete_snippet() {
             builtin function cross
             builtin function degrees
             builtin function determinant
+            keyword discard
             builtin function distance
             builtin function dot
             builtin function dot4I8Packed
@@ -1010,6 +1023,7 @@ fn complete_snippet() {
             builtin function firstTrailingBit
             builtin function floor
             builtin function fma
+            keyword for
             builtin function fract
             builtin function frexp
             builtin enumerant function
@@ -1022,13 +1036,16 @@ fn complete_snippet() {
             builtin type generator i32
             builtin constructor i64
             builtin type generator i64
+            keyword if
             builtin enumerant immediate
             builtin function insertBits
             builtin function inverseSqrt
             builtin function ldexp
             builtin function length
+            keyword let
             builtin function log
             builtin function log2
+            keyword loop
             builtin constructor mat2x2
             builtin type mat2x2
             builtin alias mat2x2f
@@ -1131,6 +1148,7 @@ fn complete_snippet() {
             builtin enumerant read_write
             builtin function reflect
             builtin function refract
+            keyword return
             builtin function reverseBits
             builtin enumerant rg11b10float
             builtin enumerant rg16float
@@ -1193,6 +1211,7 @@ fn complete_snippet() {
             builtin function subgroupShuffleUp
             builtin function subgroupShuffleXor
             builtin function subgroupXor
+            keyword switch
             builtin function tan
             builtin function tanh
             builtin enumerant task_payload
@@ -1247,6 +1266,7 @@ fn complete_snippet() {
             builtin function unpack4x8unorm
             builtin function unpack4xI8
             builtin function unpack4xU8
+            keyword var
             builtin constructor vec2
             builtin type vec2
             builtin alias vec2f
@@ -1278,6 +1298,7 @@ fn complete_snippet() {
             builtin alias vec4u
             builtin constructor vec4u
             builtin enumerant vertex_return
+            keyword while
             builtin enumerant workgroup
             builtin function workgroupBarrier
             builtin function workgroupUniformLoad
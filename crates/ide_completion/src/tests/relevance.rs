@@ -0,0 +1,160 @@
+//! Tests for ranking completions by the type expected at the cursor.
+
+use expect_test::{Expect, expect};
+use itertools::Itertools as _;
+
+use crate::{
+    CompletionItemKind,
+    item::CompletionRelevanceTypeMatch,
+    tests::{TEST_CONFIG, get_all_items},
+};
+
+/// Lists the completions of declarations, most relevant first.
+#[track_caller]
+fn check_relevance(
+    wa_fixture: &str,
+    expect: &Expect,
+) {
+    let actual = get_all_items(&TEST_CONFIG, wa_fixture, None)
+        .into_iter()
+        .filter(|item| {
+            !matches!(
+                item.kind,
+                CompletionItemKind::Keyword
+                    | CompletionItemKind::Snippet
+                    | CompletionItemKind::Builtin(_)
+            )
+        })
+        .sorted_by(|left, right| {
+            right
+                .relevance
+                .score()
+                .cmp(&left.relevance.score())
+                .then_with(|| left.label.primary.cmp(&right.label.primary))
+        })
+        .map(|item| {
+            let type_match = match item.relevance.type_match {
+                Some(CompletionRelevanceTypeMatch::Exact) => " (exact)",
+                Some(CompletionRelevanceTypeMatch::CouldUnify) => " (could unify)",
+                None => "",
+            };
+            format!("{}{type_match}\n", item.label.primary)
+        })
+        .collect::<String>();
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn ranks_values_of_assigned_type() {
+    check_relevance(
+        "
+        struct Light { intensity: f32 }
+        const SCALE: f32 = 2.0;
+        var<private> offset: f32;
+        fn brightness() -> f32 { return 1.0; }
+        fn light() -> Light { return Light(1.0); }
+        fn main() {
+            let count: u32 = 1u;
+            var factor: f32 = 1.0;
+            factor = b$0;
+        }
+        ",
+        &expect![[r#"
+            factor (exact)
+            SCALE (exact)
+            brightness (exact)
+            offset (exact)
+            count
+            Light
+            light
+            main
+        "#]],
+    );
+}
+
+#[test]
+fn ranks_values_of_parameter_type() {
+    check_relevance(
+        "
+        const LIMIT = 4;
+        fn scale(value: f32, count: u32) -> f32 { return value; }
+        fn main() {
+            let index: u32 = 1u;
+            let amount: f32 = 1.0;
+            scale(amount, i$0);
+        }
+        ",
+        &expect![[r#"
+            index (exact)
+            LIMIT (could unify)
+            amount
+            main
+            scale
+        "#]],
+    );
+}
+
+#[test]
+fn ranks_conditions() {
+    check_relevance(
+        "
+        fn main() {
+            let enabled = true;
+            let count = 1u;
+            if e$0 {
+            }
+        }
+        ",
+        &expect![[r#"
+            enabled (exact)
+            count
+            main
+        "#]],
+    );
+}
+
+#[test]
+fn ranks_fields_of_declared_type() {
+    check_relevance(
+        "
+        struct Light { intensity: f32, color: vec3<f32> }
+        fn main() {
+            let light = Light(1.0, vec3(1.0));
+            let brightness: f32 = light.$0;
+        }
+        ",
+        &expect![[r#"
+            intensity (exact)
+            color
+        "#]],
+    );
+}
+
+#[test]
+fn ranks_builtin_functions_by_return_type() {
+    let items = get_all_items(
+        &TEST_CONFIG,
+        "
+        fn main() {
+            let direction: vec3<f32> = n$0;
+        }
+        ",
+        None,
+    );
+    let type_match = |name: &str| {
+        items
+            .iter()
+            .find(|item| item.label.primary == name)
+            .and_then(|item| item.relevance.type_match)
+    };
+    assert_eq!(
+        type_match("normalize"),
+        Some(CompletionRelevanceTypeMatch::Exact)
+    );
+    assert_eq!(
+        type_match("cross"),
+        Some(CompletionRelevanceTypeMatch::Exact)
+    );
+    assert_eq!(type_match("length"), None);
+    assert_eq!(type_match("dot"), None);
+}
@@ -1,34 +1,103 @@
 //! Test for completions that are WESL-specific.
 
+use expect_test::expect;
+
 use crate::tests::completion_list;
 
 #[test]
-// TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/1323
 fn complete_package() {
-    assert_eq!(
-        completion_list(
-            "
+    expect![[r#"
+        module other_package
+        keyword package
+        keyword super
+    "#]]
+    .assert_eq(&completion_list(
+        "
             //- /shaders.wesl package:my_package dependencies:other_package edition:2026_pre
             import $0;
             //- /shaders.wesl package:other_package edition:2026_pre
             ",
-        ),
-        String::new(),
-    );
+    ));
 }
 
 #[test]
-// TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/1323
 fn complete_module() {
-    assert_eq!(
-        completion_list(
-            "
+    expect![[r#"
+        module shaders
+    "#]]
+    .assert_eq(&completion_list(
+        "
             //- /shaders.wesl edition:2026_pre
             import package::$0;
             //- /shaders/foo.wesl
             alias Foo = u32;
             ",
-        ),
-        String::new(),
-    );
+    ));
+}
+
+#[test]
+fn complete_nested_module() {
+    expect![[r#"
+        module foo
+    "#]]
+    .assert_eq(&completion_list(
+        "
+            //- /main.wesl edition:2026_pre
+            import package::shaders::$0;
+            //- /shaders/foo.wesl
+            alias Foo = u32;
+            ",
+    ));
+}
+
+#[test]
+fn complete_super_module() {
+    expect![[r#"
+        module point
+        module spot
+    "#]]
+    .assert_eq(&completion_list(
+        "
+            //- /main.wesl edition:2026_pre
+            //- /lights/point.wesl
+            import super::$0;
+            //- /lights/spot.wesl
+            ",
+    ));
+}
+
+#[test]
+fn complete_dependency_module() {
+    expect![[r#"
+        module lighting
+    "#]]
+    .assert_eq(&completion_list(
+        "
+            //- /main.wesl package:my_package dependencies:other_package edition:2026_pre
+            import other_package::$0;
+            //- /other/lighting.wesl package:other_package edition:2026_pre
+            fn shade() {}
+            ",
+    ));
+}
+
+#[test]
+fn complete_items_in_collection() {
+    expect![[r#"
+        struct Light
+        module area
+        constant intensity
+        function shade
+    "#]]
+    .assert_eq(&completion_list(
+        "
+            //- /main.wesl edition:2026_pre
+            import package::lighting::{shade, $0};
+            //- /lighting.wesl
+            struct Light {}
+            const intensity = 1.0;
+            fn shade() {}
+            //- /lighting/area.wesl
+            ",
+    ));
 }
@@ -248,6 +248,22 @@ pub fn lex(
     })
 }
 
+/// Lexes `source` and returns the kind of its token, if it consists of exactly one token.
+///
+/// Reserved words lex as [`SyntaxKind::Reserved`], unknown characters as [`SyntaxKind::Error`].
+#[must_use]
+pub fn single_token(
+    source: &str,
+    edition: Edition,
+) -> Option<SyntaxKind> {
+    let mut diagnostics = Vec::new();
+    let (tokens, _) = lex(source, edition, &mut diagnostics);
+    match tokens.as_slice() {
+        [token] => Some(*token),
+        _ => None,
+    }
+}
+
 struct WgslLexer<'source, 'diagnostics> {
     inner: logos::Lexer<'source, Token>,
     edition: Edition,
@@ -566,7 +582,8 @@ mod tests {
 
     use expect_test::expect;
 
-    use super::{Token, lex};
+    use super::{Token, lex, single_token};
+    use crate::SyntaxKind;
 
     #[expect(clippy::needless_pass_by_value, reason = "intended API")]
     fn check_lex(
@@ -609,6 +626,24 @@ mod tests {
         expect.assert_eq(&tokens_with_spans);
     }
 
+    #[test]
+    fn lex_single_token() {
+        assert_eq!(
+            single_token("light", edition::Edition::Wgsl),
+            Some(SyntaxKind::Identifier)
+        );
+        assert_eq!(
+            single_token("fn", edition::Edition::Wgsl),
+            Some(SyntaxKind::Fn)
+        );
+        assert_eq!(
+            single_token("class", edition::Edition::Wgsl),
+            Some(SyntaxKind::Reserved)
+        );
+        assert_eq!(single_token("a b", edition::Edition::Wgsl), None);
+        assert_eq!(single_token("", edition::Edition::Wgsl), None);
+    }
+
     #[test]
     fn lex_decimal_float() {
         check_lex("10.0", expect![["[FloatLiteral]"]]);
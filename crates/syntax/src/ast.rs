@@ -11,7 +11,8 @@ pub use trivia::{Comment, Whitespace};
 
 use self::operators::{AssignmentOperator, BinaryOperation, UnaryOperator};
 use crate::{
-    AstChildren, AstNode, AstToken, HasAttributes, HasName, HasTemplateParameters, TokenText,
+    AstChildren, AstNode, AstToken, ExtensionsConfig, HasAttributes, HasName,
+    HasTemplateParameters, TokenText,
     ast::operators::{ArithmeticOperation, ComparisonOperation, LogicOperation},
     support,
 };
@@ -298,6 +299,8 @@ ast_node! {
     items: AstChildren<Item>;
 }
 
+impl HasAttributes for GlobalCompoundDeclaration {}
+
 ast_node! {
     StructDeclaration:
     struct_token: Option<SyntaxToken Struct>;
@@ -559,6 +562,89 @@ pub enum LanguageExtension {
     BufferView,
 }
 
+impl SourceFile {
+    /// The extensions enabled by the `enable` and `requires` directives of this file.
+    #[must_use]
+    pub fn extensions(&self) -> ExtensionsConfig {
+        let mut extensions = ExtensionsConfig::default();
+        for directive in self.directives() {
+            match directive {
+                Directive::EnableDirective(enable) => {
+                    for extension in enable
+                        .enable_extensions()
+                        .filter_map(|name| name.extension().ok())
+                    {
+                        extension.enable(&mut extensions);
+                    }
+                },
+                Directive::RequiresDirective(requires) => {
+                    for extension in requires
+                        .require_extensions()
+                        .filter_map(|name| name.extension().ok())
+                    {
+                        extension.enable(&mut extensions);
+                    }
+                },
+                Directive::DiagnosticDirective(_) => {},
+            }
+        }
+        extensions
+    }
+}
+
+impl EnableExtension {
+    /// Turns on the corresponding flag of `extensions`.
+    pub const fn enable(
+        self,
+        extensions: &mut ExtensionsConfig,
+    ) {
+        let flag = match self {
+            Self::F16 => &mut extensions.f16,
+            Self::ClipDistances => &mut extensions.clip_distances,
+            Self::DualSourceBlending => &mut extensions.dual_source_blending,
+            Self::Subgroups => &mut extensions.subgroups,
+            Self::PrimitiveIndex => &mut extensions.primitive_index,
+            Self::SubgroupSizeControl => &mut extensions.subgroup_size_control,
+            Self::WgpuMeshShader => &mut extensions.wgpu_mesh_shader,
+            Self::WgpuRayQuery => &mut extensions.wgpu_ray_query,
+            Self::WgpuRayQueryVertexReturn => &mut extensions.wgpu_ray_query_vertex_return,
+            Self::WgpuRayTracingPipelines => &mut extensions.wgpu_ray_tracing_pipelines,
+            Self::WgpuInt16 => &mut extensions.wgpu_int16,
+            Self::WgpuCooperativeMatrix => &mut extensions.wgpu_cooperative_matrix,
+            Self::PerVertex => &mut extensions.per_vertex,
+            Self::DrawIndex => &mut extensions.draw_index,
+            Self::WgpuBindingArray => &mut extensions.wgpu_binding_array,
+        };
+        *flag = true;
+    }
+}
+
+impl LanguageExtension {
+    /// Turns on the corresponding flag of `extensions`.
+    pub const fn enable(
+        self,
+        extensions: &mut ExtensionsConfig,
+    ) {
+        let flag = match self {
+            Self::ReadonlyAndReadwriteStorageTextures => {
+                &mut extensions.readonly_and_readwrite_storage_textures
+            },
+            Self::Packed4x8IntegerDotProduct => &mut extensions.packed_4x8_integer_dot_product,
+            Self::UnrestrictedPointerParameters => &mut extensions.unrestricted_pointer_parameters,
+            Self::PointerCompositeAccess => &mut extensions.pointer_composite_access,
+            Self::UniformBufferStandardLayout => &mut extensions.uniform_buffer_standard_layout,
+            Self::SubgroupId => &mut extensions.subgroup_id,
+            Self::SubgroupUniformity => &mut extensions.subgroup_uniformity,
+            Self::TextureAndSamplerLet => &mut extensions.texture_and_sampler_let,
+            Self::TextureFormatsTier1 => &mut extensions.texture_formats_tier1,
+            Self::LinearIndexing => &mut extensions.linear_indexing,
+            Self::ImmediateAddressSpace => &mut extensions.immediate_address_space,
+            Self::BufferView => &mut extensions.buffer_view,
+        };
+        *flag = true;
+    }
+}
+
 impl HasAttributes for Directive {}
 
 impl HasAttributes for DiagnosticDirective {}
@@ -9,7 +9,7 @@ use std::{marker::PhantomData, ops::Deref};
 use either::Either;
 pub use parser::{
     Capabilities, Diagnostic, Edition, ExtensionsConfig, ParseEntryPoint, SyntaxElement,
-    SyntaxKind, SyntaxNode, SyntaxNodeChildren, SyntaxToken,
+    SyntaxKind, SyntaxNode, SyntaxNodeChildren, SyntaxToken, single_token,
 };
 pub use rowan::Direction;
 use smol_str::SmolStr;
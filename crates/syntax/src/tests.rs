@@ -3,7 +3,7 @@
 use std::string;
 
 use expect_test::{Expect, expect};
-use parser::Edition;
+use parser::{Edition, ExtensionsConfig};
 
 use crate::{
     AstNode, HasAttributes as _, HasName as _, Parse,
@@ -505,6 +505,27 @@ fn language_extension_names() {
     assert_eq!(map, items);
 }
 
+#[test]
+fn source_file_extensions() {
+    let parsed = check_errors(
+        "
+        enable f16, primitive_index;
+        requires linear_indexing;
+        ",
+        expect![""],
+    );
+    let extensions = parsed.tree().extensions();
+    assert_eq!(
+        extensions,
+        ExtensionsConfig {
+            f16: true,
+            primitive_index: true,
+            linear_indexing: true,
+            ..ExtensionsConfig::default()
+        }
+    );
+}
+
 #[test]
 fn operator_fun() {
     let parsed = check_errors(
@@ -10,7 +10,7 @@ pub mod flags;
 // mod rustc_tests;
 // mod scip;
 // mod ssr;
-// mod symbols;
+mod symbols;
 // mod unresolved_references;
 
 // mod progress_report;
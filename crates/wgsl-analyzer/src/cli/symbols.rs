@@ -0,0 +1,30 @@
+//! Read WGSL or WESL code on stdin, print the file structure on stdout.
+
+use std::iter;
+
+use ide::Analysis;
+
+use crate::cli::{flags, read_stdin};
+
+impl flags::Symbols {
+    #[expect(
+        clippy::print_stdout,
+        clippy::use_debug,
+        reason = "CLI feature, the kind names are meant to be shown"
+    )]
+    pub fn run(self) -> anyhow::Result<()> {
+        let text = read_stdin()?;
+        let (analysis, file_id) = Analysis::from_single_file(text);
+        let structure = analysis.file_structure(file_id)?;
+        for node in &structure {
+            let depth = iter::successors(node.parent, |&parent| structure[parent].parent).count();
+            let indent = "  ".repeat(depth);
+            let kind = node.kind;
+            match &node.detail {
+                Some(detail) => println!("{indent}{kind:?} {}: {detail}", node.label),
+                None => println!("{indent}{kind:?} {}", node.label),
+            }
+        }
+        Ok(())
+    }
+}
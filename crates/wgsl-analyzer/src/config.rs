@@ -3,11 +3,10 @@ use std::{fmt, sync::OnceLock};
 use base_db::{Capabilities, input::SourceRootId};
 use hir_ty::ty::pretty::TypeVerbosity;
 use ide::{
-    HoverConfig, HoverDocFormat, MemoryLayoutHoverRenderKind,
+    AssistConfig, HoverConfig, HoverDocFormat, MemoryLayoutHoverRenderKind,
     inlay_hints::{self, StructLayoutHints},
 };
 use ide::{
-    // AssistConfig,
     // CallHierarchyConfig,
     // CallableSnippets,
     // CompletionConfig,
@@ -28,7 +27,7 @@ use ide::{
     // SnippetScope,
     // SourceRootId,
 };
-use ide_completion::{CompletionConfig, CompletionFieldsToResolve};
+use ide_completion::{CallableSnippets, CompletionConfig, CompletionFieldsToResolve};
 use ide_db::SnippetCapability;
 use ide_diagnostics::{DiagnosticsConfig, NagaVersion};
 use itertools::Itertools as _;
@@ -82,12 +81,27 @@ config_data! {
         /// Whether to enable u64 and i64 scalar types.
         extensions_shaderInt64: bool = true,
 
+        /// Whether to show documentation on hover.
+        hover_documentation_enable: bool = true,
+        /// How to render the alignment information in a memory layout hover.
+        hover_memoryLayout_alignment: Option<MemoryLayoutHoverRenderKindDef> = Some(MemoryLayoutHoverRenderKindDef::Hexadecimal),
+        /// Whether to show memory layout data on hover.
+        hover_memoryLayout_enable: bool = true,
+        /// How to render the offset information in a memory layout hover.
+        hover_memoryLayout_offset: Option<MemoryLayoutHoverRenderKindDef> = Some(MemoryLayoutHoverRenderKindDef::Hexadecimal),
+        /// How to render the size information in a memory layout hover.
+        hover_memoryLayout_size: Option<MemoryLayoutHoverRenderKindDef> = Some(MemoryLayoutHoverRenderKindDef::Both),
+
+        /// Whether to show inlay hints for the concrete types which abstract numeric values are converted to.
+        inlayHints_conversionHints: bool = false,
         /// Whether to show inlay hints.
         inlayHints_enabled: bool = true,
         /// Whether to show inlay hints for the names of function parameters.
         inlayHints_parameterHints: bool = true,
         /// Whether to render leading colons for type hints, and trailing colons for parameter hints.
         inlayHints_renderColons: bool = true,
+        /// Which details of the layout of struct fields to show in struct layout hints.
+        inlayHints_structLayoutDetail: InlayHintsStructLayoutDetail = InlayHintsStructLayoutDetail::default(),
         /// Whether to show inlay hints for the layout of struct fields.
         inlayHints_structLayoutHints: bool = false,
         /// Whether to show inlay hints for types of variable declarations.
@@ -139,6 +153,22 @@ pub enum InlayHintsTypeVerbosity {
     Inner,
 }
 
+#[derive(
+    Clone, Copy, Debug, Serialize, Deserialize, Default, Hash, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum InlayHintsStructLayoutDetail {
+    /// The offset of each field.
+    #[default]
+    Offset,
+    /// The offset, size and alignment of each field.
+    SizeAndAlignment,
+    /// The offset of each field, and the padding between fields and at the end of the struct.
+    Padding,
+    /// The offset, size and alignment of each field, and the padding.
+    Full,
+}
+
 #[derive(
     Clone, Copy, Debug, Serialize, Deserialize, Default, Hash, PartialEq, Eq, PartialOrd, Ord,
 )]
@@ -181,7 +211,6 @@ pub struct Config {
     detached_files: Vec<AbsPathBuf>,
     wgslfmt_override_command: Option<Vec<String>>,
     wgslfmt_extra_args: Vec<String>,
-    wgslfmt_range_formatting_enable: bool,
 }
 
 impl Config {
@@ -288,7 +317,6 @@ impl Config {
             // watoml_file: Default::default(),
             wgslfmt_override_command: None,
             wgslfmt_extra_args: vec![],
-            wgslfmt_range_formatting_enable: false,
         }
     }
 
@@ -439,6 +467,18 @@ impl Config {
         }
     }
 
+    #[must_use]
+    #[expect(
+        clippy::unused_self,
+        reason = "Intended to be refactored into config macro"
+    )]
+    pub const fn assist(
+        &self,
+        source_root: Option<SourceRootId>,
+    ) -> AssistConfig {
+        AssistConfig { allowed: None }
+    }
+
     #[must_use]
     pub fn completion(
         &self,
@@ -446,10 +486,8 @@ impl Config {
     ) -> CompletionConfig {
         let client_capability_fields = self.completion_resolve_support_properties();
         CompletionConfig {
-            // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/913
-            // enable_postfix_completions: false,
-            // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/914
-            // enable_imports_on_the_fly: false,
+            enable_postfix_completions: true,
+            enable_imports_on_the_fly: true,
             // enable_private_editable: false,
             // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/915
             // enable_term_search: false,
@@ -457,12 +495,8 @@ impl Config {
             // term_search_fuel: 400,
             // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/916
             // full_function_signatures: false,
-            // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/917
-            // callable: None,
-            // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/920
-            // snippet_capability: SnippetCapability::new(self.completion_snippet()),
-            // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/914
-            // insert_use: InsertUseConfig,
+            callable: Some(CallableSnippets::FillArguments),
+            snippet_capability: SnippetCapability::new(self.completion_snippet()),
             // TODO: https://github.com/wgsl-analyzer/wgsl-analyzer/issues/922
             // prefer_prelude: false,
             // snippets: Vec<Snippet>,
@@ -497,10 +531,6 @@ impl Config {
     }
 
     #[must_use]
-    #[expect(
-        clippy::unused_self,
-        reason = "TODO: See https://github.com/wgsl-analyzer/wgsl-analyzer/issues/362"
-    )]
     pub fn hover(&self) -> HoverConfig {
         let mem_kind = |kind| match kind {
             MemoryLayoutHoverRenderKindDef::Both => MemoryLayoutHoverRenderKind::Both,
@@ -509,10 +539,18 @@ impl Config {
         };
         HoverConfig {
             links_in_hover: false,
-            memory_layout: None,
-            documentation: false,
+            memory_layout: self
+                .hover_memoryLayout_enable()
+                .then_some(MemoryLayoutHoverConfig {
+                    size: self.hover_memoryLayout_size().map(mem_kind),
+                    offset: self.hover_memoryLayout_offset().map(mem_kind),
+                    alignment: self.hover_memoryLayout_alignment().map(mem_kind),
+                    padding: None,
+                    niches: false,
+                }),
+            documentation: *self.hover_documentation_enable(),
             format: {
-                if false {
+                if self.client_capabilities.hover_markdown_support() {
                     HoverDocFormat::Markdown
                 } else {
                     HoverDocFormat::PlainText
@@ -533,9 +571,17 @@ impl Config {
             enabled: *self.inlayHints_enabled(),
             type_hints: *self.inlayHints_typeHints(),
             parameter_hints: *self.inlayHints_parameterHints(),
-            struct_layout_hints: self
-                .inlayHints_structLayoutHints()
-                .then_some(StructLayoutHints::Offset),
+            conversion_hints: *self.inlayHints_conversionHints(),
+            struct_layout_hints: self.inlayHints_structLayoutHints().then_some(
+                match self.inlayHints_structLayoutDetail() {
+                    InlayHintsStructLayoutDetail::Offset => StructLayoutHints::Offset,
+                    InlayHintsStructLayoutDetail::SizeAndAlignment => {
+                        StructLayoutHints::SizeAndAlignment
+                    },
+                    InlayHintsStructLayoutDetail::Padding => StructLayoutHints::Padding,
+                    InlayHintsStructLayoutDetail::Full => StructLayoutHints::Full,
+                },
+            ),
             type_verbosity: match self.inlayHints_typeVerbosity() {
                 InlayHintsTypeVerbosity::Full => TypeVerbosity::Full,
                 InlayHintsTypeVerbosity::Compact => TypeVerbosity::Compact,
@@ -577,7 +623,6 @@ impl Config {
             },
             Some(_) | None => WgslfmtConfig::Wgslfmt {
                 extra_arguments: self.wgslfmt_extra_args.clone(),
-                enable_range_formatting: self.wgslfmt_range_formatting_enable,
             },
         }
     }
@@ -615,7 +660,7 @@ impl Config {
         reason = "Intended to be refactored into config macro"
     )]
     pub fn typing_trigger_chars(&self) -> Option<String> {
-        Some("=.".to_owned())
+        Some("=.<}".to_owned())
     }
 }
 
@@ -671,7 +716,6 @@ impl ConfigChange {
 pub enum WgslfmtConfig {
     Wgslfmt {
         extra_arguments: Vec<String>,
-        enable_range_formatting: bool,
     },
     CustomCommand {
         command: String,
@@ -979,6 +1023,32 @@ fn field_props(
                 "`f32`"
             ]
         },
+        "InlayHintsStructLayoutDetail" => set! {
+            "type": "string",
+            "enum": ["offset", "sizeAndAlignment", "padding", "full"],
+            "enumDescriptions": [
+                "Show the offset of each field.",
+                "Show the offset, size and alignment of each field.",
+                "Show the offset of each field, and the padding between fields and at the end of the struct.",
+                "Show the offset, size and alignment of each field, and the padding."
+            ]
+        },
+        "Option<MemoryLayoutHoverRenderKindDef>" => set! {
+            "anyOf": [
+                {
+                    "type": "null"
+                },
+                {
+                    "type": "string",
+                    "enum": ["both", "decimal", "hexadecimal"],
+                    "enumDescriptions": [
+                        "Render as 12 (0xC)",
+                        "Render as 12",
+                        "Render as 0xC"
+                    ],
+                },
+            ],
+        },
         "TraceServer" => set! {
             "type": "string",
             "enum": ["off", "messages", "verbose"],
@@ -3,6 +3,7 @@
 use std::{fmt, panic, thread};
 
 use base_db::DbPanicContext;
+use crossbeam_channel::Sender;
 use fmt::Debug;
 use lsp_server::{
     ErrorCode, ExtractError, Notification as ServerNotification, Request as ServerRequest,
@@ -171,6 +172,65 @@ impl<'global_state> RequestDispatcher<'global_state> {
         )
     }
 
+    /// Dispatches a long-running request onto the thread pool, handing it a sender through which
+    /// it can report its progress to the main loop. When the VFS is marked not ready this will
+    /// return a default constructed [`R::Result`].
+    pub(crate) fn on_with_progress<Request>(
+        &mut self,
+        function: fn(
+            GlobalStateSnapshot,
+            Request::Params,
+            &Sender<Task>,
+        ) -> anyhow::Result<Request::Result>,
+        on_cancelled: fn() -> ResponseError,
+    ) -> &mut Self
+    where
+        Request: LspRequest<
+                Params: DeserializeOwned + panic::UnwindSafe + Send + fmt::Debug,
+                Result: Serialize + Default,
+            > + 'static,
+    {
+        if !self.global_state.vfs_done {
+            if let Some(ServerRequest { id, .. }) = self
+                .request
+                .take_if(|request| request.method.as_str() == Request::METHOD.as_str())
+            {
+                self.global_state
+                    .respond(Response::new_ok(id, Request::Result::default()));
+            }
+            return self;
+        }
+        let Some((request, parameters, panic_context)) = self.parse::<Request>() else {
+            return self;
+        };
+        let _guard =
+            tracing::info_span!("request", method = ?request.method, "request_id" = ?request.id)
+                .entered();
+        tracing::debug!(?parameters);
+
+        let world = self.global_state.snapshot();
+        self.global_state
+            .task_pool
+            .handle
+            .spawn_with_sender(ThreadIntent::Worker, move |sender| {
+                let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                    let _pctx = DbPanicContext::enter(panic_context);
+                    function(world, parameters, &sender)
+                }));
+                let response =
+                    match thread_result_to_response::<Request>(request.id.clone(), result) {
+                        Ok(response) => response,
+                        Err(_cancelled) => Response {
+                            id: request.id,
+                            response_result: Err(on_cancelled()),
+                        },
+                    };
+                sender.send(Task::Response(response)).unwrap();
+            });
+
+        self
+    }
+
     /// Formatting requests should never block on waiting a for task thread to open up, editors will wait
     /// on the response and a late formatting update might mess with the document and user.
     /// We can't run this on the main thread though as we invoke rustfmt which may take arbitrary time to complete!
@@ -412,3 +472,99 @@ impl NotificationDispatcher<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crossbeam_channel::{Sender, unbounded};
+    use lsp_server::{Message, Request as ServerRequest, RequestId, ResponseError};
+    use lsp_types::{
+        ClientCapabilities, Request as _, WorkspaceDiagnosticReport, WorkspaceDiagnosticRequest,
+    };
+    use vfs::AbsPathBuf;
+
+    use super::RequestDispatcher;
+    use crate::{
+        config::Config,
+        global_state::GlobalState,
+        handlers,
+        main_loop::{Task, WorkspaceDiagnosticsProgress},
+    };
+
+    fn global_state(sender: Sender<Message>) -> GlobalState {
+        let root = AbsPathBuf::try_from(env!("CARGO_MANIFEST_DIR")).unwrap();
+        let config = Config::new(
+            root.clone(),
+            ClientCapabilities::default(),
+            vec![root],
+            None,
+        );
+        GlobalState::new(sender, config)
+    }
+
+    fn cancelled() -> ResponseError {
+        ResponseError {
+            code: 0,
+            message: "cancelled".to_owned(),
+            data: None,
+        }
+    }
+
+    fn dispatch_workspace_diagnostics(state: &mut GlobalState) {
+        let request = ServerRequest::new(
+            RequestId::from(1),
+            WorkspaceDiagnosticRequest::METHOD.as_str().to_owned(),
+            serde_json::json!({ "previousResultIds": [] }),
+        );
+        state.register_request(&request, Instant::now());
+        RequestDispatcher::new(Some(request), state)
+            .on_with_progress::<WorkspaceDiagnosticRequest>(
+                handlers::request::handle_workspace_diagnostics,
+                cancelled,
+            );
+    }
+
+    fn empty_report() -> serde_json::Value {
+        serde_json::to_value(WorkspaceDiagnosticReport::default()).unwrap()
+    }
+
+    #[test]
+    fn on_with_progress_responds_with_default_while_vfs_loads() {
+        let (sender, receiver) = unbounded();
+        let mut state = global_state(sender);
+        state.vfs_done = false;
+        dispatch_workspace_diagnostics(&mut state);
+
+        let response = receiver.try_iter().find_map(|message| match message {
+            Message::Response(response) => Some(response),
+            Message::Request(_) | Message::Notification(_) => None,
+        });
+        assert_eq!(
+            response.and_then(|response| response.response_result.ok()),
+            Some(empty_report())
+        );
+    }
+
+    #[test]
+    fn on_with_progress_reports_progress_before_responding() {
+        let (sender, _receiver) = unbounded();
+        let mut state = global_state(sender);
+        state.vfs_done = true;
+        dispatch_workspace_diagnostics(&mut state);
+
+        let tasks = &state.task_pool.receiver;
+        let Ok(Task::WorkspaceDiagnostics(WorkspaceDiagnosticsProgress::Begin { total: 0 })) =
+            tasks.recv()
+        else {
+            panic!("expected the check to begin");
+        };
+        let Ok(Task::WorkspaceDiagnostics(WorkspaceDiagnosticsProgress::End)) = tasks.recv() else {
+            panic!("expected the check to end");
+        };
+        let Ok(Task::Response(response)) = tasks.recv() else {
+            panic!("expected a response");
+        };
+        assert_eq!(response.response_result.ok(), Some(empty_report()));
+    }
+}
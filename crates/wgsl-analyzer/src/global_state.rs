@@ -10,15 +10,17 @@ use ide::{Analysis, AnalysisHost, Cancellable};
 use lsp_server::{Notification as ServerNotification, Request as ServerRequest};
 use lsp_types::{
     Diagnostic, MarkupContent, Notification as LspNotification, PublishDiagnosticsNotification,
-    PublishDiagnosticsParams, Request as LspRequest, Uri,
+    PublishDiagnosticsParams, Request as LspRequest, SemanticTokens, Uri,
 };
-use parking_lot::{MappedRwLockReadGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use parking_lot::{MappedRwLockReadGuard, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use project_model::{ManifestPath, PackageChange, PackageGraph, PackageKey, WeslPackageRoot};
 use rustc_hash::FxHashMap;
 use salsa::Revision;
 use tracing::Level;
 use triomphe::Arc;
-use vfs::{AbsPathBuf, Change as VfsChange, FileExcluded, FileId, Vfs, VfsPath, loader::Handle};
+use vfs::{
+    AbsPath, AbsPathBuf, Change as VfsChange, FileExcluded, FileId, Vfs, VfsPath, loader::Handle,
+};
 use vfs_notify::NotifyHandle;
 
 use crate::{
@@ -60,6 +62,8 @@ pub(crate) struct GlobalState {
     // status
     pub(crate) shutdown_requested: bool,
     pub(crate) last_reported_status: crate::lsp::extensions::ServerStatusParameters,
+    /// The number of checked and total files of the running `workspace/diagnostic` request.
+    pub(crate) workspace_diagnostics_progress: Option<(usize, usize)>,
 
     // Project loading
     pub(crate) load_package_tasks: Vec<discover::LoadPackageTask>,
@@ -71,6 +75,7 @@ pub(crate) struct GlobalState {
     pub(crate) loader: HandleReceiver<Box<dyn Handle>, Receiver<vfs::loader::Message>>,
     pub(crate) vfs: Arc<RwLock<(Vfs, FxHashMap<FileId, LineEndings>)>>,
     pub(crate) vfs_config_version: u32,
+    pub(crate) semantic_tokens_cache: Arc<Mutex<FxHashMap<Uri, SemanticTokens>>>,
     pub(crate) vfs_progress_config_version: u32,
     pub(crate) vfs_done: bool,
     // used to track how long VFS loading takes. this can't be on `vfs::loader::Handle`,
@@ -110,9 +115,9 @@ pub(crate) struct GlobalStateSnapshot {
     pub(crate) analysis: Analysis,
     // pub(crate) check_fixes: CheckFixes,
     in_memory_documents: InMemoryDocuments,
-    // pub(crate) semantic_tokens_cache: Arc<Mutex<FxHashMap<Url, SemanticTokens>>>,
+    pub(crate) semantic_tokens_cache: Arc<Mutex<FxHashMap<Uri, SemanticTokens>>>,
     vfs: Arc<RwLock<(Vfs, FxHashMap<FileId, LineEndings>)>>,
-    // pub(crate) packages: Arc<[Package]>,
+    packages: Arc<RwLock<PackageGraph>>,
     // pub(crate) flycheck: Arc<[FlycheckHandle]>,
 }
 
@@ -170,6 +175,7 @@ impl GlobalState {
                 quiescent: true,
                 message: None,
             },
+            workspace_diagnostics_progress: None,
             load_package_tasks: Vec::new(),
             load_package_sender,
             load_package_receiver,
@@ -177,7 +183,7 @@ impl GlobalState {
             loader,
             vfs: Arc::new(RwLock::new((Vfs::default(), FxHashMap::default()))),
             vfs_config_version: 0,
-            // semantic_tokens_cache: Arc::new(Default::default()),
+            semantic_tokens_cache: Arc::new(Mutex::default()),
             vfs_progress_config_version: 0,
             vfs_done: true,
             vfs_span: None,
@@ -388,8 +394,8 @@ impl GlobalState {
             in_memory_documents: self.in_memory_documents.clone(),
             vfs: Arc::clone(&self.vfs),
             // check_fixes: Arc::clone(&self.diagnostics.check_fixes),
-            // packages: Arc::clone(&self.packages),
-            // semantic_tokens_cache: Arc::clone(&self.semantic_tokens_cache),
+            packages: Arc::clone(&self.packages),
+            semantic_tokens_cache: Arc::clone(&self.semantic_tokens_cache),
             // flycheck: self.flycheck.clone(),
         }
     }
@@ -592,6 +598,40 @@ impl GlobalStateSnapshot {
                 .version,
         )
     }
+
+    /// Returns the file at `path`, or the files inside of the folder at `path`, with their paths.
+    pub(crate) fn files_at_or_under(
+        &self,
+        path: &VfsPath,
+    ) -> Vec<(FileId, VfsPath)> {
+        self.vfs_read()
+            .iter()
+            .filter(|(_, file_path)| file_path.starts_with(path))
+            .map(|(file_id, file_path)| (file_id, file_path.clone()))
+            .collect()
+    }
+
+    /// Returns the shader files of all local packages in the workspace, opened or not.
+    pub(crate) fn local_package_files(&self) -> Vec<FileId> {
+        let packages = self.packages.read();
+        let roots: Vec<&AbsPath> = packages
+            .iter()
+            .filter(|(_, package)| {
+                package.origin.is_local() && self.config.is_in_workspace(&package.manifest)
+            })
+            .map(|(_, package)| package.root.as_path())
+            .collect();
+        self.vfs_read()
+            .iter()
+            .filter(|(_, path)| {
+                path.as_path().is_some_and(|path| {
+                    matches!(path.extension(), Some("wgsl" | "wesl"))
+                        && roots.iter().any(|root| path.starts_with(root))
+                })
+            })
+            .map(|(file_id, _)| file_id)
+            .collect()
+    }
 }
 
 pub(crate) fn file_id_to_url(
@@ -625,3 +665,92 @@ pub(crate) fn vfs_path_to_file_id(
         FileExcluded::No => Ok(Some(file_id)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use base_db::input::PackageOrigin;
+    use edition::Edition;
+    use expect_test::expect;
+    use itertools::Itertools as _;
+    use lsp_types::ClientCapabilities;
+    use project_model::{ManifestPath, PackageKey, WeslPackage};
+    use vfs::{AbsPathBuf, VfsPath};
+
+    use super::GlobalState;
+    use crate::config::Config;
+
+    fn package(
+        root: &AbsPathBuf,
+        origin: PackageOrigin,
+    ) -> WeslPackage {
+        WeslPackage {
+            manifest: ManifestPath::try_from(root.join("wesl.toml")).unwrap(),
+            display_name: None,
+            root: root.join("shaders"),
+            origin,
+            dependencies: Vec::new(),
+            edition: Edition::Wesl2025Unstable,
+        }
+    }
+
+    #[test]
+    fn local_package_files_exclude_libraries() {
+        let base = AbsPathBuf::try_from(env!("CARGO_MANIFEST_DIR")).unwrap();
+        let workspace = base.join("workspace");
+        let library = base.join("library");
+        let (sender, _receiver) = crossbeam_channel::unbounded();
+        let config = Config::new(
+            workspace.clone(),
+            ClientCapabilities::default(),
+            vec![workspace.clone()],
+            None,
+        );
+        let state = GlobalState::new(sender, config);
+        for package in [
+            package(&workspace, PackageOrigin::Local),
+            package(&library, PackageOrigin::Library),
+        ] {
+            state
+                .packages
+                .write()
+                .set(PackageKey::from_package(&package), package);
+        }
+        for path in [
+            "workspace/wesl.toml",
+            "workspace/shaders/main.wgsl",
+            "workspace/shaders/lighting/shadows.wesl",
+            "workspace/shaders/readme.md",
+            "workspace/examples/example.wgsl",
+            "library/wesl.toml",
+            "library/shaders/library.wgsl",
+        ] {
+            state
+                .vfs
+                .write()
+                .0
+                .set_file_contents(VfsPath::from(base.join(path)), Some(Vec::new()));
+        }
+
+        let snapshot = state.snapshot();
+        let files = snapshot.local_package_files();
+        let vfs = snapshot.vfs_read();
+        let actual: String = files
+            .into_iter()
+            .map(|file_id| {
+                let path = vfs.file_path(file_id);
+                let path = path.as_path().unwrap().strip_prefix(&base).unwrap();
+                path.as_utf8_path()
+                    .components()
+                    .map(|component| component.as_str())
+                    .join("/")
+            })
+            .sorted()
+            .map(|path| format!("{path}\n"))
+            .collect();
+        expect![[r#"
+            workspace/shaders/lighting/shadows.wesl
+            workspace/shaders/main.wgsl
+        "#]]
+        .assert_eq(&actual);
+    }
+}
@@ -4,19 +4,40 @@
     reason = "handlers should have a specific signature"
 )]
 
-use base_db::{FilePosition, FileRange, TextRange};
-use ide::{Cancellable, HoverAction, HoverGotoTypeData};
+use std::hash::{Hash as _, Hasher as _};
+
+use base_db::{FilePosition, FileRange, TextRange, TextSize};
+use crossbeam_channel::Sender;
+use ide::{
+    AssistKind, AssistResolveStrategy, Cancellable, HoverAction, HoverGotoTypeData, Query,
+    SingleResolve,
+};
 use ide_diagnostics::DiagnosticsConfig;
+use itertools::Itertools as _;
 use lsp_types::{
-    CompletionList, CompletionParams, CompletionResponse, Contents, Definition, DefinitionParams,
-    DefinitionResponse, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity,
-    DiagnosticTag, DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentFormattingParams,
-    FoldingRange, FoldingRangeParams, FullDocumentDiagnosticReport, Hover, InlayHint,
-    InlayHintParams, MarkupContent, MarkupKind, Range, RelatedFullDocumentDiagnosticReport,
-    SignatureHelp, SignatureHelpParams, TextDocumentIdentifier, TextEdit,
+    BaseSymbolInformation, CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams,
+    CallHierarchyItem, CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams,
+    CallHierarchyPrepareParams, CodeActionParams, CodeLens, CodeLensParams, CompletionList,
+    CompletionParams, CompletionResponse, Contents, DeclarationParams, Definition,
+    DefinitionParams, DefinitionResponse, Diagnostic, DiagnosticRelatedInformation,
+    DiagnosticSeverity, DiagnosticTag, DocumentDiagnosticParams, DocumentDiagnosticReport,
+    DocumentFormattingParams, DocumentHighlight, DocumentHighlightParams, DocumentLink,
+    DocumentLinkParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+    DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, FoldingRange, FoldingRangeParams,
+    FullDocumentDiagnosticReport, Hover, InlayHint, InlayHintParams, Location, MarkupContent,
+    MarkupKind, PrepareRenameParams, PrepareRenameResult, Range, ReferenceParams,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    RenameFilesParams, RenameParams, SelectionRange, SelectionRangeParams, SemanticTokens,
+    SemanticTokensDeltaParams, SemanticTokensDeltaResponse, SemanticTokensParams, SignatureHelp,
+    SignatureHelpParams, SymbolInformation, TextDocumentIdentifier, TextDocumentPositionParams,
+    TextEdit, TypeDefinitionParams, UnchangedDocumentDiagnosticReport, Uri,
+    WorkspaceDiagnosticParams, WorkspaceDiagnosticReport, WorkspaceDocumentDiagnosticReport,
+    WorkspaceEdit, WorkspaceFullDocumentDiagnosticReport, WorkspaceSymbolResponse,
+    WorkspaceUnchangedDocumentDiagnosticReport,
 };
+use rustc_hash::{FxHashMap, FxHasher};
 use stdx::format_to;
-use vfs::{AbsPath, FileId};
+use vfs::{AbsPath, FileId, VfsPath};
 
 use crate::{
     Result,
@@ -26,9 +47,12 @@ use crate::{
         self,
         extensions::{
             self, PositionOrRange, ViewModuleGraphParameters, ViewPackageGraphParameters,
+            WorkspaceSymbolParameters, WorkspaceSymbolSearchKind, WorkspaceSymbolSearchScope,
         },
         from_proto, to_proto,
+        utilities::invalid_params_error,
     },
+    main_loop::{Task, WorkspaceDiagnosticsProgress},
     try_default,
 };
 
@@ -106,6 +130,677 @@ pub(crate) fn handle_goto_definition(
     Ok(Some(result))
 }
 
+pub(crate) fn handle_goto_declaration(
+    snap: GlobalStateSnapshot,
+    parameters: DeclarationParams,
+) -> anyhow::Result<Option<DefinitionResponse>> {
+    let _p = tracing::info_span!("handle_goto_declaration").entered();
+    let position = try_default!(from_proto::file_position(
+        &snap,
+        &parameters.text_document_position_params
+    )?);
+    let Some(navigation_info) = snap.analysis.goto_declaration(position)? else {
+        return Ok(None);
+    };
+    let source = FileRange {
+        file_id: position.file_id,
+        range: navigation_info.range,
+    };
+    let result =
+        to_proto::goto_definition_response(&snap, Some(source), vec![navigation_info.info])?;
+    Ok(Some(result))
+}
+
+pub(crate) fn handle_goto_type_definition(
+    snap: GlobalStateSnapshot,
+    parameters: TypeDefinitionParams,
+) -> anyhow::Result<Option<DefinitionResponse>> {
+    let _p = tracing::info_span!("handle_goto_type_definition").entered();
+    let position = try_default!(from_proto::file_position(
+        &snap,
+        &parameters.text_document_position_params
+    )?);
+    let Some(navigation_info) = snap.analysis.goto_type_definition(position)? else {
+        return Ok(None);
+    };
+    let source = FileRange {
+        file_id: position.file_id,
+        range: navigation_info.range,
+    };
+    let result =
+        to_proto::goto_definition_response(&snap, Some(source), vec![navigation_info.info])?;
+    Ok(Some(result))
+}
+
+pub(crate) fn handle_call_hierarchy_prepare(
+    snap: GlobalStateSnapshot,
+    parameters: CallHierarchyPrepareParams,
+) -> anyhow::Result<Option<Vec<CallHierarchyItem>>> {
+    let _p = tracing::info_span!("handle_call_hierarchy_prepare").entered();
+    let position = try_default!(from_proto::file_position(
+        &snap,
+        &parameters.text_document_position_params
+    )?);
+    let Some(navigation_info) = snap.analysis.call_hierarchy(position)? else {
+        return Ok(None);
+    };
+    let result = navigation_info
+        .info
+        .into_iter()
+        .map(|item| to_proto::call_hierarchy_item(&snap, item.target, item.name))
+        .collect::<Cancellable<Vec<_>>>()?;
+    Ok(Some(result))
+}
+
+pub(crate) fn handle_call_hierarchy_incoming(
+    snap: GlobalStateSnapshot,
+    parameters: CallHierarchyIncomingCallsParams,
+) -> anyhow::Result<Option<Vec<CallHierarchyIncomingCall>>> {
+    let _p = tracing::info_span!("handle_call_hierarchy_incoming").entered();
+    let position = try_default!(call_hierarchy_item_position(&snap, &parameters.item)?);
+    let Some(calls) = snap.analysis.incoming_calls(position)? else {
+        return Ok(None);
+    };
+    let mut result = Vec::with_capacity(calls.len());
+    for call in calls {
+        let from_ranges = call_ranges(&snap, &call.ranges)?;
+        result.push(CallHierarchyIncomingCall {
+            from: to_proto::call_hierarchy_item(&snap, call.target, call.name)?,
+            from_ranges,
+        });
+    }
+    Ok(Some(result))
+}
+
+pub(crate) fn handle_call_hierarchy_outgoing(
+    snap: GlobalStateSnapshot,
+    parameters: CallHierarchyOutgoingCallsParams,
+) -> anyhow::Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+    let _p = tracing::info_span!("handle_call_hierarchy_outgoing").entered();
+    let position = try_default!(call_hierarchy_item_position(&snap, &parameters.item)?);
+    let Some(calls) = snap.analysis.outgoing_calls(position)? else {
+        return Ok(None);
+    };
+    let mut result = Vec::with_capacity(calls.len());
+    for call in calls {
+        // The ranges are in the file of the item the calls were requested for.
+        let from_ranges = call_ranges(&snap, &call.ranges)?;
+        result.push(CallHierarchyOutgoingCall {
+            to: to_proto::call_hierarchy_item(&snap, call.target, call.name)?,
+            from_ranges,
+        });
+    }
+    Ok(Some(result))
+}
+
+/// Returns `None` if the file of the item was excluded.
+fn call_hierarchy_item_position(
+    snap: &GlobalStateSnapshot,
+    item: &CallHierarchyItem,
+) -> anyhow::Result<Option<FilePosition>> {
+    let document_position = TextDocumentPositionParams::new(
+        TextDocumentIdentifier::new(item.uri.clone()),
+        item.selection_range.start,
+    );
+    from_proto::file_position(snap, &document_position)
+}
+
+fn call_ranges(
+    snap: &GlobalStateSnapshot,
+    ranges: &[FileRange],
+) -> Cancellable<Vec<Range>> {
+    ranges
+        .iter()
+        .map(|file_range| {
+            let line_index = snap.file_line_index(file_range.file_id)?;
+            Ok(to_proto::range(&line_index, file_range.range))
+        })
+        .collect()
+}
+
+pub(crate) fn handle_references(
+    snap: GlobalStateSnapshot,
+    parameters: ReferenceParams,
+) -> anyhow::Result<Option<Vec<Location>>> {
+    let _p = tracing::info_span!("handle_references").entered();
+    let position = try_default!(from_proto::file_position(
+        &snap,
+        &parameters.text_document_position_params
+    )?);
+    let Some(references) = snap.analysis.find_all_refs(position, None)? else {
+        return Ok(None);
+    };
+
+    let declaration = if parameters.context.include_declaration {
+        references.declaration.map(|declaration| FileRange {
+            file_id: declaration.navigation_target.file_id,
+            range: declaration.navigation_target.focus_or_full_range(),
+        })
+    } else {
+        None
+    };
+
+    let locations = declaration
+        .into_iter()
+        .chain(
+            references
+                .references
+                .into_iter()
+                .flat_map(|(file_id, references)| {
+                    references
+                        .into_iter()
+                        .map(move |(range, _)| FileRange { file_id, range })
+                }),
+        )
+        .unique()
+        .filter_map(|frange| to_proto::location(&snap, frange).ok())
+        .collect();
+
+    Ok(Some(locations))
+}
+
+pub(crate) fn handle_code_lens(
+    snap: GlobalStateSnapshot,
+    parameters: CodeLensParams,
+) -> anyhow::Result<Option<Vec<CodeLens>>> {
+    let _p = tracing::info_span!("handle_code_lens").entered();
+    let file_id = try_default!(from_proto::file_id(&snap, &parameters.text_document.uri)?);
+    let mut lenses = Vec::new();
+    for annotation in snap.analysis.annotations(file_id)? {
+        to_proto::code_lens(&mut lenses, &snap, annotation)?;
+    }
+    Ok(Some(lenses))
+}
+
+pub(crate) fn handle_code_lens_resolve(
+    snap: GlobalStateSnapshot,
+    mut code_lens: CodeLens,
+) -> anyhow::Result<CodeLens> {
+    let _p = tracing::info_span!("handle_code_lens_resolve").entered();
+    let Some(data) = code_lens.data.take() else {
+        return Ok(code_lens);
+    };
+    let resolve_data = serde_json::from_value::<extensions::CodeLensResolveData>(data)?;
+    let Some(annotation) = from_proto::annotation(&snap, code_lens.range, resolve_data)? else {
+        return Ok(code_lens);
+    };
+    let annotation = snap.analysis.resolve_annotation(annotation)?;
+
+    let mut lenses = Vec::new();
+    to_proto::code_lens(&mut lenses, &snap, annotation)?;
+    Ok(lenses.pop().unwrap_or(code_lens))
+}
+
+pub(crate) fn handle_document_link(
+    snap: GlobalStateSnapshot,
+    parameters: DocumentLinkParams,
+) -> anyhow::Result<Option<Vec<DocumentLink>>> {
+    let _p = tracing::info_span!("handle_document_link").entered();
+    let file_id = try_default!(from_proto::file_id(&snap, &parameters.text_document.uri)?);
+    let line_index = snap.file_line_index(file_id)?;
+    let links = snap
+        .analysis
+        .document_links(file_id)?
+        .into_iter()
+        .map(|link| DocumentLink {
+            range: to_proto::range(&line_index, link.range),
+            target: Some(to_proto::url(&snap, link.target)),
+            tooltip: None,
+            data: None,
+        })
+        .collect();
+    Ok(Some(links))
+}
+
+pub(crate) fn handle_document_highlight(
+    snap: GlobalStateSnapshot,
+    parameters: DocumentHighlightParams,
+) -> anyhow::Result<Option<Vec<DocumentHighlight>>> {
+    let _p = tracing::info_span!("handle_document_highlight").entered();
+    let position = try_default!(from_proto::file_position(
+        &snap,
+        &parameters.text_document_position_params
+    )?);
+    let line_index = snap.file_line_index(position.file_id)?;
+    let Some(highlights) = snap.analysis.highlight_related(position)? else {
+        return Ok(None);
+    };
+    let result = highlights
+        .into_iter()
+        .map(|highlight| DocumentHighlight {
+            range: to_proto::range(&line_index, highlight.range),
+            kind: highlight
+                .category
+                .and_then(to_proto::document_highlight_kind),
+        })
+        .collect();
+    Ok(Some(result))
+}
+
+pub(crate) fn handle_document_symbol(
+    snap: GlobalStateSnapshot,
+    parameters: DocumentSymbolParams,
+) -> anyhow::Result<Option<DocumentSymbolResponse>> {
+    let _p = tracing::info_span!("handle_document_symbol").entered();
+    let file_id = try_default!(from_proto::file_id(&snap, &parameters.text_document.uri)?);
+    let line_index = snap.file_line_index(file_id)?;
+
+    let mut parents: Vec<(DocumentSymbol, Option<usize>)> = Vec::new();
+    for symbol in snap.analysis.file_structure(file_id)? {
+        let document_symbol = DocumentSymbol {
+            name: symbol.label,
+            detail: symbol.detail,
+            kind: to_proto::structure_node_kind(symbol.kind),
+            tags: None,
+            deprecated: None,
+            range: to_proto::range(&line_index, symbol.node_range),
+            selection_range: to_proto::range(&line_index, symbol.navigation_range),
+            children: None,
+        };
+        parents.push((document_symbol, symbol.parent));
+    }
+
+    // Builds hierarchy from a flat list, in reverse order (so that indices make sense)
+    let document_symbols = {
+        let mut accumulator = Vec::new();
+        while let Some((mut node, parent_index)) = parents.pop() {
+            if let Some(children) = &mut node.children {
+                children.reverse();
+            }
+            let parent = match parent_index {
+                None => &mut accumulator,
+                Some(index) => parents[index].0.children.get_or_insert_with(Vec::new),
+            };
+            parent.push(node);
+        }
+        accumulator.reverse();
+        accumulator
+    };
+
+    let result = if snap.config.hierarchical_symbols() {
+        DocumentSymbolResponse::DocumentSymbolList(document_symbols)
+    } else {
+        let url = to_proto::url(&snap, file_id);
+        let mut symbol_information = Vec::new();
+        for symbol in document_symbols {
+            flatten_document_symbol(&symbol, None, &url, &mut symbol_information);
+        }
+        DocumentSymbolResponse::SymbolInformationList(symbol_information)
+    };
+    Ok(Some(result))
+}
+
+fn flatten_document_symbol(
+    symbol: &DocumentSymbol,
+    container_name: Option<String>,
+    url: &Uri,
+    result: &mut Vec<SymbolInformation>,
+) {
+    result.push(SymbolInformation {
+        base_symbol_information: BaseSymbolInformation {
+            name: symbol.name.clone(),
+            kind: symbol.kind,
+            tags: symbol.tags.clone(),
+            container_name,
+        },
+        deprecated: symbol.deprecated,
+        location: Location::new(url.clone(), symbol.range),
+    });
+
+    for child in symbol.children.iter().flatten() {
+        flatten_document_symbol(child, Some(symbol.name.clone()), url, result);
+    }
+}
+
+pub(crate) fn handle_semantic_tokens_full(
+    snap: GlobalStateSnapshot,
+    parameters: SemanticTokensParams,
+) -> anyhow::Result<Option<SemanticTokens>> {
+    let _p = tracing::info_span!("handle_semantic_tokens_full").entered();
+    let file_id = try_default!(from_proto::file_id(&snap, &parameters.text_document.uri)?);
+    let text = snap.analysis.file_text(file_id)?;
+    let line_index = snap.file_line_index(file_id)?;
+
+    let highlights = snap.analysis.highlight(file_id)?;
+    let semantic_tokens = to_proto::semantic_tokens(&text, &line_index, highlights);
+
+    // Unconditionally cache the tokens
+    snap.semantic_tokens_cache
+        .lock()
+        .insert(parameters.text_document.uri, semantic_tokens.clone());
+
+    Ok(Some(semantic_tokens))
+}
+
+pub(crate) fn handle_semantic_tokens_full_delta(
+    snap: GlobalStateSnapshot,
+    parameters: SemanticTokensDeltaParams,
+) -> anyhow::Result<Option<SemanticTokensDeltaResponse>> {
+    let _p = tracing::info_span!("handle_semantic_tokens_full_delta").entered();
+    let file_id = try_default!(from_proto::file_id(&snap, &parameters.text_document.uri)?);
+    let text = snap.analysis.file_text(file_id)?;
+    let line_index = snap.file_line_index(file_id)?;
+
+    let highlights = snap.analysis.highlight(file_id)?;
+    let semantic_tokens = to_proto::semantic_tokens(&text, &line_index, highlights);
+
+    let cached_tokens = snap
+        .semantic_tokens_cache
+        .lock()
+        .remove(&parameters.text_document.uri);
+
+    if let Some(
+        cached_tokens @ SemanticTokens {
+            result_id: Some(previous_id),
+            ..
+        },
+    ) = &cached_tokens
+        && *previous_id == parameters.previous_result_id
+    {
+        let delta = to_proto::semantic_token_delta(cached_tokens, &semantic_tokens);
+        snap.semantic_tokens_cache
+            .lock()
+            .insert(parameters.text_document.uri, semantic_tokens);
+        return Ok(Some(SemanticTokensDeltaResponse::SemanticTokensDelta(
+            delta,
+        )));
+    }
+
+    // Clone first to keep the lock short
+    let semantic_tokens_clone = semantic_tokens.clone();
+    snap.semantic_tokens_cache
+        .lock()
+        .insert(parameters.text_document.uri, semantic_tokens_clone);
+
+    Ok(Some(SemanticTokensDeltaResponse::SemanticTokens(
+        semantic_tokens,
+    )))
+}
+
+/// The maximum number of symbols returned for a workspace symbol search.
+const WORKSPACE_SYMBOL_SEARCH_LIMIT: usize = 128;
+
+pub(crate) fn handle_workspace_symbol(
+    snap: GlobalStateSnapshot,
+    parameters: WorkspaceSymbolParameters,
+) -> anyhow::Result<Option<WorkspaceSymbolResponse>> {
+    let _p = tracing::info_span!("handle_workspace_symbol").entered();
+    let WorkspaceSymbolParameters {
+        query,
+        search_scope,
+        search_kind,
+        ..
+    } = parameters;
+    let include_dependencies = matches!(
+        search_scope,
+        Some(WorkspaceSymbolSearchScope::WorkspaceAndDependencies)
+    );
+    let only_types = matches!(search_kind, Some(WorkspaceSymbolSearchKind::OnlyTypes));
+
+    let mut symbols = workspace_symbols(&snap, &query, include_dependencies, only_types)?;
+    // If nothing is declared in the workspace, the user is likely looking for a dependency.
+    if symbols.is_empty() && !include_dependencies {
+        symbols = workspace_symbols(&snap, &query, true, only_types)?;
+    }
+    Ok(Some(WorkspaceSymbolResponse::SymbolInformationList(
+        symbols,
+    )))
+}
+
+fn workspace_symbols(
+    snap: &GlobalStateSnapshot,
+    query: &str,
+    include_dependencies: bool,
+    only_types: bool,
+) -> anyhow::Result<Vec<SymbolInformation>> {
+    let mut query = Query::new(query.to_owned());
+    if include_dependencies {
+        query.libs();
+    }
+    if only_types {
+        query.only_types();
+    }
+    query.limit(WORKSPACE_SYMBOL_SEARCH_LIMIT);
+
+    let mut result = Vec::new();
+    for symbol in snap.analysis.symbol_search(query)? {
+        let line_index = snap.file_line_index(symbol.file_id)?;
+        result.push(SymbolInformation {
+            base_symbol_information: BaseSymbolInformation {
+                name: symbol.name.as_str().to_owned(),
+                kind: to_proto::symbol_kind(symbol.kind),
+                tags: None,
+                container_name: symbol.container_name,
+            },
+            deprecated: None,
+            location: Location::new(
+                to_proto::url(snap, symbol.file_id),
+                to_proto::range(&line_index, symbol.focus_range),
+            ),
+        });
+    }
+    Ok(result)
+}
+
+pub(crate) fn handle_prepare_rename(
+    snap: GlobalStateSnapshot,
+    parameters: PrepareRenameParams,
+) -> anyhow::Result<Option<PrepareRenameResult>> {
+    let _p = tracing::info_span!("handle_prepare_rename").entered();
+    let position = try_default!(from_proto::file_position(
+        &snap,
+        &parameters.text_document_position_params
+    )?);
+
+    let change = snap
+        .analysis
+        .prepare_rename(position)?
+        .map_err(|error| invalid_params_error(error.to_string()))?;
+
+    let line_index = snap.file_line_index(position.file_id)?;
+    let range = to_proto::range(&line_index, change.range);
+    Ok(Some(PrepareRenameResult::Range(range)))
+}
+
+pub(crate) fn handle_rename(
+    snap: GlobalStateSnapshot,
+    parameters: RenameParams,
+) -> anyhow::Result<Option<WorkspaceEdit>> {
+    let _p = tracing::info_span!("handle_rename").entered();
+    let position = try_default!(from_proto::file_position(
+        &snap,
+        &TextDocumentPositionParams {
+            text_document: parameters.text_document,
+            position: parameters.position,
+        }
+    )?);
+
+    let change = snap
+        .analysis
+        .rename(position, &parameters.new_name)?
+        .map_err(|error| invalid_params_error(error.to_string()))?;
+
+    let workspace_edit = to_proto::workspace_edit(&snap, change)?;
+    Ok(Some(workspace_edit))
+}
+
+pub(crate) fn handle_will_rename_files(
+    snap: GlobalStateSnapshot,
+    parameters: RenameFilesParams,
+) -> anyhow::Result<Option<WorkspaceEdit>> {
+    let _p = tracing::info_span!("handle_will_rename_files").entered();
+    let mut renames = Vec::new();
+    for rename in &parameters.files {
+        let old_path = from_proto::vfs_path(&Uri::parse(&rename.old_uri)?)?;
+        let new_path = from_proto::vfs_path(&Uri::parse(&rename.new_uri)?)?;
+        renames.extend(renamed_files(&snap, &old_path, &new_path));
+    }
+
+    let change = snap.analysis.will_rename_files(&renames)?;
+    if change.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(to_proto::workspace_edit(&snap, change)?))
+}
+
+/// Returns the files which move when the file or folder at `old_path` moves to `new_path`, with their new paths.
+fn renamed_files(
+    snap: &GlobalStateSnapshot,
+    old_path: &VfsPath,
+    new_path: &VfsPath,
+) -> Vec<(FileId, VfsPath)> {
+    // A folder moves all files inside of it.
+    snap.files_at_or_under(old_path)
+        .into_iter()
+        .filter_map(|(file_id, path)| {
+            let new_file_path = match path.strip_prefix(old_path) {
+                Some(relative) if path != *old_path => {
+                    new_path.join(relative.as_utf8_path().as_str())
+                },
+                Some(_) | None => Some(new_path.clone()),
+            };
+            Some((file_id, new_file_path?))
+        })
+        .collect()
+}
+
+pub(crate) fn handle_code_action(
+    snap: GlobalStateSnapshot,
+    parameters: CodeActionParams,
+) -> anyhow::Result<Option<Vec<extensions::CodeAction>>> {
+    let _p = tracing::info_span!("handle_code_action").entered();
+    if !snap.config.code_action_literals() {
+        // We intentionally do not support command-based actions, as those
+        // require either custom client-code or server-initiated edits. Server
+        // initiated edits break causality, so we avoid those.
+        return Ok(None);
+    }
+
+    let frange = try_default!(from_proto::file_range(
+        &snap,
+        &parameters.text_document,
+        parameters.range
+    )?);
+    let source_root = snap.analysis.source_root_id(frange.file_id)?;
+    let mut assists_config = snap.config.assist(Some(source_root));
+    assists_config.allowed = parameters.context.only.clone().map(|only| {
+        only.into_iter()
+            .filter_map(from_proto::assist_kind)
+            .collect()
+    });
+
+    let code_action_resolve_cap = snap.config.code_action_resolve();
+    let resolve = if code_action_resolve_cap {
+        AssistResolveStrategy::None
+    } else {
+        AssistResolveStrategy::All
+    };
+    let version = snap.file_version(frange.file_id);
+
+    let diagnostics_config = snap.config.diagnostics(Some(source_root));
+    let assists =
+        snap.analysis
+            .assists_with_fixes(&assists_config, &diagnostics_config, resolve, frange)?;
+    let mut result = Vec::with_capacity(assists.len());
+    for (index, assist) in assists.into_iter().enumerate() {
+        let resolve_data = code_action_resolve_cap.then(|| (index, parameters.clone(), version));
+        result.push(to_proto::code_action(&snap, assist, resolve_data)?);
+    }
+    Ok(Some(result))
+}
+
+pub(crate) fn handle_code_action_resolve(
+    snap: GlobalStateSnapshot,
+    mut code_action: extensions::CodeAction,
+) -> anyhow::Result<extensions::CodeAction> {
+    let _p = tracing::info_span!("handle_code_action_resolve").entered();
+    let Some(resolve_data) = code_action.data.take() else {
+        return Err(invalid_params_error("code action without data".to_owned()).into());
+    };
+
+    let file_id = from_proto::file_id(
+        &snap,
+        &resolve_data.code_action_parameters.text_document.uri,
+    )?
+    .ok_or_else(|| invalid_params_error("file not found".to_owned()))?;
+    if snap.file_version(file_id) != resolve_data.version {
+        return Err(invalid_params_error("stale code action".to_owned()).into());
+    }
+    let line_index = snap.file_line_index(file_id)?;
+    let range = from_proto::text_range(&line_index, resolve_data.code_action_parameters.range)?;
+    let frange = FileRange { file_id, range };
+
+    let source_root = snap.analysis.source_root_id(file_id)?;
+    let mut assists_config = snap.config.assist(Some(source_root));
+    assists_config.allowed = resolve_data
+        .code_action_parameters
+        .context
+        .only
+        .map(|only| {
+            only.into_iter()
+                .filter_map(from_proto::assist_kind)
+                .collect()
+        });
+
+    let (assist_index, assist_resolve) = parse_action_id(&resolve_data.id)
+        .map_err(|error| invalid_params_error(format!("failed to parse action id: {error}")))?;
+    let expected_assist_id = assist_resolve.assist_id.clone();
+    let expected_kind = assist_resolve.assist_kind;
+
+    let diagnostics_config = snap.config.diagnostics(Some(source_root));
+    let assists = snap.analysis.assists_with_fixes(
+        &assists_config,
+        &diagnostics_config,
+        AssistResolveStrategy::Single(assist_resolve),
+        frange,
+    )?;
+    let assist = match assists.get(assist_index) {
+        Some(assist) if assist.id.name == expected_assist_id && assist.id.kind == expected_kind => {
+            assist.clone()
+        },
+        _ => {
+            return Err(invalid_params_error(format!(
+                "Mismatching assist at index {assist_index} for the resolve parameters given. Expected assist `{expected_assist_id}` of kind `{}`.",
+                expected_kind.name(),
+            ))
+            .into());
+        },
+    };
+    let resolved = to_proto::code_action(&snap, assist, None)?;
+    code_action.edit = resolved.edit;
+    code_action.command = resolved.command;
+    Ok(code_action)
+}
+
+/// Parses the id created by [`to_proto::code_action`]: `name:kind:index:group_index`.
+fn parse_action_id(action_id: &str) -> Result<(usize, SingleResolve), String> {
+    let (name, kind, index, group_index) = action_id
+        .split(':')
+        .collect_tuple()
+        .ok_or_else(|| "action id must consist of 4 parts".to_owned())?;
+    let assist_kind: AssistKind = kind.parse()?;
+    let index = index
+        .parse::<usize>()
+        .map_err(|error| format!("incorrect index: {error}"))?;
+    let assist_subtype = if group_index.is_empty() {
+        None
+    } else {
+        Some(
+            group_index
+                .parse::<usize>()
+                .map_err(|error| format!("incorrect group index: {error}"))?,
+        )
+    };
+    Ok((
+        index,
+        SingleResolve {
+            assist_id: name.to_owned(),
+            assist_kind,
+            assist_subtype,
+        },
+    ))
+}
+
 pub(crate) fn handle_completion(
     snap: GlobalStateSnapshot,
     CompletionParams {
@@ -171,6 +866,104 @@ pub(crate) fn handle_folding_range(
     Ok(Some(result))
 }
 
+pub(crate) fn handle_on_enter(
+    snap: GlobalStateSnapshot,
+    parameters: TextDocumentPositionParams,
+) -> anyhow::Result<Option<Vec<lsp::extensions::SnippetTextEdit>>> {
+    let _p = tracing::info_span!("handle_on_enter").entered();
+    let position = try_default!(from_proto::file_position(&snap, &parameters)?);
+    let Some(edit) = snap.analysis.on_enter(position)? else {
+        return Ok(None);
+    };
+    let line_index = snap.file_line_index(position.file_id)?;
+    Ok(Some(to_proto::snippet_text_edit_vec(
+        &line_index,
+        true,
+        edit,
+    )))
+}
+
+pub(crate) fn handle_on_type_formatting(
+    snap: GlobalStateSnapshot,
+    parameters: DocumentOnTypeFormattingParams,
+) -> anyhow::Result<Option<Vec<lsp::extensions::SnippetTextEdit>>> {
+    let _p = tracing::info_span!("handle_on_type_formatting").entered();
+    let char_typed = parameters.ch.chars().next().unwrap_or('\0');
+    if !snap
+        .config
+        .typing_trigger_chars()
+        .is_some_and(|trigger_chars| trigger_chars.contains(char_typed))
+    {
+        return Ok(None);
+    }
+    let mut position = try_default!(from_proto::file_position(
+        &snap,
+        &parameters.text_document_position
+    )?);
+    let line_index = snap.file_line_index(position.file_id)?;
+
+    // In `ide`, the `on_char_typed` invariant is that
+    // `text.char_at(position) == char_typed`.
+    position.offset -= TextSize::of(char_typed);
+    let text = snap.analysis.file_text(position.file_id)?;
+    if stdx::never!(!text[usize::from(position.offset)..].starts_with(char_typed)) {
+        return Ok(None);
+    }
+
+    let Some(source_change) = snap.analysis.on_char_typed(position, char_typed)? else {
+        return Ok(None);
+    };
+    if source_change.is_snippet && !snap.config.client_capabilities().snippet_text_edit() {
+        return Ok(None);
+    }
+    // This should be a single-file edit.
+    let Some((_, edit)) = source_change.source_file_edits.into_iter().next() else {
+        return Ok(None);
+    };
+    Ok(Some(to_proto::snippet_text_edit_vec(
+        &line_index,
+        source_change.is_snippet,
+        edit,
+    )))
+}
+
+pub(crate) fn handle_selection_range(
+    snap: GlobalStateSnapshot,
+    parameters: SelectionRangeParams,
+) -> anyhow::Result<Option<Vec<SelectionRange>>> {
+    let _p = tracing::info_span!("handle_selection_range").entered();
+    let file_id = try_default!(from_proto::file_id(&snap, &parameters.text_document.uri)?);
+    let line_index = snap.file_line_index(file_id)?;
+    let result = parameters
+        .positions
+        .into_iter()
+        .map(|position| {
+            let offset = from_proto::offset(&line_index, position)?;
+            let mut ranges = Vec::new();
+            let mut range = TextRange::empty(offset);
+            loop {
+                ranges.push(range);
+                let next = snap
+                    .analysis
+                    .extend_selection(FileRange { file_id, range })?;
+                if next == range {
+                    break;
+                }
+                range = next;
+            }
+            // Nest the ranges from the outermost one inwards.
+            let selection_range = ranges.into_iter().rev().fold(None, |parent, range| {
+                Some(SelectionRange {
+                    range: to_proto::range(&line_index, range),
+                    parent: parent.map(Box::new),
+                })
+            });
+            Ok(selection_range.expect("the cursor position is always a range"))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Some(result))
+}
+
 pub(crate) fn handle_formatting(
     snap: GlobalStateSnapshot,
     parameters: DocumentFormattingParams,
@@ -178,9 +971,7 @@ pub(crate) fn handle_formatting(
     let Some(file_id) = from_proto::file_id(&snap, &parameters.text_document.uri)? else {
         return Ok(None);
     };
-    let Some(node) = snap.analysis.format(file_id, None)? else {
-        return Ok(None);
-    };
+    let node = snap.analysis.format(file_id)?;
     let line_index = snap.file_line_index(file_id)?;
 
     let before = snap.analysis.file_text(file_id)?;
@@ -191,6 +982,29 @@ pub(crate) fn handle_formatting(
     Ok(Some(edits))
 }
 
+pub(crate) fn handle_range_formatting(
+    snap: GlobalStateSnapshot,
+    parameters: DocumentRangeFormattingParams,
+) -> Result<Option<Vec<TextEdit>>> {
+    let Some(file_range) =
+        from_proto::file_range(&snap, &parameters.text_document, parameters.range)?
+    else {
+        return Ok(None);
+    };
+    let Some((range, replacement)) = snap.analysis.format_range(file_range)? else {
+        return Ok(None);
+    };
+    let line_index = snap.file_line_index(file_range.file_id)?;
+
+    let before = snap.analysis.file_text(file_range.file_id)?;
+    let mut after = before.to_string();
+    after.replace_range(std::ops::Range::<usize>::from(range), &replacement);
+
+    let diff = diff::diff(&before, &after);
+    let edits = to_proto::text_edit_vec(&line_index, diff);
+    Ok(Some(edits))
+}
+
 pub(crate) fn handle_hover(
     snap: GlobalStateSnapshot,
     parameters: lsp::extensions::HoverParameters,
@@ -334,17 +1148,27 @@ pub(crate) fn handle_document_diagnostics(
     }
 
     let items = publish_diagnostics(&snap, &config, file_id)?;
+    let result_id = diagnostics_result_id(&items);
+
+    if parameters.previous_result_id.as_ref() == Some(&result_id) {
+        return Ok(
+            DocumentDiagnosticReport::RelatedUnchangedDocumentDiagnosticReport(
+                RelatedUnchangedDocumentDiagnosticReport {
+                    related_documents: None,
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                },
+            ),
+        );
+    }
 
     Ok(
         DocumentDiagnosticReport::RelatedFullDocumentDiagnosticReport(
             RelatedFullDocumentDiagnosticReport {
                 related_documents: None,
                 full_document_diagnostic_report: FullDocumentDiagnosticReport {
-                    result_id: Some(
-                        parameters
-                            .previous_result_id
-                            .unwrap_or_else(|| "wgsl-analyzer".to_owned()),
-                    ),
+                    result_id: Some(result_id),
                     items,
                 },
             },
@@ -352,6 +1176,155 @@ pub(crate) fn handle_document_diagnostics(
     )
 }
 
+/// Checks every file of the local packages, so that errors in files which were never opened show
+/// up as well. Files whose diagnostics did not change since the client's last pull are reported
+/// as unchanged.
+pub(crate) fn handle_workspace_diagnostics(
+    snap: GlobalStateSnapshot,
+    parameters: WorkspaceDiagnosticParams,
+    sender: &Sender<Task>,
+) -> anyhow::Result<WorkspaceDiagnosticReport> {
+    let _p = tracing::info_span!("handle_workspace_diagnostics").entered();
+    let previous_result_ids: FxHashMap<Uri, String> = parameters
+        .previous_result_ids
+        .into_iter()
+        .map(|previous| (previous.uri, previous.value))
+        .collect();
+
+    let files = snap.local_package_files();
+    let progress = WorkspaceDiagnosticsProgressGuard::begin(sender, files.len());
+    let mut items = Vec::with_capacity(files.len());
+    for (checked, file_id) in files.into_iter().enumerate() {
+        progress.report(checked);
+        let source_root = snap.analysis.source_root_id(file_id).ok();
+        let config = snap.config.diagnostics(source_root);
+        if !config.enabled {
+            continue;
+        }
+
+        let uri = snap.file_id_to_url(file_id);
+        let version = snap.file_version(file_id);
+        let diagnostics = publish_diagnostics(&snap, &config, file_id)?;
+        let previous_result_id = previous_result_ids.get(&uri);
+        let report = workspace_document_report(uri, version, diagnostics, previous_result_id);
+        items.push(report);
+    }
+
+    Ok(WorkspaceDiagnosticReport { items })
+}
+
+/// Reports the diagnostics of one file, or that they did not change since the client pulled the
+/// diagnostics identified by `previous_result_id`.
+fn workspace_document_report(
+    uri: Uri,
+    version: Option<i32>,
+    diagnostics: Vec<Diagnostic>,
+    previous_result_id: Option<&String>,
+) -> WorkspaceDocumentDiagnosticReport {
+    let result_id = diagnostics_result_id(&diagnostics);
+    if previous_result_id == Some(&result_id) {
+        WorkspaceDocumentDiagnosticReport::WorkspaceUnchangedDocumentDiagnosticReport(
+            WorkspaceUnchangedDocumentDiagnosticReport {
+                uri,
+                version,
+                unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                    result_id,
+                },
+            },
+        )
+    } else {
+        WorkspaceDocumentDiagnosticReport::WorkspaceFullDocumentDiagnosticReport(
+            WorkspaceFullDocumentDiagnosticReport {
+                uri,
+                version,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: Some(result_id),
+                    items: diagnostics,
+                },
+            },
+        )
+    }
+}
+
+/// Reports the progress of a workspace check to the main loop, and its end once dropped, so that
+/// a cancelled check does not leave the server status behind.
+struct WorkspaceDiagnosticsProgressGuard<'sender> {
+    sender: &'sender Sender<Task>,
+}
+
+impl<'sender> WorkspaceDiagnosticsProgressGuard<'sender> {
+    fn begin(
+        sender: &'sender Sender<Task>,
+        total: usize,
+    ) -> Self {
+        let guard = Self { sender };
+        guard.send(WorkspaceDiagnosticsProgress::Begin { total });
+        guard
+    }
+
+    fn report(
+        &self,
+        checked: usize,
+    ) {
+        self.send(WorkspaceDiagnosticsProgress::Report { checked });
+    }
+
+    fn send(
+        &self,
+        progress: WorkspaceDiagnosticsProgress,
+    ) {
+        // The main loop may already be gone during shutdown, and then nobody is left to tell.
+        drop(self.sender.send(Task::WorkspaceDiagnostics(progress)));
+    }
+}
+
+impl Drop for WorkspaceDiagnosticsProgressGuard<'_> {
+    fn drop(&mut self) {
+        self.send(WorkspaceDiagnosticsProgress::End);
+    }
+}
+
+/// Identifies a set of diagnostics by its contents, so that the client can tell whether they
+/// changed since its last pull.
+fn diagnostics_result_id(diagnostics: &[Diagnostic]) -> String {
+    let mut hasher = FxHasher::default();
+    for diagnostic in diagnostics {
+        hash_range(&diagnostic.range, &mut hasher);
+        diagnostic
+            .severity
+            .as_ref()
+            .map(std::mem::discriminant)
+            .hash(&mut hasher);
+        if let Some(Code::String(code)) = &diagnostic.code {
+            code.hash(&mut hasher);
+        }
+        diagnostic.source.hash(&mut hasher);
+        match &diagnostic.message {
+            lsp_types::Message::String(message) => message.hash(&mut hasher),
+            lsp_types::Message::MarkupContent(MarkupContent { value, kind: _ }) => {
+                value.hash(&mut hasher);
+            },
+        }
+        for tag in diagnostic.tags.iter().flatten() {
+            std::mem::discriminant(tag).hash(&mut hasher);
+        }
+        for related in diagnostic.related_information.iter().flatten() {
+            related.location.uri.hash(&mut hasher);
+            hash_range(&related.location.range, &mut hasher);
+            related.message.hash(&mut hasher);
+        }
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn hash_range(
+    range: &Range,
+    hasher: &mut FxHasher,
+) {
+    let Range { start, end } = range;
+    (start.line, start.character, end.line, end.character).hash(hasher);
+}
+
 pub(crate) fn handle_inlay_hints(
     snap: GlobalStateSnapshot,
     parameters: InlayHintParams,
@@ -393,7 +1366,10 @@ pub(crate) fn publish_diagnostics(
     file_id: FileId,
 ) -> Result<Vec<Diagnostic>> {
     let line_index = snapshot.file_line_index(file_id)?;
-    let diagnostics = snapshot.analysis.diagnostics(config, file_id)?;
+    let diagnostics =
+        snapshot
+            .analysis
+            .diagnostics(config, &AssistResolveStrategy::None, file_id)?;
 
     diagnostics
         .into_iter()
@@ -487,3 +1463,218 @@ mod diff {
         builder.finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crossbeam_channel::unbounded;
+    use expect_test::expect;
+    use itertools::Itertools as _;
+    use lsp_types::{
+        ClientCapabilities, Code, Diagnostic, DiagnosticSeverity, Position, Range, Uri,
+        WorkspaceDocumentDiagnosticReport,
+    };
+    use vfs::{AbsPathBuf, VfsPath};
+
+    use super::{
+        WorkspaceDiagnosticsProgressGuard, diagnostics_result_id, renamed_files,
+        workspace_document_report,
+    };
+    use crate::{
+        config::Config,
+        global_state::GlobalState,
+        main_loop::{Task, WorkspaceDiagnosticsProgress},
+    };
+
+    fn diagnostic(
+        line: u32,
+        message: &str,
+    ) -> Diagnostic {
+        Diagnostic {
+            range: Range::new(Position::new(line, 0), Position::new(line, 4)),
+            severity: Some(DiagnosticSeverity::Error),
+            code: Some(Code::String("syntax-error".to_owned())),
+            code_description: None,
+            source: Some("wgsl-analyzer".to_owned()),
+            message: message.to_owned().into(),
+            related_information: None,
+            tags: None,
+            data: None,
+        }
+    }
+
+    fn uri() -> Uri {
+        Uri::parse("file:///workspace/main.wgsl").unwrap()
+    }
+
+    /// The result id of a full report, or `None` if the report is unchanged.
+    fn full_result_id(report: &WorkspaceDocumentDiagnosticReport) -> Option<String> {
+        match report {
+            WorkspaceDocumentDiagnosticReport::WorkspaceFullDocumentDiagnosticReport(report) => {
+                report.full_document_diagnostic_report.result_id.clone()
+            },
+            WorkspaceDocumentDiagnosticReport::WorkspaceUnchangedDocumentDiagnosticReport(_) => {
+                None
+            },
+        }
+    }
+
+    #[test]
+    fn result_id_depends_on_diagnostics() {
+        let first = vec![diagnostic(1, "expected `;`")];
+        assert_eq!(
+            diagnostics_result_id(&first),
+            diagnostics_result_id(&[diagnostic(1, "expected `;`")])
+        );
+        assert_ne!(
+            diagnostics_result_id(&first),
+            diagnostics_result_id(&[diagnostic(2, "expected `;`")])
+        );
+        assert_ne!(
+            diagnostics_result_id(&first),
+            diagnostics_result_id(&[diagnostic(1, "expected `}`")])
+        );
+        assert_ne!(diagnostics_result_id(&first), diagnostics_result_id(&[]));
+    }
+
+    #[test]
+    fn unchanged_diagnostics_are_reported_as_unchanged() {
+        let diagnostics = vec![diagnostic(1, "expected `;`")];
+        let report = workspace_document_report(uri(), Some(1), diagnostics.clone(), None);
+        let result_id = full_result_id(&report).unwrap();
+
+        let report = workspace_document_report(uri(), Some(1), diagnostics, Some(&result_id));
+        match report {
+            WorkspaceDocumentDiagnosticReport::WorkspaceUnchangedDocumentDiagnosticReport(
+                report,
+            ) => {
+                assert_eq!(
+                    report.unchanged_document_diagnostic_report.result_id,
+                    result_id
+                );
+            },
+            WorkspaceDocumentDiagnosticReport::WorkspaceFullDocumentDiagnosticReport(_) => {
+                panic!("expected an unchanged report")
+            },
+        }
+    }
+
+    #[test]
+    fn changed_diagnostics_are_reported_in_full() {
+        let report =
+            workspace_document_report(uri(), Some(1), vec![diagnostic(1, "expected `;`")], None);
+        let result_id = full_result_id(&report).unwrap();
+
+        let report = workspace_document_report(uri(), Some(2), Vec::new(), Some(&result_id));
+        match report {
+            WorkspaceDocumentDiagnosticReport::WorkspaceFullDocumentDiagnosticReport(report) => {
+                assert_eq!(report.version, Some(2));
+                assert!(report.full_document_diagnostic_report.items.is_empty());
+                assert_ne!(
+                    report.full_document_diagnostic_report.result_id,
+                    Some(result_id)
+                );
+            },
+            WorkspaceDocumentDiagnosticReport::WorkspaceUnchangedDocumentDiagnosticReport(_) => {
+                panic!("expected a full report")
+            },
+        }
+    }
+
+    #[test]
+    fn progress_guard_reports_begin_and_end() {
+        let (sender, receiver) = unbounded();
+        {
+            let progress = WorkspaceDiagnosticsProgressGuard::begin(&sender, 2);
+            progress.report(1);
+        }
+        let progress = receiver
+            .try_iter()
+            .map(|task| match task {
+                Task::WorkspaceDiagnostics(WorkspaceDiagnosticsProgress::Begin { total }) => {
+                    format!("begin {total}")
+                },
+                Task::WorkspaceDiagnostics(WorkspaceDiagnosticsProgress::Report { checked }) => {
+                    format!("report {checked}")
+                },
+                Task::WorkspaceDiagnostics(WorkspaceDiagnosticsProgress::End) => "end".to_owned(),
+                Task::Response(_)
+                | Task::Retry(_)
+                | Task::Diagnostics(_)
+                | Task::PrimeCaches(_)
+                | Task::DiscoverProject(_) => "other".to_owned(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(progress, ["begin 2", "report 1", "end"]);
+    }
+
+    #[test]
+    fn progress_guard_ignores_closed_channel() {
+        let (sender, receiver) = unbounded();
+        drop(receiver);
+        let progress = WorkspaceDiagnosticsProgressGuard::begin(&sender, 1);
+        progress.report(0);
+        drop(progress);
+    }
+
+    #[test]
+    fn renaming_folder_moves_modules_inside() {
+        let base = AbsPathBuf::try_from(env!("CARGO_MANIFEST_DIR")).unwrap();
+        let (sender, _receiver) = unbounded();
+        let config = Config::new(
+            base.clone(),
+            ClientCapabilities::default(),
+            vec![base.clone()],
+            None,
+        );
+        let state = GlobalState::new(sender, config);
+        for path in [
+            "shaders/main.wesl",
+            "shaders/lighting.wesl",
+            "shaders/lighting/brdf.wesl",
+            "shaders/lighting/sky.wesl",
+            "shaders/lighting/models/ggx.wesl",
+            "shaders/lighting_old/brdf.wesl",
+        ] {
+            state
+                .vfs
+                .write()
+                .0
+                .set_file_contents(VfsPath::from(base.join(path)), Some(Vec::new()));
+        }
+
+        let snapshot = state.snapshot();
+        let renames = renamed_files(
+            &snapshot,
+            &VfsPath::from(base.join("shaders/lighting")),
+            &VfsPath::from(base.join("shaders/shading")),
+        );
+        let vfs = state.vfs.read();
+        let relative = |path: &VfsPath| {
+            path.as_path()
+                .unwrap()
+                .strip_prefix(&base)
+                .unwrap()
+                .as_utf8_path()
+                .components()
+                .map(|component| component.as_str())
+                .join("/")
+        };
+        let actual: String = renames
+            .iter()
+            .map(|(file_id, new_path)| {
+                format!(
+                    "{} -> {}\n",
+                    relative(vfs.0.file_path(*file_id)),
+                    relative(new_path)
+                )
+            })
+            .sorted()
+            .collect();
+        expect![[r#"
+            shaders/lighting/brdf.wesl -> shaders/shading/brdf.wesl
+            shaders/lighting/models/ggx.wesl -> shaders/shading/models/ggx.wesl
+            shaders/lighting/sky.wesl -> shaders/shading/sky.wesl
+        "#]]
+        .assert_eq(&actual);
+    }
+}
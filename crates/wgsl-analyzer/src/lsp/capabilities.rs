@@ -1,23 +1,26 @@
 use ide_completion::CompletionFieldsToResolve;
 use line_index::WideEncoding;
 use lsp_types::{
-    ChangeNotifications, ClientCapabilities as LspClientCapabilities, CodeActionKind,
-    CodeActionOptions, CodeActionProvider, CompletionOptions, DefinitionProvider,
-    DiagnosticOptions, DiagnosticProvider, DocumentFormattingProvider,
-    DocumentOnTypeFormattingOptions, DocumentRangeFormattingProvider, FileOperationFilter,
-    FileOperationOptions, FileOperationPattern, FileOperationPatternKind,
-    FileOperationRegistrationOptions, FoldingRangeProvider, HoverProvider, InlayHintOptions,
-    InlayHintProvider, MarkupKind, PositionEncodingKind, ResourceOperationKind, Save, SaveOptions,
-    SelectionRangeProvider, ServerCapabilities, ServerCompletionItemOptions, SignatureHelpOptions,
-    TextDocumentSync, TextDocumentSyncKind, TextDocumentSyncOptions, WorkDoneProgressOptions,
-    WorkspaceFoldersServerCapabilities, WorkspaceOptions,
+    CallHierarchyProvider, ChangeNotifications, ClientCapabilities as LspClientCapabilities,
+    CodeActionKind, CodeActionOptions, CodeActionProvider, CodeLensOptions, CompletionOptions,
+    DeclarationProvider, DefinitionProvider, DiagnosticOptions, DiagnosticProvider,
+    DocumentFormattingProvider, DocumentHighlightProvider, DocumentLinkOptions,
+    DocumentOnTypeFormattingOptions, DocumentRangeFormattingProvider, DocumentSymbolProvider,
+    FileOperationFilter, FileOperationOptions, FileOperationPattern, FileOperationPatternKind,
+    FileOperationRegistrationOptions, FoldingRangeProvider, Full, HoverProvider, InlayHintOptions,
+    InlayHintProvider, MarkupKind, PositionEncodingKind, ReferencesProvider, RenameOptions,
+    RenameProvider, ResourceOperationKind, Save, SaveOptions, SelectionRangeProvider,
+    SemanticTokensFullDelta, SemanticTokensOptions, SemanticTokensProvider, ServerCapabilities,
+    ServerCompletionItemOptions, SignatureHelpOptions, TextDocumentSync, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TypeDefinitionProvider, WorkDoneProgressOptions,
+    WorkspaceFoldersServerCapabilities, WorkspaceOptions, WorkspaceSymbolProvider,
 };
 use rustc_hash::FxHashSet;
 
 use crate::{
     config::{Config, WgslfmtConfig},
     line_index::PositionEncoding,
-    lsp::extensions,
+    lsp::{extensions, semantic_tokens},
 };
 
 /// # Panics
@@ -51,29 +54,31 @@ pub fn server_capabilities(config: &Config) -> ServerCapabilities {
                 ),
             }),
             resolve_provider: None,
-            trigger_characters: Some(vec![".".to_owned()]),
+            trigger_characters: Some(vec![".".to_owned(), "@".to_owned()]),
             all_commit_characters: None,
             work_done_progress_options: WorkDoneProgressOptions {
                 work_done_progress: None,
             },
         }),
-        declaration_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/339
+        declaration_provider: Some(DeclarationProvider::Bool(true)),
         definition_provider: Some(DefinitionProvider::Bool(true)),
-        type_definition_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/340
-        implementation_provider: None,  // WGSL does not have "implementations"
-        references_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/347
-        document_highlight_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/348
-        document_symbol_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/349
-        workspace_symbol_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/350
-        code_action_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/351
-        code_lens_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/352
+        type_definition_provider: Some(TypeDefinitionProvider::Bool(true)),
+        implementation_provider: None, // WGSL does not have "implementations"
+        references_provider: Some(ReferencesProvider::Bool(true)),
+        document_highlight_provider: Some(DocumentHighlightProvider::Bool(true)),
+        document_symbol_provider: Some(DocumentSymbolProvider::Bool(true)),
+        workspace_symbol_provider: Some(WorkspaceSymbolProvider::Bool(true)),
+        code_action_provider: Some(config.client_capabilities().code_action_capabilities()),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
         document_formatting_provider: Some(DocumentFormattingProvider::Bool(true)),
         document_range_formatting_provider: match config.wgslfmt(None) {
-            WgslfmtConfig::Wgslfmt {
-                enable_range_formatting: true,
-                ..
-            } => Some(DocumentRangeFormattingProvider::Bool(true)),
-            WgslfmtConfig::CustomCommand { .. } | WgslfmtConfig::Wgslfmt { .. } => {
+            WgslfmtConfig::Wgslfmt { .. } => Some(DocumentRangeFormattingProvider::Bool(true)),
+            WgslfmtConfig::CustomCommand { .. } => {
                 Some(DocumentRangeFormattingProvider::Bool(false))
             },
         },
@@ -87,10 +92,20 @@ pub fn server_capabilities(config: &Config) -> ServerCapabilities {
         }),
         selection_range_provider: Some(SelectionRangeProvider::Bool(true)),
         folding_range_provider: Some(FoldingRangeProvider::Bool(true)),
-        rename_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/346
+        rename_provider: Some(RenameProvider::RenameOptions(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        })),
         linked_editing_range_provider: None, // Not relevant
-        document_link_provider: None, // Not relevant
-        color_provider: None,  // Not relevant
+        document_link_provider: Some(DocumentLinkOptions {
+            resolve_provider: None,
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
+        color_provider: None,           // Not relevant
         execute_command_provider: None, // Not relevant
         workspace: Some(WorkspaceOptions {
             workspace_folders: Some(WorkspaceFoldersServerCapabilities {
@@ -126,9 +141,20 @@ pub fn server_capabilities(config: &Config) -> ServerCapabilities {
             }),
             text_document_content: None,
         }),
-        call_hierarchy_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/343
-        semantic_tokens_provider: None, // TODO https://github.com/wgsl-analyzer/wgsl-analyzer/issues/342
-        moniker_provider: None,         // Not relevant
+        call_hierarchy_provider: Some(CallHierarchyProvider::Bool(true)),
+        semantic_tokens_provider: Some(SemanticTokensProvider::SemanticTokensOptions(
+            SemanticTokensOptions {
+                legend: semantic_tokens::legend(),
+                range: None,
+                full: Some(Full::SemanticTokensFullDelta(SemanticTokensFullDelta {
+                    delta: Some(true),
+                })),
+                work_done_progress_options: WorkDoneProgressOptions {
+                    work_done_progress: None,
+                },
+            },
+        )),
+        moniker_provider: None, // Not relevant
         inlay_hint_provider: Some(InlayHintProvider::InlayHintOptions(InlayHintOptions {
             work_done_progress_options: WorkDoneProgressOptions {
                 work_done_progress: None,
@@ -140,8 +166,7 @@ pub fn server_capabilities(config: &Config) -> ServerCapabilities {
         diagnostic_provider: Some(DiagnosticProvider::DiagnosticOptions(DiagnosticOptions {
             identifier: Some("wgsl-analyzer".to_owned()),
             inter_file_dependencies: true,
-            // FIXME
-            workspace_diagnostics: false,
+            workspace_diagnostics: true,
             work_done_progress_options: WorkDoneProgressOptions {
                 work_done_progress: None,
             },
@@ -16,11 +16,10 @@ use camino::Utf8PathBuf;
 use lsp_types::{
     ChangeAnnotation, ChangeAnnotationIdentifier, CodeActionKind, CodeActionParams, Command,
     DefinitionResponse, DocumentOnTypeFormattingParams, Hover, HoverRequest as LspHoverRequest,
-    ImplementationParams, InsertTextFormat, LocationLink, LspNotificationMethod, LspRequestMethod,
-    MessageDirection, Notification, OptionalVersionedTextDocumentIdentifier, PartialResultParams,
-    Position, Range, Request, ResourceOperation, TextDocumentIdentifier,
-    TextDocumentPositionParams, TextEdit, Uri, WorkDoneProgressParams, WorkspaceEdit,
-    WorkspaceSymbolResponse,
+    InsertTextFormat, LocationLink, LspNotificationMethod, LspRequestMethod, MessageDirection,
+    Notification, OptionalVersionedTextDocumentIdentifier, PartialResultParams, Position, Range,
+    Request, ResourceOperation, TextDocumentIdentifier, TextDocumentPositionParams, TextEdit, Uri,
+    WorkDoneProgressParams, WorkspaceEdit, WorkspaceSymbolResponse,
 };
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
@@ -802,14 +801,14 @@ pub struct OpenCargoTomlParameters {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CodeLensResolveData {
-    pub version: i32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub version: Option<i32>,
     pub kind: CodeLensResolveDataKind,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum CodeLensResolveDataKind {
-    Impls(ImplementationParams),
     References(TextDocumentPositionParams),
 }
 
@@ -2,8 +2,11 @@ use std::str::FromStr as _;
 
 use anyhow::format_err;
 use base_db::{FilePosition, FileRange, TextRange, TextSize};
+use ide::{Annotation, AnnotationKind, AssistKind};
 use line_index::{LineCol, WideLineCol};
-use lsp_types::{Position, Range, TextDocumentIdentifier, TextDocumentPositionParams, Uri};
+use lsp_types::{
+    CodeActionKind, Position, Range, TextDocumentIdentifier, TextDocumentPositionParams, Uri,
+};
 use paths::Utf8PathBuf;
 use vfs::{AbsPathBuf, FileId};
 
@@ -11,6 +14,7 @@ use crate::{
     Result,
     global_state::GlobalStateSnapshot,
     line_index::{LineIndex, PositionEncoding},
+    lsp::extensions::{CodeLensResolveData, CodeLensResolveDataKind},
     try_default,
 };
 
@@ -111,3 +115,41 @@ pub(crate) fn file_range_uri(
     let range = text_range(&line_index, range)?;
     Ok(Some(FileRange { file_id, range }))
 }
+
+#[expect(
+    clippy::wildcard_enum_match_arm,
+    reason = "only the kinds of assists have a counterpart"
+)]
+pub(crate) fn assist_kind(kind: CodeActionKind) -> Option<AssistKind> {
+    let assist_kind = match kind {
+        CodeActionKind::QuickFix => AssistKind::QuickFix,
+        CodeActionKind::Refactor => AssistKind::Refactor,
+        CodeActionKind::RefactorRewrite => AssistKind::RefactorRewrite,
+        _ => return None,
+    };
+    Some(assist_kind)
+}
+
+/// Returns `None` if the file was excluded or has changed since the code lens was computed.
+pub(crate) fn annotation(
+    snap: &GlobalStateSnapshot,
+    range: Range,
+    data: CodeLensResolveData,
+) -> anyhow::Result<Option<Annotation>> {
+    match data.kind {
+        CodeLensResolveDataKind::References(parameters) => {
+            let position = try_default!(file_position(snap, &parameters)?);
+            if snap.file_version(position.file_id) != data.version {
+                return Ok(None);
+            }
+            let line_index = snap.file_line_index(position.file_id)?;
+            Ok(Some(Annotation {
+                range: text_range(&line_index, range)?,
+                kind: AnnotationKind::HasReferences {
+                    position,
+                    data: None,
+                },
+            }))
+        },
+    }
+}
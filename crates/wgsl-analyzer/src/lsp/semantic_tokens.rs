@@ -0,0 +1,320 @@
+//! Semantic Tokens helpers
+
+use std::ops;
+
+use lsp_types::{Range, SemanticTokens, SemanticTokensEdit, SemanticTokensLegend};
+
+macro_rules! define_semantic_token_types {
+    (
+        standard {
+            $($standard:ident => $standard_string:literal),* $(,)?
+        }
+        custom {
+            $($custom:ident => $custom_string:literal),* $(,)?
+        }
+    ) => {
+        pub(crate) mod types {
+            $(pub(crate) const $standard: &str = $standard_string;)*
+            $(pub(crate) const $custom: &str = $custom_string;)*
+        }
+
+        pub(crate) const SUPPORTED_TYPES: &[&str] = &[
+            $(self::types::$standard,)*
+            $(self::types::$custom,)*
+        ];
+    };
+}
+
+define_semantic_token_types![
+    standard {
+        COMMENT => "comment",
+        DECORATOR => "decorator",
+        ENUM_MEMBER => "enumMember",
+        FUNCTION => "function",
+        KEYWORD => "keyword",
+        NAMESPACE => "namespace",
+        NUMBER => "number",
+        PARAMETER => "parameter",
+        PROPERTY => "property",
+        STRUCT => "struct",
+        VARIABLE => "variable",
+    }
+    custom {
+        BOOLEAN => "boolean",
+        BUILTIN_TYPE => "builtinType",
+        TYPE_ALIAS => "typeAlias",
+    }
+];
+
+macro_rules! define_semantic_token_modifiers {
+    (
+        standard {
+            $($standard:ident => $standard_string:literal),* $(,)?
+        }
+        custom {
+            $($custom:ident => $custom_string:literal),* $(,)?
+        }
+    ) => {
+        pub(crate) mod modifiers {
+            $(pub(crate) const $standard: &str = $standard_string;)*
+            $(pub(crate) const $custom: &str = $custom_string;)*
+        }
+
+        pub(crate) const SUPPORTED_MODIFIERS: &[&str] = &[
+            $(self::modifiers::$standard,)*
+            $(self::modifiers::$custom,)*
+        ];
+    };
+}
+
+define_semantic_token_modifiers![
+    standard {
+        DECLARATION => "declaration",
+        DEFAULT_LIBRARY => "defaultLibrary",
+        READONLY => "readonly",
+    }
+    custom {
+        IMPORTED => "imported",
+        PRIVATE => "private",
+        WORKGROUP => "workgroup",
+        UNIFORM => "uniform",
+        STORAGE => "storage",
+        READ_WRITE => "readWrite",
+    }
+];
+
+#[derive(Default)]
+pub(crate) struct ModifierSet(pub(crate) u32);
+
+impl ops::BitOrAssign<&str> for ModifierSet {
+    fn bitor_assign(
+        &mut self,
+        rhs: &str,
+    ) {
+        if let Some(index) = SUPPORTED_MODIFIERS
+            .iter()
+            .position(|modifier| *modifier == rhs)
+        {
+            self.0 |= 1 << index;
+        }
+    }
+}
+
+pub(crate) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: SUPPORTED_TYPES
+            .iter()
+            .map(|&r#type| r#type.to_owned())
+            .collect(),
+        token_modifiers: SUPPORTED_MODIFIERS
+            .iter()
+            .map(|&modifier| modifier.to_owned())
+            .collect(),
+    }
+}
+
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    reason = "the legend has far fewer than u32::MAX entries"
+)]
+pub(crate) fn type_index(r#type: &str) -> u32 {
+    SUPPORTED_TYPES
+        .iter()
+        .position(|supported| *supported == r#type)
+        .unwrap() as u32
+}
+
+/// Tokens are encoded relative to each other.
+///
+/// This is a direct port of <https://github.com/microsoft/vscode-languageserver-node/blob/f425af9de46a0187adb78ec8a46b9b2ce80c5412/server/src/sematicTokens.proposed.ts#L45>
+pub(crate) struct SemanticTokensBuilder {
+    id: String,
+    previous_line: u32,
+    previous_character: u32,
+    data: Vec<u32>,
+}
+
+impl SemanticTokensBuilder {
+    pub(crate) const fn new(id: String) -> Self {
+        Self {
+            id,
+            previous_line: 0,
+            previous_character: 0,
+            data: Vec::new(),
+        }
+    }
+
+    /// Pushes a new token onto the builder.
+    ///
+    /// The range must not span multiple lines.
+    pub(crate) fn push(
+        &mut self,
+        range: Range,
+        token_index: u32,
+        modifier_bitset: u32,
+    ) {
+        let mut delta_line = range.start.line;
+        let mut delta_start = range.start.character;
+
+        if !self.data.is_empty() {
+            delta_line -= self.previous_line;
+            if delta_line == 0 {
+                delta_start -= self.previous_character;
+            }
+        }
+
+        let token_length = range.end.character - range.start.character;
+        self.data.extend([
+            delta_line,
+            delta_start,
+            token_length,
+            token_index,
+            modifier_bitset,
+        ]);
+
+        self.previous_line = range.start.line;
+        self.previous_character = range.start.character;
+    }
+
+    pub(crate) fn build(self) -> SemanticTokens {
+        SemanticTokens {
+            result_id: Some(self.id),
+            data: self.data,
+        }
+    }
+}
+
+/// The number of integers encoding a single token.
+const TOKEN_LENGTH: usize = 5;
+
+/// Computes the edit turning the `old` tokens into the `new` ones.
+///
+/// Only the differing middle part is replaced, as tokens of an edited file
+/// rarely change outside of the edited region.
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    reason = "token data of a single file fits into u32"
+)]
+pub(crate) fn diff_tokens(
+    old: &[u32],
+    new: &[u32],
+) -> Vec<SemanticTokensEdit> {
+    let offset = new
+        .chunks(TOKEN_LENGTH)
+        .zip(old.chunks(TOKEN_LENGTH))
+        .take_while(|(new, old)| new == old)
+        .count()
+        * TOKEN_LENGTH;
+
+    let (_, old) = old.split_at(offset);
+    let (_, new) = new.split_at(offset);
+
+    let offset_from_end = new
+        .rchunks(TOKEN_LENGTH)
+        .zip(old.rchunks(TOKEN_LENGTH))
+        .take_while(|(new, old)| new == old)
+        .count()
+        * TOKEN_LENGTH;
+
+    let (old, _) = old.split_at(old.len() - offset_from_end);
+    let (new, _) = new.split_at(new.len() - offset_from_end);
+
+    if old.is_empty() && new.is_empty() {
+        vec![]
+    } else {
+        // The lsp data field is actually a byte-diff but we
+        // travel in tokens so `start` and `delete_count` are in multiples of the
+        // serialized size of `SemanticToken`.
+        vec![SemanticTokensEdit {
+            start: offset as u32,
+            delete_count: old.len() as u32,
+            data: Some(new.to_vec()),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from(token: (u32, u32, u32, u32, u32)) -> [u32; TOKEN_LENGTH] {
+        [token.0, token.1, token.2, token.3, token.4]
+    }
+
+    #[test]
+    fn diff_insert_at_end() {
+        let before = [from((1, 2, 3, 4, 5)), from((6, 7, 8, 9, 10))].concat();
+        let after = [
+            from((1, 2, 3, 4, 5)),
+            from((6, 7, 8, 9, 10)),
+            from((11, 12, 13, 14, 15)),
+        ]
+        .concat();
+
+        let edits = diff_tokens(&before, &after);
+        assert_eq!(
+            edits[0],
+            SemanticTokensEdit {
+                start: 10,
+                delete_count: 0,
+                data: Some(from((11, 12, 13, 14, 15)).to_vec()),
+            }
+        );
+    }
+
+    #[test]
+    fn diff_insert_at_beginning() {
+        let before = [from((1, 2, 3, 4, 5)), from((6, 7, 8, 9, 10))].concat();
+        let after = [
+            from((11, 12, 13, 14, 15)),
+            from((1, 2, 3, 4, 5)),
+            from((6, 7, 8, 9, 10)),
+        ]
+        .concat();
+
+        let edits = diff_tokens(&before, &after);
+        assert_eq!(
+            edits[0],
+            SemanticTokensEdit {
+                start: 0,
+                delete_count: 0,
+                data: Some(from((11, 12, 13, 14, 15)).to_vec()),
+            }
+        );
+    }
+
+    #[test]
+    fn diff_replace_in_middle() {
+        let before = [
+            from((1, 2, 3, 4, 5)),
+            from((6, 7, 8, 9, 10)),
+            from((11, 12, 13, 14, 15)),
+        ]
+        .concat();
+        let after = [
+            from((1, 2, 3, 4, 5)),
+            from((16, 17, 18, 19, 20)),
+            from((21, 22, 23, 24, 25)),
+            from((11, 12, 13, 14, 15)),
+        ]
+        .concat();
+
+        let edits = diff_tokens(&before, &after);
+        assert_eq!(
+            edits[0],
+            SemanticTokensEdit {
+                start: 5,
+                delete_count: 5,
+                data: Some([from((16, 17, 18, 19, 20)), from((21, 22, 23, 24, 25))].concat()),
+            }
+        );
+    }
+
+    #[test]
+    fn diff_unchanged() {
+        let tokens = [from((1, 2, 3, 4, 5)), from((6, 7, 8, 9, 10))].concat();
+        assert!(diff_tokens(&tokens, &tokens).is_empty());
+    }
+}
@@ -1,8 +1,13 @@
-use std::ops::Not as _;
+use std::{
+    ops::Not as _,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 use base_db::{FileRange, TextRange, TextSize};
 use ide::{
-    Cancellable, Fold, FoldKind, InlayHintLabel, NavigationTarget,
+    Annotation, AnnotationKind, Assist, AssistKind, Cancellable, FileSymbolKind, Fold, FoldKind,
+    Highlight, HlMod, HlRange, HlTag, InlayHintLabel, NavigationTarget, ReferenceCategory,
+    StructureNodeKind,
     inlay_hints::{
         InlayFieldsToResolve, InlayHint as IdeInlayHint,
         InlayHintLabelPart as IdeInlayHintLabelPart, InlayKind, LazyProperty,
@@ -16,17 +21,22 @@ use ide_completion::{
         CompletionRelevance,
     },
 };
-use ide_db::text_edit::{InsertDelete, TextEdit as IdeTextEdit};
+use ide_db::{
+    source_change::SourceChange,
+    text_edit::{InsertDelete, TextEdit as IdeTextEdit},
+};
 use itertools::Itertools as _;
 use lsp_types::{
-    ActiveParameter, CompletionItem as LspCompletionItem,
-    CompletionItemKind as LspCompletionItemKind, CompletionItemLabelDetails, CompletionItemTag,
-    CompletionItemTextEdit, Definition, DefinitionResponse, Documentation, FoldingRange,
-    FoldingRangeKind, InlayHint as LspInlayHint, InlayHintKind,
-    InlayHintLabelPart as LspInlayHintLabelPart, InsertReplaceEdit, InsertTextFormat, Label,
-    Location, LocationLink, MarkupContent, MarkupKind, ParameterInformation,
-    ParameterInformationLabel, Position, Range, SignatureHelp as LspSignatureHelp,
-    SignatureInformation, TextDocumentPositionParams, TextEdit as LspTextEdit, Tooltip, Uri,
+    ActiveParameter, CallHierarchyItem, CodeActionKind, CodeActionParams, CodeLens,
+    CompletionItem as LspCompletionItem, CompletionItemKind as LspCompletionItemKind,
+    CompletionItemLabelDetails, CompletionItemTag, CompletionItemTextEdit, Definition,
+    DefinitionResponse, DocumentHighlightKind, Documentation, FoldingRange, FoldingRangeKind,
+    InlayHint as LspInlayHint, InlayHintKind, InlayHintLabelPart as LspInlayHintLabelPart,
+    InsertReplaceEdit, InsertTextFormat, Label, Location, LocationLink, MarkupContent, MarkupKind,
+    OptionalVersionedTextDocumentIdentifier, ParameterInformation, ParameterInformationLabel,
+    Position, Range, SemanticTokens, SemanticTokensDelta, SignatureHelp as LspSignatureHelp,
+    SignatureInformation, SymbolKind, TextDocumentPositionParams, TextEdit as LspTextEdit, Tooltip,
+    Uri, WorkspaceEdit,
 };
 use paths::{AbsPath, Utf8Component, Utf8Prefix};
 use rustc_hash::FxHasher;
@@ -38,7 +48,7 @@ use crate::{
     config::Config,
     global_state::GlobalStateSnapshot,
     line_index::{LineEndings, LineIndex, PositionEncoding},
-    lsp,
+    lsp::{self, semantic_tokens},
 };
 
 pub(crate) fn folding_range(
@@ -99,6 +109,39 @@ pub(crate) fn folding_range(
     }
 }
 
+pub(crate) const fn document_highlight_kind(
+    category: ReferenceCategory
+) -> Option<DocumentHighlightKind> {
+    match category {
+        ReferenceCategory::Read => Some(DocumentHighlightKind::Read),
+        ReferenceCategory::Write => Some(DocumentHighlightKind::Write),
+        ReferenceCategory::Import => None,
+    }
+}
+
+pub(crate) const fn structure_node_kind(kind: StructureNodeKind) -> SymbolKind {
+    match kind {
+        StructureNodeKind::Function => SymbolKind::Function,
+        StructureNodeKind::Struct => SymbolKind::Struct,
+        StructureNodeKind::Field => SymbolKind::Field,
+        StructureNodeKind::GlobalVariable => SymbolKind::Variable,
+        StructureNodeKind::Constant | StructureNodeKind::Override => SymbolKind::Constant,
+        StructureNodeKind::TypeAlias => SymbolKind::TypeParameter,
+        StructureNodeKind::Import => SymbolKind::Module,
+        StructureNodeKind::Conditional => SymbolKind::Namespace,
+    }
+}
+
+pub(crate) const fn symbol_kind(kind: FileSymbolKind) -> SymbolKind {
+    match kind {
+        FileSymbolKind::Function => SymbolKind::Function,
+        FileSymbolKind::Struct => SymbolKind::Struct,
+        FileSymbolKind::GlobalVariable => SymbolKind::Variable,
+        FileSymbolKind::GlobalConstant | FileSymbolKind::Override => SymbolKind::Constant,
+        FileSymbolKind::TypeAlias => SymbolKind::TypeParameter,
+    }
+}
+
 /// Returns a [`Uri`] object from a given path, will lowercase drive letters if present.
 /// This will only happen when processing windows paths.
 ///
@@ -175,6 +218,95 @@ pub(crate) fn location(
     Ok(location)
 }
 
+pub(crate) fn code_lens(
+    accumulator: &mut Vec<CodeLens>,
+    snap: &GlobalStateSnapshot,
+    annotation: Annotation,
+) -> Cancellable<()> {
+    match annotation.kind {
+        AnnotationKind::HasReferences {
+            position: file_position,
+            data,
+        } => {
+            let line_index = snap.file_line_index(file_position.file_id)?;
+            let annotation_range = range(&line_index, annotation.range);
+            let url = url(snap, file_position.file_id);
+            let lsp_position = position(&line_index, file_position.offset);
+
+            let Some(references) = data else {
+                let resolve_data = lsp::extensions::CodeLensResolveData {
+                    version: snap.file_version(file_position.file_id),
+                    kind: lsp::extensions::CodeLensResolveDataKind::References(
+                        TextDocumentPositionParams {
+                            text_document: lsp_types::TextDocumentIdentifier { uri: url },
+                            position: lsp_position,
+                        },
+                    ),
+                };
+                accumulator.push(CodeLens {
+                    range: annotation_range,
+                    command: None,
+                    data: Some(to_value(resolve_data).unwrap()),
+                });
+                return Ok(());
+            };
+            let title = match references.len() {
+                1 => "1 reference".to_owned(),
+                count => format!("{count} references"),
+            };
+            let locations = references
+                .into_iter()
+                .filter_map(|frange| location(snap, frange).ok())
+                .collect();
+            accumulator.push(CodeLens {
+                range: annotation_range,
+                command: Some(command::show_references(
+                    title,
+                    &url,
+                    lsp_position,
+                    locations,
+                )),
+                data: None,
+            });
+        },
+        AnnotationKind::HasResources {
+            position: file_position,
+            resources,
+        } => {
+            let line_index = snap.file_line_index(file_position.file_id)?;
+            let url = url(snap, file_position.file_id);
+            let title = if resources.is_empty() {
+                "0 resources".to_owned()
+            } else {
+                resources
+                    .iter()
+                    .map(|resource| {
+                        format!(
+                            "@group({}) @binding({}) {}",
+                            resource.group, resource.binding, resource.name
+                        )
+                    })
+                    .join(", ")
+            };
+            let locations = resources
+                .into_iter()
+                .filter_map(|resource| location(snap, resource.declaration).ok())
+                .collect();
+            accumulator.push(CodeLens {
+                range: range(&line_index, annotation.range),
+                command: Some(command::show_references(
+                    title,
+                    &url,
+                    position(&line_index, file_position.offset),
+                    locations,
+                )),
+                data: None,
+            });
+        },
+    }
+    Ok(())
+}
+
 pub(crate) fn completion_items(
     config: &Config,
     fields_to_resolve: CompletionFieldsToResolve,
@@ -430,6 +562,7 @@ pub(crate) const fn completion_item_kind(
         IdeCompletionItemKind::TypeAlias | IdeCompletionItemKind::Struct => {
             LspCompletionItemKind::Struct
         },
+        IdeCompletionItemKind::Attribute => LspCompletionItemKind::Property,
         IdeCompletionItemKind::Builtin(kind) => match kind {
             ide_completion::item::BuiltInKind::Declaration => LspCompletionItemKind::Value,
             ide_completion::item::BuiltInKind::Enumerant => LspCompletionItemKind::EnumMember,
@@ -465,6 +598,117 @@ pub(crate) fn text_edit_vec(
         .collect()
 }
 
+pub(crate) fn snippet_text_edit_vec(
+    line_index: &LineIndex,
+    is_snippet: bool,
+    text_edit: IdeTextEdit,
+) -> Vec<lsp::extensions::SnippetTextEdit> {
+    text_edit_vec(line_index, text_edit)
+        .into_iter()
+        .map(|edit| lsp::extensions::SnippetTextEdit {
+            range: edit.range,
+            new_text: edit.new_text,
+            insert_text_format: is_snippet.then_some(InsertTextFormat::SNIPPET),
+            annotation_id: None,
+        })
+        .collect()
+}
+
+pub(crate) fn workspace_edit(
+    snap: &GlobalStateSnapshot,
+    source_change: SourceChange,
+) -> Cancellable<WorkspaceEdit> {
+    let mut changes = Vec::with_capacity(source_change.source_file_edits.len());
+    for (file_id, edit) in source_change.source_file_edits {
+        let line_index = snap.file_line_index(file_id)?;
+        changes.push((url(snap, file_id), text_edit_vec(&line_index, edit)));
+    }
+    Ok(WorkspaceEdit {
+        changes: Some(changes.into_iter().collect()),
+        document_changes: None,
+        change_annotations: None,
+    })
+}
+
+pub(crate) fn snippet_workspace_edit(
+    snap: &GlobalStateSnapshot,
+    source_change: SourceChange,
+) -> Cancellable<lsp::extensions::SnippetWorkspaceEdit> {
+    let is_snippet = source_change.is_snippet;
+    let mut document_changes = Vec::with_capacity(source_change.source_file_edits.len());
+    for (file_id, edit) in source_change.source_file_edits {
+        let line_index = snap.file_line_index(file_id)?;
+        let edits = snippet_text_edit_vec(&line_index, is_snippet, edit);
+        document_changes.push(lsp::extensions::SnippetDocumentChangeOperation::Edit(
+            lsp::extensions::SnippetTextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier {
+                    uri: url(snap, file_id),
+                    version: snap.file_version(file_id),
+                },
+                edits,
+            },
+        ));
+    }
+    Ok(lsp::extensions::SnippetWorkspaceEdit {
+        changes: None,
+        document_changes: Some(document_changes),
+        change_annotations: None,
+    })
+}
+
+pub(crate) const fn code_action_kind(kind: AssistKind) -> CodeActionKind {
+    match kind {
+        AssistKind::QuickFix => CodeActionKind::QuickFix,
+        AssistKind::Refactor => CodeActionKind::Refactor,
+        AssistKind::RefactorRewrite => CodeActionKind::RefactorRewrite,
+    }
+}
+
+/// Converts an assist to a code action.
+///
+/// Unresolved assists carry the data to find them again in `codeAction/resolve`:
+/// their index among the computed assists, the original request and the document version.
+pub(crate) fn code_action(
+    snap: &GlobalStateSnapshot,
+    assist: Assist,
+    resolve_data: Option<(usize, CodeActionParams, Option<i32>)>,
+) -> Cancellable<lsp::extensions::CodeAction> {
+    let mut result = lsp::extensions::CodeAction {
+        title: assist.label,
+        group: assist
+            .group
+            .filter(|_| snap.config.code_action_group())
+            .map(|group| group.0),
+        kind: Some(code_action_kind(assist.id.kind)),
+        edit: None,
+        is_preferred: None,
+        command: None,
+        data: None,
+    };
+    match (assist.source_change, resolve_data) {
+        (Some(change), _) => result.edit = Some(snippet_workspace_edit(snap, change)?),
+        (None, Some((index, code_action_parameters, version))) => {
+            result.data = Some(lsp::extensions::CodeActionData {
+                id: format!(
+                    "{}:{}:{index}:{}",
+                    assist.id.name,
+                    assist.id.kind.name(),
+                    assist
+                        .id
+                        .group_index
+                        .map_or_else(String::new, |group_index| group_index.to_string()),
+                ),
+                code_action_parameters,
+                version,
+            });
+        },
+        (None, None) => {
+            stdx::never!("assist should always be resolved if client can't do lazy resolving");
+        },
+    }
+    Ok(result)
+}
+
 pub(crate) fn completion_text_edit(
     line_index: &LineIndex,
     insert_replace_support: Option<Position>,
@@ -558,7 +802,7 @@ pub(crate) fn inlay_hint(
         kind: match inlay_hint.kind {
             InlayKind::Parameter => Some(InlayHintKind::Parameter),
             InlayKind::Type => Some(InlayHintKind::Type),
-            InlayKind::StructLayout => None,
+            InlayKind::Conversion | InlayKind::StructLayout => None,
         },
         text_edits,
         data,
@@ -683,6 +927,25 @@ fn location_info(
     Ok((target_uri, target_range, target_selection_range))
 }
 
+pub(crate) fn call_hierarchy_item(
+    snap: &GlobalStateSnapshot,
+    target: NavigationTarget,
+    name: String,
+) -> Cancellable<CallHierarchyItem> {
+    let uri = url(snap, target.file_id);
+    let line_index = snap.file_line_index(target.file_id)?;
+    Ok(CallHierarchyItem {
+        name,
+        kind: SymbolKind::Function,
+        tags: None,
+        detail: target.description.clone(),
+        uri,
+        range: range(&line_index, target.full_range),
+        selection_range: range(&line_index, target.focus_or_full_range()),
+        data: None,
+    })
+}
+
 pub(crate) fn goto_definition_response(
     snap: &GlobalStateSnapshot,
     source: Option<FileRange>,
@@ -791,6 +1054,112 @@ pub(crate) fn signature_help(
     }
 }
 
+static TOKEN_RESULT_COUNTER: AtomicU32 = AtomicU32::new(1);
+
+pub(crate) fn semantic_tokens(
+    text: &str,
+    line_index: &LineIndex,
+    highlights: Vec<HlRange>,
+) -> SemanticTokens {
+    let id = TOKEN_RESULT_COUNTER
+        .fetch_add(1, Ordering::SeqCst)
+        .to_string();
+    let mut builder = semantic_tokens::SemanticTokensBuilder::new(id);
+
+    for highlight_range in highlights {
+        let (r#type, modifiers) = semantic_token_type_and_modifiers(highlight_range.highlight);
+        let token_index = semantic_tokens::type_index(r#type);
+
+        for mut text_range in line_index.index.lines(highlight_range.range) {
+            if text[text_range].ends_with('\n') {
+                text_range =
+                    TextRange::new(text_range.start(), text_range.end() - TextSize::of('\n'));
+            }
+            builder.push(range(line_index, text_range), token_index, modifiers.0);
+        }
+    }
+
+    builder.build()
+}
+
+pub(crate) fn semantic_token_delta(
+    previous: &SemanticTokens,
+    current: &SemanticTokens,
+) -> SemanticTokensDelta {
+    let result_id = current.result_id.clone();
+    let edits = semantic_tokens::diff_tokens(&previous.data, &current.data);
+    SemanticTokensDelta { result_id, edits }
+}
+
+fn semantic_token_type_and_modifiers(
+    highlight: Highlight
+) -> (&'static str, semantic_tokens::ModifierSet) {
+    use semantic_tokens::{modifiers, types};
+
+    let mut modifier_set = semantic_tokens::ModifierSet::default();
+    let r#type = match highlight.tag {
+        HlTag::Attribute => types::DECORATOR,
+        HlTag::BoolLiteral => types::BOOLEAN,
+        HlTag::BuiltinType => types::BUILTIN_TYPE,
+        HlTag::Comment => types::COMMENT,
+        HlTag::Constant | HlTag::Local => {
+            modifier_set |= modifiers::READONLY;
+            types::VARIABLE
+        },
+        HlTag::EnumMember => types::ENUM_MEMBER,
+        HlTag::Field => types::PROPERTY,
+        HlTag::Function => types::FUNCTION,
+        HlTag::Keyword => types::KEYWORD,
+        HlTag::Module => types::NAMESPACE,
+        HlTag::NumericLiteral => types::NUMBER,
+        HlTag::Parameter => types::PARAMETER,
+        HlTag::Struct => types::STRUCT,
+        HlTag::TypeAlias => types::TYPE_ALIAS,
+        HlTag::Variable => types::VARIABLE,
+    };
+
+    for modifier in highlight.mods.iter() {
+        modifier_set |= match modifier {
+            HlMod::Declaration => modifiers::DECLARATION,
+            HlMod::DefaultLibrary => modifiers::DEFAULT_LIBRARY,
+            HlMod::Imported => modifiers::IMPORTED,
+            HlMod::Private => modifiers::PRIVATE,
+            HlMod::Workgroup => modifiers::WORKGROUP,
+            HlMod::Uniform => modifiers::UNIFORM,
+            HlMod::Storage => modifiers::STORAGE,
+            HlMod::ReadWrite => modifiers::READ_WRITE,
+        };
+    }
+
+    (r#type, modifier_set)
+}
+
+pub(crate) mod command {
+    use lsp_types::{Command, Location, Position, Uri};
+    use serde_json::to_value;
+
+    /// Shows `locations` in the peek view of the client, next to `position`.
+    pub(crate) fn show_references(
+        title: String,
+        uri: &Uri,
+        position: Position,
+        locations: Vec<Location>,
+    ) -> Command {
+        // `editor.action.showReferences` cannot be used directly, because it takes VS Code
+        // types, which the client converts these arguments to.
+        Command {
+            title,
+            tooltip: None,
+            command: "wgsl-analyzer.showReferences".into(),
+            arguments: Some(vec![
+                to_value(uri).unwrap(),
+                to_value(position).unwrap(),
+                to_value(locations).unwrap(),
+            ]),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ParameterInformation;
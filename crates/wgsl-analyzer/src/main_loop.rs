@@ -13,16 +13,22 @@ use crossbeam_channel::{Receiver, select};
 // use ide_db::base_db::{SourceDatabase, SourceRootDatabase, VfsPath};
 use lsp_server::{Connection, Notification, Request};
 use lsp_types::{
-    CancelNotification, CodeLensRefreshRequest, CompletionRequest, DefinitionRequest, Diagnostic,
+    CallHierarchyIncomingCallsRequest, CallHierarchyOutgoingCallsRequest,
+    CallHierarchyPrepareRequest, CancelNotification, CodeLensRefreshRequest, CodeLensRequest,
+    CodeLensResolveRequest, CompletionRequest, DeclarationRequest, DefinitionRequest, Diagnostic,
     DiagnosticRefreshRequest, DiagnosticServerCancellationData, DidChangeConfigurationNotification,
     DidChangeTextDocumentNotification, DidChangeWatchedFilesNotification,
     DidChangeWorkspaceFoldersNotification, DidCloseTextDocumentNotification,
     DidOpenTextDocumentNotification, DidSaveTextDocumentNotification, DocumentDiagnosticRequest,
-    DocumentFilter, DocumentFormattingRequest, ExitNotification, FoldingRangeRequest,
-    InlayHintRefreshRequest, InlayHintRequest, MessageType, Notification as _, Registration,
-    RegistrationParams, RegistrationRequest, SaveOptions, SemanticTokensRefreshRequest,
-    ShutdownRequest, SignatureHelpRequest, TextDocumentFilter, TextDocumentFilterPattern,
-    TextDocumentRegistrationOptions, TextDocumentSaveRegistrationOptions, Uri,
+    DocumentFilter, DocumentFormattingRequest, DocumentHighlightRequest, DocumentLinkRequest,
+    DocumentRangeFormattingRequest, DocumentSymbolRequest, ExitNotification, FoldingRangeRequest,
+    InlayHintRefreshRequest, InlayHintRequest, MessageType, Notification as _,
+    PrepareRenameRequest, ReferencesRequest, Registration, RegistrationParams, RegistrationRequest,
+    RenameRequest, SaveOptions, SelectionRangeRequest, SemanticTokensDeltaRequest,
+    SemanticTokensRefreshRequest, SemanticTokensRequest, ShutdownRequest, SignatureHelpRequest,
+    TextDocumentFilter, TextDocumentFilterPattern, TextDocumentRegistrationOptions,
+    TextDocumentSaveRegistrationOptions, TypeDefinitionRequest, Uri, WillRenameFilesRequest,
+    WorkspaceDiagnosticRequest,
 };
 use project_model::{PackageKey, ProjectManifest};
 use salsa::{Cancelled, Durability};
@@ -126,6 +132,7 @@ pub(crate) enum Task {
     Diagnostics(DiagnosticsTaskKind),
     // DiscoverTest(lsp::ext::DiscoverTestResults),
     PrimeCaches(PrimeCachesProgress),
+    WorkspaceDiagnostics(WorkspaceDiagnosticsProgress),
 }
 
 #[derive(Debug)]
@@ -134,6 +141,14 @@ pub(crate) enum DiagnosticsTaskKind {
     Semantic(DiagnosticsGeneration, Vec<(FileId, Vec<Diagnostic>)>),
 }
 
+/// Progress of a `workspace/diagnostic` request, reported through the server status.
+#[derive(Debug)]
+pub(crate) enum WorkspaceDiagnosticsProgress {
+    Begin { total: usize },
+    Report { checked: usize },
+    End,
+}
+
 #[derive(Debug)]
 pub(crate) enum PrimeCachesProgress {
     Begin,
@@ -507,7 +522,7 @@ impl GlobalState {
             if client_refresh {
                 // Refresh semantic tokens if the client supports it.
                 if self.config.semantic_tokens_refresh() {
-                    // self.semantic_tokens_cache.lock().clear();
+                    self.semantic_tokens_cache.lock().clear();
                     self.send_request::<SemanticTokensRefreshRequest>((), |_, _| ());
                 }
 
@@ -738,6 +753,17 @@ impl GlobalState {
                 },
                 PrimeCachesProgress::End { .. } => prime_caches_progress.push(progress),
             },
+            Task::WorkspaceDiagnostics(progress) => match progress {
+                WorkspaceDiagnosticsProgress::Begin { total } => {
+                    self.workspace_diagnostics_progress = Some((0, total));
+                },
+                WorkspaceDiagnosticsProgress::Report { checked } => {
+                    if let Some((last_checked, _)) = &mut self.workspace_diagnostics_progress {
+                        *last_checked = checked;
+                    }
+                },
+                WorkspaceDiagnosticsProgress::End => self.workspace_diagnostics_progress = None,
+            },
             Task::DiscoverProject(argument) => {
                 if let Some(load_task) =
                     LoadPackageTask::discover_local(&argument, self.load_package_sender.clone())
@@ -955,28 +981,64 @@ impl GlobalState {
         // FIXME: Some of these NO_RETRY could be retries if the file they are interested didn't change.
         // All other request handlers
         dispatcher
+            // Request handlers that must run on the main thread
+            // because they are latency sensitive.
+            .on_sync::<lsp::extensions::OnEnterRequest>(handlers::request::handle_on_enter)
+            .on_sync::<lsp::extensions::OnTypeFormattingRequest>(
+                handlers::request::handle_on_type_formatting,
+            )
             .on::<NO_RETRY, DefinitionRequest>(handlers::request::handle_goto_definition)
+            .on::<NO_RETRY, DeclarationRequest>(handlers::request::handle_goto_declaration)
+            .on::<NO_RETRY, TypeDefinitionRequest>(handlers::request::handle_goto_type_definition)
+            .on::<NO_RETRY, CallHierarchyPrepareRequest>(
+                handlers::request::handle_call_hierarchy_prepare,
+            )
+            .on::<NO_RETRY, CallHierarchyIncomingCallsRequest>(
+                handlers::request::handle_call_hierarchy_incoming,
+            )
+            .on::<NO_RETRY, CallHierarchyOutgoingCallsRequest>(
+                handlers::request::handle_call_hierarchy_outgoing,
+            )
+            .on::<NO_RETRY, ReferencesRequest>(handlers::request::handle_references)
+            .on::<NO_RETRY, DocumentHighlightRequest>(handlers::request::handle_document_highlight)
+            .on::<NO_RETRY, DocumentLinkRequest>(handlers::request::handle_document_link)
+            .on::<NO_RETRY, CodeLensRequest>(handlers::request::handle_code_lens)
+            .on::<NO_RETRY, CodeLensResolveRequest>(handlers::request::handle_code_lens_resolve)
+            .on::<NO_RETRY, lsp::extensions::CodeActionRequest>(
+                handlers::request::handle_code_action,
+            )
+            .on::<RETRY, lsp::extensions::CodeActionResolveRequest>(
+                handlers::request::handle_code_action_resolve,
+            )
+            .on::<NO_RETRY, DocumentSymbolRequest>(handlers::request::handle_document_symbol)
+            .on::<NO_RETRY, SemanticTokensRequest>(handlers::request::handle_semantic_tokens_full)
+            .on::<NO_RETRY, SemanticTokensDeltaRequest>(
+                handlers::request::handle_semantic_tokens_full_delta,
+            )
+            .on::<NO_RETRY, lsp::extensions::WorkspaceSymbolRequest>(
+                handlers::request::handle_workspace_symbol,
+            )
+            .on::<NO_RETRY, PrepareRenameRequest>(handlers::request::handle_prepare_rename)
+            .on::<NO_RETRY, RenameRequest>(handlers::request::handle_rename)
+            .on::<NO_RETRY, WillRenameFilesRequest>(handlers::request::handle_will_rename_files)
             .on::<RETRY, CompletionRequest>(handlers::request::handle_completion)
             .on_fmt_thread::<DocumentFormattingRequest>(handlers::request::handle_formatting)
+            .on_fmt_thread::<DocumentRangeFormattingRequest>(
+                handlers::request::handle_range_formatting,
+            )
             .on::<RETRY, FoldingRangeRequest>(handlers::request::handle_folding_range)
+            .on::<RETRY, SelectionRangeRequest>(handlers::request::handle_selection_range)
             .on::<NO_RETRY, lsp::extensions::HoverRequest>(handlers::request::handle_hover)
             .on::<NO_RETRY, ShutdownRequest>(handlers::request::handle_shutdown)
             .on::<NO_RETRY, InlayHintRequest>(handlers::request::handle_inlay_hints)
             .on_with_vfs_default::<DocumentDiagnosticRequest, _>(
                 handlers::request::handle_document_diagnostics,
                 handlers::request::empty_diagnostic_report,
-                || lsp_server::ResponseError {
-                    #[expect(
-                        clippy::as_conversions,
-                        reason = "lsp_server sets this to the JSON RPC value"
-                    )]
-                    code: lsp_server::ErrorCode::ServerCancelled as i32,
-                    message: "server cancelled the request".to_owned(),
-                    data: serde_json::to_value(DiagnosticServerCancellationData {
-                        retrigger_request: true,
-                    })
-                    .ok(),
-                },
+                diagnostics_cancelled_error,
+            )
+            .on_with_progress::<WorkspaceDiagnosticRequest>(
+                handlers::request::handle_workspace_diagnostics,
+                diagnostics_cancelled_error,
             )
             .on::<NO_RETRY, lsp::extensions::ViewSyntaxTreeRequest>(
                 handlers::request::view_syntax_tree,
@@ -1017,3 +1079,19 @@ impl GlobalState {
         .finish();
     }
 }
+
+/// Asks the client to pull the diagnostics again once the server has caught up.
+fn diagnostics_cancelled_error() -> lsp_server::ResponseError {
+    lsp_server::ResponseError {
+        #[expect(
+            clippy::as_conversions,
+            reason = "lsp_server sets this to the JSON RPC value"
+        )]
+        code: lsp_server::ErrorCode::ServerCancelled as i32,
+        message: "server cancelled the request".to_owned(),
+        data: serde_json::to_value(DiagnosticServerCancellationData {
+            retrigger_request: true,
+        })
+        .ok(),
+    }
+}
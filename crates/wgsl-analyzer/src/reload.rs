@@ -9,7 +9,7 @@ use lsp_types::{
 use paths::AbsPathBuf;
 use project_model::PackageRoot;
 use salsa::Durability;
-use stdx::thread::ThreadIntent;
+use stdx::{format_to, thread::ThreadIntent};
 use tracing::info;
 use triomphe::Arc;
 use vfs::{VfsPath, file_set::FileSetConfig};
@@ -87,6 +87,11 @@ impl GlobalState {
         //     message.push('\n');
         // }
 
+        if let Some((checked, total)) = self.workspace_diagnostics_progress {
+            status.quiescent = false;
+            format_to!(message, "Checking workspace: {checked}/{total} files\n\n");
+        }
+
         if !message.is_empty() {
             status.message = Some(message.trim_end().to_owned());
         }
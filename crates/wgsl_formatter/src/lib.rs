@@ -13,11 +13,14 @@
 //! ```
 
 mod format;
+mod range;
 mod util;
 
 use rowan::WalkEvent;
 use syntax::{AstNode as _, SyntaxKind, SyntaxNode, ast};
 
+pub use crate::range::format_range;
+
 /// Formats a WGSL/WESL source string and returns the formatted result.
 ///
 /// Parses with `Edition::LATEST` so that all syntax (including WESL
@@ -99,14 +102,15 @@ impl std::str::FromStr for Policy {
 ///
 /// This is the core recursive driver. It tracks indentation depth as it
 /// enters and leaves block-like nodes, delegating per-node formatting to
-/// [`format::format_syntax_node`].
+/// [`format::format_syntax_node`]. A node nested in blocks starts out at
+/// the indentation of the innermost block.
 pub fn format_recursive(
     syntax: &SyntaxNode,
     options: &FormattingOptions,
 ) {
     let preorder = syntax.preorder();
 
-    let mut indentation: usize = 0;
+    let mut indentation = syntax.ancestors().skip(1).filter(is_indent_kind).count();
 
     for event in preorder {
         match event {
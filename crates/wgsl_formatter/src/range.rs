@@ -0,0 +1,197 @@
+//! Formatting of the part of a file selected by a range.
+
+use rowan::{NodeOrToken, TextRange, TextSize};
+use syntax::{SyntaxKind, SyntaxNode};
+
+use crate::{FormattingOptions, format_recursive};
+
+/// Formats the statements or items of `file` which intersect `range`.
+///
+/// Only the statements of the innermost block (or the items of the file) containing the range
+/// are formatted, each at the indentation of its block. The result is the range of the original
+/// text which changed together with its formatted replacement, so text outside of the formatted
+/// statements is never touched. Returns `None` if nothing needs formatting.
+#[must_use]
+pub fn format_range(
+    file: &SyntaxNode,
+    range: TextRange,
+    options: &FormattingOptions,
+) -> Option<(TextRange, String)> {
+    let formatted = file.clone_for_update();
+    let units = units_in_range(&formatted, range);
+    if units.is_empty() {
+        return None;
+    }
+    for unit in &units {
+        format_recursive(unit, options);
+    }
+    changed_range(&file.to_string(), &formatted.to_string())
+}
+
+/// The children of the innermost block or file containing `range` which intersect it.
+fn units_in_range(
+    file: &SyntaxNode,
+    range: TextRange,
+) -> Vec<SyntaxNode> {
+    let covering = match file.covering_element(range) {
+        NodeOrToken::Node(node) => Some(node),
+        NodeOrToken::Token(token) => token.parent(),
+    };
+    let Some(container) = covering.and_then(|node| {
+        node.ancestors().find(|node| {
+            matches!(
+                node.kind(),
+                SyntaxKind::SourceFile | SyntaxKind::CompoundStatement | SyntaxKind::SwitchBody
+            )
+        })
+    }) else {
+        return Vec::new();
+    };
+    container
+        .children()
+        .filter(|child| {
+            child
+                .text_range()
+                .intersect(range)
+                .is_some_and(|common| range.is_empty() || !common.is_empty())
+        })
+        .collect()
+}
+
+/// The range of `before` which differs from `after`, and the text of `after` replacing it.
+fn changed_range(
+    before: &str,
+    after: &str,
+) -> Option<(TextRange, String)> {
+    let prefix: usize = before
+        .chars()
+        .zip(after.chars())
+        .take_while(|(left, right)| left == right)
+        .map(|(character, _)| character.len_utf8())
+        .sum();
+    let (before_rest, after_rest) = (&before[prefix..], &after[prefix..]);
+    if before_rest == after_rest {
+        return None;
+    }
+    let suffix: usize = before_rest
+        .chars()
+        .rev()
+        .zip(after_rest.chars().rev())
+        .take_while(|(left, right)| left == right)
+        .map(|(character, _)| character.len_utf8())
+        .sum();
+    let start = TextSize::try_from(prefix).ok()?;
+    let end = TextSize::try_from(before.len() - suffix).ok()?;
+    Some((
+        TextRange::new(start, end),
+        after_rest[..after_rest.len() - suffix].to_owned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{Expect, expect};
+    use rowan::{TextRange, TextSize};
+
+    use crate::{FormattingOptions, format_range};
+
+    /// Formats the range between the two `$0` markers and applies the edits.
+    #[track_caller]
+    fn check(
+        before: &str,
+        after: &Expect,
+    ) {
+        let before = before.trim_start();
+        let (start, rest) = before.split_once("$0").expect("missing range start");
+        let (selected, end) = rest.split_once("$0").expect("missing range end");
+        let text = format!("{start}{selected}{end}");
+        let range = TextRange::at(TextSize::of(start), TextSize::of(selected));
+
+        let file = syntax::parse(&text, syntax::Edition::LATEST).syntax();
+        let mut result = text.clone();
+        if let Some((changed, replacement)) =
+            format_range(&file, range, &FormattingOptions::default())
+        {
+            result.replace_range(std::ops::Range::<usize>::from(changed), &replacement);
+        }
+        after.assert_eq(&result);
+    }
+
+    #[test]
+    fn formats_only_selected_statement() {
+        check(
+            "
+fn main() {
+    let  a  =  1 ;
+    $0let  b  =  2 ;$0
+    let  c  =  3 ;
+}
+",
+            &expect![[r#"
+                fn main() {
+                    let  a  =  1 ;
+                    let b = 2;
+                    let  c  =  3 ;
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn formats_statements_intersecting_range() {
+        check(
+            "
+fn main() {
+    let  a  =  1 ;
+    let  b  $0=  2 ;
+    let  c  =$0  3 ;
+    let  d  =  4 ;
+}
+",
+            &expect![[r#"
+                fn main() {
+                    let  a  =  1 ;
+                    let b = 2;
+                    let c = 3;
+                    let  d  =  4 ;
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn indents_nested_statement() {
+        check(
+            "
+fn main() {
+    if true {
+$0discard ;$0
+    }
+}
+",
+            &expect![[r#"
+                fn main() {
+                    if true {
+                        discard;
+                    }
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn formats_only_selected_item() {
+        check(
+            "
+const  a  =  1 ;
+$0fn  main ( )  {  }$0
+const  b  =  2 ;
+",
+            &expect![[r#"
+                const  a  =  1 ;
+                fn main() {}
+                const  b  =  2 ;
+            "#]],
+        );
+    }
+}
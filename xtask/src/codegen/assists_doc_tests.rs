@@ -1,16 +1,188 @@
-use std::fs;
+//! Generates the doc tests and the manual of the assists from their documentation comments.
 
-use crate::{codegen::add_preamble, project_root};
+use std::{
+    fmt::{self, Write as _},
+    fs,
+    path::Path,
+};
+
+use crate::{
+    codegen::{CommentBlock, Location, add_preamble, ensure_file_contents},
+    flags::CodegenType,
+    project_root,
+    utilities::list_files,
+};
 
 pub(crate) fn generate(check: bool) {
+    let assists = Assist::collect();
+
+    let mut contents = String::from("use super::check_doc_test;\n");
+    for assist in &assists {
+        for (index, section) in assist.sections.iter().enumerate() {
+            let test_id = if index == 0 {
+                assist.id.clone()
+            } else {
+                format!("{}_{index}", assist.id)
+            };
+            writeln!(
+                contents,
+                r######"
+#[test]
+fn doctest_{test_id}() {{
+    check_doc_test(
+        "{}",
+        r#####"
+{}"#####,
+        r#####"
+{}"#####,
+    );
+}}"######,
+                assist.id, section.before, section.after,
+            )
+            .unwrap();
+        }
+    }
+    let contents = add_preamble(CodegenType::AssistsDocTests, contents);
+    let destination = project_root().join("crates/ide-assists/src/tests/generated.rs");
+    ensure_file_contents(CodegenType::AssistsDocTests, &destination, &contents, check);
+
+    // Do not generate assists manual when run with `--check`
     if check {
         return;
     }
     // Generate assists manual.
     // Note that we do *not* commit manual to the git repository.
     // Instead, `cargo xtask release` runs this test before making a release.
-    let contents = String::new();
-    let contents = add_preamble(crate::flags::CodegenType::AssistsDocTests, contents);
+    let contents = assists
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let contents = add_preamble(CodegenType::AssistsDocTests, contents);
     let destination = project_root().join("docs/book/src/assists_generated.md");
     fs::write(destination, contents).unwrap();
 }
+
+#[derive(Debug)]
+struct Section {
+    doc: String,
+    before: String,
+    after: String,
+}
+
+#[derive(Debug)]
+struct Assist {
+    id: String,
+    location: Location,
+    sections: Vec<Section>,
+}
+
+impl Assist {
+    fn collect() -> Vec<Self> {
+        let handlers_directory = project_root().join("crates/ide-assists/src/handlers");
+
+        let mut result = Vec::new();
+        for path in list_files(&handlers_directory) {
+            Self::collect_file(&mut result, &path);
+        }
+        result.sort_by(|left, right| left.id.cmp(&right.id));
+        result
+    }
+
+    fn collect_file(
+        result: &mut Vec<Self>,
+        path: &Path,
+    ) {
+        let text = fs::read_to_string(path).unwrap();
+        let comment_blocks = CommentBlock::extract("Assist", &text);
+
+        for block in comment_blocks {
+            let id = block.id;
+            assert!(
+                id.chars()
+                    .all(|character| character.is_ascii_lowercase() || character == '_'),
+                "invalid assist id: {id:?}"
+            );
+            let mut lines = block.contents.iter().peekable();
+            let location = Location {
+                file: path.to_path_buf(),
+                line: block.line,
+            };
+            let mut assist = Self {
+                id,
+                location,
+                sections: Vec::new(),
+            };
+
+            loop {
+                let doc = take_until(lines.by_ref(), "```").trim().to_owned();
+                assert!(
+                    (doc.starts_with(|character: char| character.is_ascii_uppercase())
+                        && doc.ends_with('.'))
+                        || !assist.sections.is_empty(),
+                    "\n\n{}: assist docs should be proper sentences, with capitalization and a full stop at the end.\n\n{doc}\n\n",
+                    &assist.id,
+                );
+
+                let before = take_until(lines.by_ref(), "```");
+
+                assert_eq!(lines.next().unwrap().as_str(), "->");
+                assert_eq!(lines.next().unwrap().as_str(), "```");
+                let after = take_until(lines.by_ref(), "```");
+
+                assist.sections.push(Section { doc, before, after });
+                if lines.peek().is_none() {
+                    break;
+                }
+            }
+
+            result.push(assist);
+        }
+    }
+}
+
+impl fmt::Display for Assist {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        writeln!(formatter, "### `{}`", self.id)?;
+        writeln!(formatter, "**Source:** {}", self.location)?;
+
+        for section in &self.sections {
+            let before = section.before.replace("$0", "┃");
+            let after = section.after.replace("$0", "┃");
+            writeln!(
+                formatter,
+                "
+{}
+
+#### Before
+```wgsl
+{before}```
+
+#### After
+```wgsl
+{after}```",
+                section.doc,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn take_until<'line>(
+    lines: impl Iterator<Item = &'line String>,
+    marker: &str,
+) -> String {
+    let mut text = String::new();
+    for line in lines {
+        if line.trim_start().starts_with(marker) {
+            break;
+        }
+        text.push_str(line);
+        text.push('\n');
+    }
+    text
+}